@@ -76,10 +76,13 @@ fn print_symbol(symbol: &Symbol<'_, '_>, section_kinds: &HashMap<SectionIndex, S
             | Some(SectionKind::Metadata)
             | Some(SectionKind::Elf(_)) => '?',
             Some(SectionKind::Text) => 't',
-            Some(SectionKind::Data) | Some(SectionKind::Tls) | Some(SectionKind::TlsVariables) => {
-                'd'
-            }
-            Some(SectionKind::ReadOnlyData) | Some(SectionKind::ReadOnlyString) => 'r',
+            Some(SectionKind::Data)
+            | Some(SectionKind::Tls)
+            | Some(SectionKind::TlsVariables)
+            | Some(SectionKind::DynamicLinkInfo) => 'd',
+            Some(SectionKind::ReadOnlyData)
+            | Some(SectionKind::ReadOnlyString)
+            | Some(SectionKind::Unwind) => 'r',
             Some(SectionKind::UninitializedData) | Some(SectionKind::UninitializedTls) => 'b',
             Some(SectionKind::Common) => 'C',
         },
@@ -0,0 +1,19 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use object::{Object, ObjectSection, ObjectSegment, ObjectSymbol};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(file) = object::File::parse_checked(data) {
+        for segment in file.segments() {
+            let _ = segment.data();
+        }
+        for section in file.sections() {
+            let _ = section.data();
+            for _ in section.relocations() {}
+        }
+        for symbol in file.symbols() {
+            let _ = symbol.name();
+        }
+        let _ = file.symbol_map();
+    }
+});
@@ -7,6 +7,10 @@ pub enum Architecture {
     Arm,
     I386,
     Mips,
+    PowerPc,
+    PowerPc64,
+    Riscv32,
+    Riscv64,
     S390x,
     Wasm32,
     X86_64,
@@ -23,6 +27,10 @@ impl Architecture {
             Architecture::Arm => Some(AddressSize::U32),
             Architecture::I386 => Some(AddressSize::U32),
             Architecture::Mips => Some(AddressSize::U32),
+            Architecture::PowerPc => Some(AddressSize::U32),
+            Architecture::PowerPc64 => Some(AddressSize::U64),
+            Architecture::Riscv32 => Some(AddressSize::U32),
+            Architecture::Riscv64 => Some(AddressSize::U64),
             Architecture::S390x => Some(AddressSize::U64),
             Architecture::Wasm32 => Some(AddressSize::U32),
             Architecture::X86_64 => Some(AddressSize::U64),
@@ -135,6 +143,18 @@ pub enum SectionKind {
     Linker,
     /// ELF note section.
     Note,
+    /// Information for the dynamic linker.
+    ///
+    /// Example ELF sections: `.dynamic`, `.gnu.hash`, `.gnu.version`
+    DynamicLinkInfo,
+    /// Unwind information for exception handling.
+    ///
+    /// Example ELF sections: `.eh_frame`, `.eh_frame_hdr`
+    ///
+    /// Example Mach-O sections: `__TEXT/__eh_frame`, `__TEXT/__unwind_info`
+    ///
+    /// Example COFF sections: `.pdata`, `.xdata`
+    Unwind,
     /// Metadata such as symbols or relocations.
     ///
     /// Example ELF sections: `.symtab`, `.strtab`, `.group`
@@ -693,8 +693,18 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 /// Extended section indices for a symbol table.
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// Relative relocation entries.
+pub const SHT_RELR: u32 = 19;
 /// Start of OS-specific section types.
 pub const SHT_LOOS: u32 = 0x6000_0000;
+/// GNU-style hash table.
+pub const SHT_GNU_HASH: u32 = 0x6fff_fff6;
+/// Version definition section.
+pub const SHT_GNU_VERDEF: u32 = 0x6fff_fffd;
+/// Version needs section.
+pub const SHT_GNU_VERNEED: u32 = 0x6fff_fffe;
+/// Version symbol table.
+pub const SHT_GNU_VERSYM: u32 = 0x6fff_ffff;
 /// End of OS-specific section types.
 pub const SHT_HIOS: u32 = 0x6fff_ffff;
 /// Start of processor-specific section types.
@@ -774,6 +784,8 @@ pub struct CompressionHeader64<E: Endian> {
 
 /// ZLIB/DEFLATE algorithm.
 pub const ELFCOMPRESS_ZLIB: u32 = 1;
+/// Zstandard algorithm.
+pub const ELFCOMPRESS_ZSTD: u32 = 2;
 /// Start of OS-specific compression types.
 pub const ELFCOMPRESS_LOOS: u32 = 0x6000_0000;
 /// End of OS-specific compression types.
@@ -1489,6 +1501,12 @@ pub const DT_PREINIT_ARRAY: u32 = 32;
 pub const DT_PREINIT_ARRAYSZ: u32 = 33;
 /// Address of SYMTAB_SHNDX section
 pub const DT_SYMTAB_SHNDX: u32 = 34;
+/// Total size of RELR relative relocations
+pub const DT_RELRSZ: u32 = 35;
+/// Address of RELR relative relocations
+pub const DT_RELR: u32 = 36;
+/// Size of one RELR relative relocation
+pub const DT_RELRENT: u32 = 37;
 /// Start of OS-specific
 pub const DT_LOOS: u32 = 0x6000_000d;
 /// End of OS-specific
@@ -1652,12 +1670,86 @@ pub const DF_1_STUB: u32 = 0x0400_0000;
 #[allow(missing_docs)]
 pub const DF_1_PIE: u32 = 0x0800_0000;
 
-// TODO: ELF*_Verdef, VER_DEF_*, VER_FLG_*, VER_NDX_*
-// TODO: Elf*_Verdaux
+/// Version definition entry.
+///
+/// The entries form a linked list in the `SHT_GNU_VERDEF` section.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Verdef<E: Endian> {
+    /// Version revision. One of the `VER_DEF_*` constants.
+    pub vd_version: U16<E>,
+    /// Version information. A combination of the `VER_FLG_*` constants.
+    pub vd_flags: U16<E>,
+    /// Version index, as used in the version symbol table.
+    pub vd_ndx: U16<E>,
+    /// Number of associated `Verdaux` entries.
+    pub vd_cnt: U16<E>,
+    /// Version name hash value.
+    pub vd_hash: U32<E>,
+    /// Offset in bytes from this entry to the `Verdaux` array.
+    pub vd_aux: U32<E>,
+    /// Offset in bytes from this entry to the next `Verdef` entry.
+    pub vd_next: U32<E>,
+}
+
+// Values for `Verdef::vd_version`.
+/// No version.
+pub const VER_DEF_NONE: u16 = 0;
+/// Current version.
+pub const VER_DEF_CURRENT: u16 = 1;
+
+// Values for `Verdef::vd_flags`.
+/// Version definition of the file itself.
+pub const VER_FLG_BASE: u16 = 0x1;
+/// Weak version identifier.
+pub const VER_FLG_WEAK: u16 = 0x2;
+
+// Special version indices for the version symbol table.
+/// Symbol is local.
+pub const VER_NDX_LOCAL: u16 = 0;
+/// Symbol is global.
+pub const VER_NDX_GLOBAL: u16 = 1;
+
+// Bit masks for entries in the version symbol table.
+/// The version index.
+pub const VERSYM_VERSION: u16 = 0x7fff;
+/// Symbol is hidden. It can't be referenced without a version.
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+
+/// Auxiliary version definition entry.
+///
+/// The first entry gives the version name, and any later entries give the
+/// names of the parent versions.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Verdaux<E: Endian> {
+    /// Offset in the linked string table of the version or dependency name.
+    pub vda_name: U32<E>,
+    /// Offset in bytes from this entry to the next `Verdaux` entry.
+    pub vda_next: U32<E>,
+}
+
 // TODO: Elf*_Verneed, VER_NEED_*
 // TODO: Elf*_Vernaux, VER_FLG_*
 // TODO: Elf*_auxv_t, AT_*
 
+/// Header of `SHT_GNU_HASH` section.
+///
+/// The header is followed by the bloom filter, the bucket array, and the chain array.
+/// The bloom filter entries are 32 bits for 32-bit files and 64 bits for 64-bit files.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct GnuHashHeader<E: Endian> {
+    /// The number of hash buckets.
+    pub bucket_count: U32<E>,
+    /// The dynamic symbol table index of the first symbol in the hash table.
+    pub symbol_base: U32<E>,
+    /// The number of words in the bloom filter.
+    pub bloom_count: U32<E>,
+    /// The bit shift count for the bloom filter.
+    pub bloom_shift: U32<E>,
+}
+
 /// Note section entry header.
 ///
 /// A note consists of a header followed by a variable length name and descriptor.
@@ -1749,6 +1841,32 @@ pub const NT_GNU_GOLD_VERSION: u32 = 4;
 /// Program property.
 pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
 
+/// Go entries in the note section have this name.
+pub static ELF_NOTE_GO: &[u8] = b"Go";
+
+// Note types for `ELF_NOTE_GO`.
+
+/// Go build ID.
+///
+/// The descriptor is the build ID string, which consists of up to four
+/// slash separated hashes.
+pub const NT_GO_BUILD_ID: u32 = 4;
+
+/// SystemTap entries in the note section have this name.
+pub static ELF_NOTE_STAPSDT: &[u8] = b"stapsdt";
+
+// Note types for `ELF_NOTE_STAPSDT`.
+
+/// A statically defined tracing probe.
+///
+/// The descriptor consists of three addresses:
+/// - address 0: the address of the probe
+/// - address 1: the link-time address of the `.stapsdt.base` section
+/// - address 2: the address of the semaphore, or 0 if there is none
+///
+/// Then follow the '\0'-terminated provider, name, and argument strings.
+pub const NT_STAPSDT: u32 = 3;
+
 // TODO: GNU_PROPERTY_*
 // TODO: Elf*_Move
 
@@ -6053,4 +6171,7 @@ unsafe_impl_endian_pod!(
     Dyn64,
     NoteHeader32,
     NoteHeader64,
+    Verdef,
+    Verdaux,
+    GnuHashHeader,
 );
@@ -336,6 +336,12 @@ pub const IMAGE_FILE_MACHINE_TRICORE: u16 = 0x0520;
 pub const IMAGE_FILE_MACHINE_CEF: u16 = 0x0CEF;
 /// EFI Byte Code
 pub const IMAGE_FILE_MACHINE_EBC: u16 = 0x0EBC;
+/// RISC-V 32-bit address space
+pub const IMAGE_FILE_MACHINE_RISCV32: u16 = 0x5032;
+/// RISC-V 64-bit address space
+pub const IMAGE_FILE_MACHINE_RISCV64: u16 = 0x5064;
+/// RISC-V 128-bit address space
+pub const IMAGE_FILE_MACHINE_RISCV128: u16 = 0x5128;
 /// AMD64 (K8)
 pub const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
 /// M32R little-endian
@@ -2034,6 +2040,30 @@ pub struct ImageResourceDataEntry {
     pub reserved: U32<LE>,
 }
 
+// Resource type IDs for `ImageResourceDirectoryEntry::name_or_id`.
+pub const RT_CURSOR: u16 = 1;
+pub const RT_BITMAP: u16 = 2;
+pub const RT_ICON: u16 = 3;
+pub const RT_MENU: u16 = 4;
+pub const RT_DIALOG: u16 = 5;
+pub const RT_STRING: u16 = 6;
+pub const RT_FONTDIR: u16 = 7;
+pub const RT_FONT: u16 = 8;
+pub const RT_ACCELERATOR: u16 = 9;
+pub const RT_RCDATA: u16 = 10;
+pub const RT_MESSAGETABLE: u16 = 11;
+pub const RT_GROUP_CURSOR: u16 = 12;
+pub const RT_GROUP_ICON: u16 = 14;
+pub const RT_VERSION: u16 = 16;
+pub const RT_DLGINCLUDE: u16 = 17;
+pub const RT_PLUGPLAY: u16 = 19;
+pub const RT_VXD: u16 = 20;
+pub const RT_ANICURSOR: u16 = 21;
+pub const RT_ANIICON: u16 = 22;
+pub const RT_HTML: u16 = 23;
+/// Application manifest.
+pub const RT_MANIFEST: u16 = 24;
+
 //
 // Code Integrity in loadconfig (CI)
 //
@@ -2862,6 +2892,36 @@ pub struct ImageCor20Header {
     pub managed_native_header: ImageDataDirectory,
 }
 
+//
+// Attribute certificate table.
+//
+// The security directory entry gives the file offset and size of this table.
+// Each entry is aligned to 8 bytes.
+//
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct WinCertificate {
+    /// The length of the entry, including this header.
+    pub length: U32<LE>,
+    /// One of the `WIN_CERT_REVISION_*` values.
+    pub revision: U16<LE>,
+    /// One of the `WIN_CERT_TYPE_*` values.
+    pub certificate_type: U16<LE>,
+    // Followed by the certificate data.
+}
+
+pub const WIN_CERT_REVISION_1_0: u16 = 0x0100;
+pub const WIN_CERT_REVISION_2_0: u16 = 0x0200;
+
+/// `bCertificate` contains an X.509 certificate.
+pub const WIN_CERT_TYPE_X509: u16 = 0x0001;
+/// `bCertificate` contains a PKCS#7 `SignedData` structure.
+pub const WIN_CERT_TYPE_PKCS_SIGNED_DATA: u16 = 0x0002;
+pub const WIN_CERT_TYPE_RESERVED_1: u16 = 0x0003;
+/// Terminal Server Protocol Stack certificate signing.
+pub const WIN_CERT_TYPE_TS_STACK_SIGNED: u16 = 0x0004;
+
 unsafe_impl_pod!(
     ImageDosHeader,
     ImageOs2Header,
@@ -2945,4 +3005,5 @@ unsafe_impl_pod!(
     ImageArchitectureEntry,
     ImportObjectHeader,
     ImageCor20Header,
+    WinCertificate,
 );
@@ -1,5 +1,6 @@
 use alloc::fmt;
 use alloc::vec::Vec;
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 
 #[cfg(feature = "coff")]
@@ -15,9 +16,9 @@ use crate::read::wasm;
 use crate::read::{
     self, Architecture, BinaryFormat, CodeView, ComdatKind, CompressedData, CompressedFileRange,
     Error, Export, FileFlags, FileKind, Import, Object, ObjectComdat, ObjectMap, ObjectSection,
-    ObjectSegment, ObjectSymbol, ObjectSymbolTable, ReadRef, Relocation, Result, SectionFlags,
-    SectionIndex, SectionKind, SymbolFlags, SymbolIndex, SymbolKind, SymbolMap, SymbolMapName,
-    SymbolScope, SymbolSection,
+    ObjectSegment, ObjectSymbol, ObjectSymbolTable, Permissions, RawRelocation, ReadError, ReadRef,
+    Relocation, Result, SectionFlags, SectionIndex, SectionKind, SymbolFlags, SymbolIndex,
+    SymbolKind, SymbolMap, SymbolMapName, SymbolScope, SymbolSection, Toolchain, ValidationIssue,
 };
 use crate::Endianness;
 
@@ -171,6 +172,7 @@ macro_rules! next_inner {
 #[derive(Debug)]
 pub struct File<'data, R: ReadRef<'data> = &'data [u8]> {
     inner: FileInternal<'data, R>,
+    base_offset: u64,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -217,7 +219,133 @@ impl<'data, R: ReadRef<'data>> File<'data, R> {
             #[allow(unreachable_patterns)]
             _ => return Err(Error("Unsupported file format")),
         };
-        Ok(File { inner })
+        Ok(File {
+            inner,
+            base_offset: 0,
+        })
+    }
+
+    /// Parse the raw file data, and validate the data that is used by the methods
+    /// of the `Object` trait.
+    ///
+    /// This is intended for use with untrusted input. For any input, this function
+    /// does not panic, and the memory that it allocates is bounded by the size of
+    /// the input. Any malformed header, segment, section, or symbol results in an error.
+    ///
+    /// Methods on the returned file may still return errors for data that is not
+    /// validated by this function, such as the contents of debugging sections.
+    /// Decompressing a section allocates the uncompressed size that is given in the
+    /// file, so callers should check `CompressedData::uncompressed_size` first.
+    pub fn parse_checked(data: R) -> Result<Self> {
+        let file = Self::parse(data)?;
+        file.check()?;
+        Ok(file)
+    }
+
+    fn check(&self) -> Result<()> {
+        for segment in self.segments() {
+            segment.data()?;
+            segment.name()?;
+        }
+        for section in self.sections() {
+            section.name()?;
+            section.segment_name()?;
+            section.compressed_data()?;
+            for _ in section.relocations() {}
+        }
+        for symbol in self.symbols().chain(self.dynamic_symbols()) {
+            symbol.name()?;
+        }
+        for comdat in self.comdats() {
+            comdat.name()?;
+            for _ in comdat.sections() {}
+        }
+        if let Some(relocations) = self.dynamic_relocations() {
+            for _ in relocations {}
+        }
+        self.imports()?;
+        self.exports()?;
+        self.mach_uuid()?;
+        self.build_id()?;
+        self.gnu_debuglink()?;
+        self.gnu_debugaltlink()?;
+        self.pdb_info()?;
+        Ok(())
+    }
+
+    /// Parse the raw file data, recovering from errors where possible.
+    ///
+    /// Errors that prevent the file from being identified or its header from being
+    /// read are fatal, and no file is returned. Errors in tables such as the section
+    /// table or symbol table are recoverable, and the corresponding table is treated
+    /// as empty. All errors are returned, in the order they were encountered.
+    ///
+    /// Wasm files do not support lenient parsing, and any error is fatal.
+    pub fn parse_lenient(data: R) -> (Option<Self>, Vec<Error>) {
+        let mut errors = Vec::new();
+        let inner = match FileKind::parse(data) {
+            Ok(kind) => Self::parse_lenient_internal(kind, data, &mut errors),
+            Err(error) => Err(error),
+        };
+        match inner {
+            Ok(inner) => (
+                Some(File {
+                    inner,
+                    base_offset: 0,
+                }),
+                errors,
+            ),
+            Err(error) => {
+                errors.push(error);
+                (None, errors)
+            }
+        }
+    }
+
+    fn parse_lenient_internal(
+        kind: FileKind,
+        data: R,
+        errors: &mut Vec<Error>,
+    ) -> Result<FileInternal<'data, R>> {
+        Ok(match kind {
+            #[cfg(feature = "elf")]
+            FileKind::Elf32 => FileInternal::Elf32(elf::ElfFile32::parse_lenient(data, errors)?),
+            #[cfg(feature = "elf")]
+            FileKind::Elf64 => FileInternal::Elf64(elf::ElfFile64::parse_lenient(data, errors)?),
+            #[cfg(feature = "macho")]
+            FileKind::MachO32 => {
+                FileInternal::MachO32(macho::MachOFile32::parse_lenient(data, errors)?)
+            }
+            #[cfg(feature = "macho")]
+            FileKind::MachO64 => {
+                FileInternal::MachO64(macho::MachOFile64::parse_lenient(data, errors)?)
+            }
+            #[cfg(feature = "wasm")]
+            FileKind::Wasm => {
+                // Wasm files are parsed by `wasmparser`, which has no recoverable errors.
+                let _ = errors;
+                FileInternal::Wasm(wasm::WasmFile::parse(data)?)
+            }
+            #[cfg(feature = "pe")]
+            FileKind::Pe32 => FileInternal::Pe32(pe::PeFile32::parse_lenient(data, errors)?),
+            #[cfg(feature = "pe")]
+            FileKind::Pe64 => FileInternal::Pe64(pe::PeFile64::parse_lenient(data, errors)?),
+            #[cfg(feature = "coff")]
+            FileKind::Coff => FileInternal::Coff(coff::CoffFile::parse_lenient(data, errors)?),
+            #[allow(unreachable_patterns)]
+            _ => return Err(Error("Unsupported file format")),
+        })
+    }
+
+    /// The offset of the file data within the containing data.
+    ///
+    /// This is the offset that was passed to `File::parse_at_offset`, or 0 for
+    /// other parse functions. File offsets returned by methods such as
+    /// `ObjectSection::file_range` are relative to the start of the file data, so
+    /// add this offset to convert them to offsets within the containing data.
+    #[inline]
+    pub fn base_offset(&self) -> u64 {
+        self.base_offset
     }
 
     /// Return the file format.
@@ -237,6 +365,23 @@ impl<'data, R: ReadRef<'data>> File<'data, R> {
     }
 }
 
+impl<'data> File<'data, &'data [u8]> {
+    /// Parse file data that is embedded at the given offset within other data.
+    ///
+    /// This is useful for object files that are contained in archives or other
+    /// containers. The file data extends from the offset to the end of `data`.
+    /// The offset is recorded and can be retrieved with `File::base_offset`.
+    pub fn parse_at_offset(data: &'data [u8], offset: u64) -> Result<Self> {
+        let file_data = usize::try_from(offset)
+            .ok()
+            .and_then(|offset| data.get(offset..))
+            .read_error("Invalid file offset")?;
+        let mut file = Self::parse(file_data)?;
+        file.base_offset = offset;
+        Ok(file)
+    }
+}
+
 impl<'data, R: ReadRef<'data>> read::private::Sealed for File<'data, R> {}
 
 impl<'data, 'file, R> Object<'data, 'file> for File<'data, R>
@@ -267,6 +412,18 @@ where
         with_inner!(self.inner, FileInternal, |x| x.is_64())
     }
 
+    fn pointer_size(&self) -> u8 {
+        with_inner!(self.inner, FileInternal, |x| x.pointer_size())
+    }
+
+    fn is_relocatable(&self) -> bool {
+        with_inner!(self.inner, FileInternal, |x| x.is_relocatable())
+    }
+
+    fn default_page_size(&self) -> u64 {
+        with_inner!(self.inner, FileInternal, |x| x.default_page_size())
+    }
+
     fn segments(&'file self) -> SegmentIterator<'data, 'file, R> {
         SegmentIterator {
             inner: map_inner!(self.inner, FileInternal, SegmentIteratorInternal, |x| x
@@ -286,6 +443,12 @@ where
         .map(|inner| Section { inner })
     }
 
+    fn section_by_address(&'file self, address: u64) -> Option<Section<'data, 'file, R>> {
+        map_inner_option!(self.inner, FileInternal, SectionInternal, |x| x
+            .section_by_address(address))
+        .map(|inner| Section { inner })
+    }
+
     fn sections(&'file self) -> SectionIterator<'data, 'file, R> {
         SectionIterator {
             inner: map_inner!(self.inner, FileInternal, SectionIteratorInternal, |x| x
@@ -359,6 +522,10 @@ where
         None
     }
 
+    fn contains_symbol(&'file self, name: &str) -> bool {
+        with_inner!(self.inner, FileInternal, |x| x.contains_symbol(name))
+    }
+
     fn symbol_map(&self) -> SymbolMap<SymbolMapName<'data>> {
         with_inner!(self.inner, FileInternal, |x| x.symbol_map())
     }
@@ -404,10 +571,37 @@ where
         with_inner!(self.inner, FileInternal, |x| x.pdb_info())
     }
 
+    #[inline]
+    fn toolchain(&'file self) -> Toolchain {
+        with_inner!(self.inner, FileInternal, |x| x.toolchain())
+    }
+
+    #[inline]
+    fn validate(&'file self) -> Vec<ValidationIssue> {
+        with_inner!(self.inner, FileInternal, |x| x.validate())
+    }
+
+    #[inline]
+    fn timestamp(&self) -> Option<u64> {
+        with_inner!(self.inner, FileInternal, |x| x.timestamp())
+    }
+
     fn entry(&self) -> u64 {
         with_inner!(self.inner, FileInternal, |x| x.entry())
     }
 
+    fn entry_symbol(&'file self) -> Option<Symbol<'data, 'file, R>> {
+        map_inner_option!(self.inner, FileInternal, SymbolInternal, |x| x
+            .entry_symbol()
+            .map(|x| (x, PhantomData)))
+        .map(|inner| Symbol { inner })
+    }
+
+    #[inline]
+    fn preferred_base(&'file self) -> u64 {
+        with_inner!(self.inner, FileInternal, |x| x.preferred_base())
+    }
+
     fn flags(&self) -> FileFlags {
         with_inner!(self.inner, FileInternal, |x| x.flags())
     }
@@ -534,9 +728,16 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSegment<'data> for Segment<'data, 'f
     fn name(&self) -> Result<Option<&str>> {
         with_inner!(self.inner, SegmentInternal, |x| x.name())
     }
+
+    fn permissions(&self) -> Permissions {
+        with_inner!(self.inner, SegmentInternal, |x| x.permissions())
+    }
 }
 
 /// An iterator of the sections of a `File`.
+///
+/// The section headers are validated when the file is parsed, so this never
+/// yields a malformed section header.
 #[derive(Debug)]
 pub struct SectionIterator<'data, 'file, R: ReadRef<'data> = &'data [u8]>
 where
@@ -695,9 +896,17 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSection<'data> for Section<'data, 'f
         }
     }
 
+    fn raw_relocations(&self) -> Result<Vec<RawRelocation>> {
+        with_inner!(self.inner, SectionInternal, |x| x.raw_relocations())
+    }
+
     fn flags(&self) -> SectionFlags {
         with_inner!(self.inner, SectionInternal, |x| x.flags())
     }
+
+    fn permissions(&self) -> Permissions {
+        with_inner!(self.inner, SectionInternal, |x| x.permissions())
+    }
 }
 
 /// An iterator of the COMDAT section groups of a `File`.
@@ -921,6 +1130,8 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSymbolTable<'data> for SymbolTable<'
 }
 
 /// An iterator over symbol table entries.
+///
+/// Iteration stops at the first symbol that cannot be read, rather than panicking.
 #[derive(Debug)]
 pub struct SymbolIterator<'data, 'file, R = &'data [u8]>
 where
@@ -1090,9 +1301,15 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSymbol<'data> for Symbol<'data, 'fil
     fn flags(&self) -> SymbolFlags<SectionIndex> {
         with_inner!(self.inner, SymbolInternal, |x| x.0.flags())
     }
+
+    fn is_thumb(&self) -> bool {
+        with_inner!(self.inner, SymbolInternal, |x| x.0.is_thumb())
+    }
 }
 
 /// An iterator over dynamic relocation entries.
+///
+/// Relocation sections whose data cannot be read are skipped.
 #[derive(Debug)]
 pub struct DynamicRelocationIterator<'data, 'file, R = &'data [u8]>
 where
@@ -1132,6 +1349,8 @@ impl<'data, 'file, R: ReadRef<'data>> Iterator for DynamicRelocationIterator<'da
 }
 
 /// An iterator over section relocation entries.
+///
+/// If the relocation data for the section cannot be read, the iterator is empty.
 #[derive(Debug)]
 pub struct SectionRelocationIterator<'data, 'file, R: ReadRef<'data> = &'data [u8]>
 where
@@ -26,6 +26,7 @@ pub struct ArchiveFile<'data, R: ReadRef<'data> = &'data [u8]> {
     len: u64,
     offset: u64,
     kind: ArchiveKind,
+    thin: bool,
     symbols: &'data [u8],
     names: &'data [u8],
 }
@@ -38,15 +39,20 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
         let magic = data
             .read_bytes(&mut tail, archive::MAGIC.len() as u64)
             .read_error("Invalid archive size")?;
-        if magic != &archive::MAGIC[..] {
+        let thin = if magic == &archive::MAGIC[..] {
+            false
+        } else if magic == &archive::THIN_MAGIC[..] {
+            true
+        } else {
             return Err(Error("Unsupported archive identifier"));
-        }
+        };
 
         let mut file = ArchiveFile {
             data,
             offset: tail,
             len,
             kind: ArchiveKind::Unknown,
+            thin,
             symbols: &[],
             names: &[],
         };
@@ -62,7 +68,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
         // BSD has:
         // - "__.SYMDEF" or "__.SYMDEF SORTED": symbol table (optional)
         if tail < len {
-            let member = ArchiveMember::parse(data, &mut tail, &[])?;
+            let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
             if member.name == b"/" {
                 // GNU symbol table (unless we later determine this is COFF).
                 file.kind = ArchiveKind::Gnu;
@@ -70,7 +76,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
                 file.offset = tail;
 
                 if tail < len {
-                    let member = ArchiveMember::parse(data, &mut tail, &[])?;
+                    let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
                     if member.name == b"/" {
                         // COFF linker member.
                         file.kind = ArchiveKind::Coff;
@@ -78,7 +84,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
                         file.offset = tail;
 
                         if tail < len {
-                            let member = ArchiveMember::parse(data, &mut tail, &[])?;
+                            let member = ArchiveMember::parse(data, &mut tail, &[], thin)?;
                             if member.name == b"//" {
                                 // COFF names table.
                                 file.names = member.data(data)?;
@@ -114,6 +120,27 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
         self.kind
     }
 
+    /// Return true if the archive is a thin archive.
+    ///
+    /// The members of a thin archive do not contain the file data. Instead, the
+    /// member names are paths to the files, relative to the directory containing
+    /// the archive.
+    #[inline]
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// Return the data of the symbol table member.
+    ///
+    /// This is the `/` member for GNU archives, the second `/` member for COFF
+    /// archives, and the `__.SYMDEF` member for BSD archives. The data is not parsed.
+    ///
+    /// Returns an empty slice if there is no symbol table member.
+    #[inline]
+    pub fn symbol_index(&self) -> &'data [u8] {
+        self.symbols
+    }
+
     /// Iterate over the members of the archive.
     ///
     /// This does not return special members.
@@ -124,6 +151,7 @@ impl<'data, R: ReadRef<'data>> ArchiveFile<'data, R> {
             offset: self.offset,
             len: self.len,
             names: self.names,
+            thin: self.thin,
         }
     }
 }
@@ -135,6 +163,7 @@ pub struct ArchiveMemberIterator<'data, R: ReadRef<'data> = &'data [u8]> {
     offset: u64,
     len: u64,
     names: &'data [u8],
+    thin: bool,
 }
 
 impl<'data, R: ReadRef<'data>> Iterator for ArchiveMemberIterator<'data, R> {
@@ -144,7 +173,7 @@ impl<'data, R: ReadRef<'data>> Iterator for ArchiveMemberIterator<'data, R> {
         if self.offset >= self.len {
             return None;
         }
-        let member = ArchiveMember::parse(self.data, &mut self.offset, self.names);
+        let member = ArchiveMember::parse(self.data, &mut self.offset, self.names, self.thin);
         if member.is_err() {
             self.offset = self.len;
         }
@@ -159,16 +188,20 @@ pub struct ArchiveMember<'data> {
     name: &'data [u8],
     offset: u64,
     size: u64,
+    thin: bool,
 }
 
 impl<'data> ArchiveMember<'data> {
     /// Parse the archive member header, name, and file data.
     ///
     /// This reads the extended name (if any) and adjusts the file size.
+    ///
+    /// If `thin` is true, then the file data is only present for special members.
     fn parse<R: ReadRef<'data>>(
         data: R,
         offset: &mut u64,
         names: &'data [u8],
+        thin: bool,
     ) -> read::Result<Self> {
         let header = data
             .read::<archive::Header>(offset)
@@ -180,12 +213,17 @@ impl<'data> ArchiveMember<'data> {
         let mut file_offset = *offset;
         let mut file_size =
             parse_u64_digits(&header.size, 10).read_error("Invalid archive member size")?;
-        *offset = offset
-            .checked_add(file_size)
-            .read_error("Archive member size is too large")?;
-        // Entries are padded to an even number of bytes.
-        if (file_size & 1) != 0 {
-            *offset = offset.saturating_add(1);
+        // Special members such as the symbol table and names table have names
+        // starting with '/', followed by a space or another '/'.
+        let thin = thin && !(header.name[0] == b'/' && matches!(header.name[1], b' ' | b'/'));
+        if !thin {
+            *offset = offset
+                .checked_add(file_size)
+                .read_error("Archive member size is too large")?;
+            // Entries are padded to an even number of bytes.
+            if (file_size & 1) != 0 {
+                *offset = offset.saturating_add(1);
+            }
         }
 
         let name = if header.name[0] == b'/' && (header.name[1] as char).is_digit(10) {
@@ -212,6 +250,7 @@ impl<'data> ArchiveMember<'data> {
             name,
             offset: file_offset,
             size: file_size,
+            thin,
         })
     }
 
@@ -253,14 +292,48 @@ impl<'data> ArchiveMember<'data> {
         parse_u64_digits(&self.header.mode, 8)
     }
 
+    /// Return true if the member is in a thin archive, and so does not contain the
+    /// file data.
+    ///
+    /// The file name of such a member is a path relative to the directory containing
+    /// the archive. Use `thin_path` to obtain the full path, and read the file data from
+    /// that path. The file may itself be a thin archive.
+    #[inline]
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// Return the path of the file for a member of a thin archive.
+    ///
+    /// `directory` is the directory containing the archive.
+    ///
+    /// Returns an error if the name is not valid UTF-8.
+    #[cfg(feature = "std")]
+    pub fn thin_path(&self, directory: &std::path::Path) -> read::Result<std::path::PathBuf> {
+        let name = core::str::from_utf8(self.name)
+            .ok()
+            .read_error("Non UTF-8 archive member name")?;
+        Ok(directory.join(name))
+    }
+
     /// Return the offset and size of the file data.
+    ///
+    /// For members of thin archives, the size is the size of the referenced file,
+    /// and the offset is not meaningful.
     pub fn file_range(&self) -> (u64, u64) {
         (self.offset, self.size)
     }
 
     /// Return the file data.
+    ///
+    /// Returns an error for members of thin archives.
     #[inline]
     pub fn data<R: ReadRef<'data>>(&self, data: R) -> read::Result<&'data [u8]> {
+        if self.thin {
+            return Err(Error(
+                "Archive member data is not present in a thin archive",
+            ));
+        }
         data.read_bytes_at(self.offset, self.size)
             .read_error("Archive member size is too large")
     }
@@ -290,10 +363,16 @@ fn parse_sysv_extended_name<'data>(digits: &[u8], names: &'data [u8]) -> Result<
     let offset = parse_u64_digits(digits, 10).ok_or(())?;
     let offset = offset.try_into().map_err(|_| ())?;
     let name_data = names.get(offset..).ok_or(())?;
-    let name = match name_data.iter().position(|&x| x == b'/' || x == 0) {
+    // GNU names are terminated by "/\n", and COFF names are terminated by a null.
+    // Names in thin archives may be paths, so they may contain '/'.
+    let name = match name_data.iter().position(|&x| x == b'\n' || x == 0) {
         Some(len) => &name_data[..len],
         None => name_data,
     };
+    let name = match name.last() {
+        Some(b'/') => &name[..name.len() - 1],
+        _ => name,
+    };
     Ok(name)
 }
 
@@ -380,6 +459,34 @@ mod tests {
         assert_eq!(archive.kind(), ArchiveKind::Coff);
     }
 
+    #[test]
+    fn symbol_index() {
+        let data = b"\
+            !<arch>\n\
+            /                                               4         `\n\
+            0123\
+            a.o/            0           0     0     644     4         `\n\
+            data";
+        let data = &data[..];
+        let archive = ArchiveFile::parse(data).unwrap();
+        assert_eq!(archive.symbol_index(), &b"0123"[..]);
+        let mut members = archive.members();
+        let member = members.next().unwrap().unwrap();
+        assert_eq!(member.name(), b"a.o");
+        assert!(members.next().is_none());
+
+        let data = b"\
+            !<arch>\n\
+            #1/9                                            13        `\n\
+            __.SYMDEF0123";
+        let archive = ArchiveFile::parse(&data[..]).unwrap();
+        assert_eq!(archive.symbol_index(), &b"0123"[..]);
+
+        let data = b"!<arch>\n";
+        let archive = ArchiveFile::parse(&data[..]).unwrap();
+        assert!(archive.symbol_index().is_empty());
+    }
+
     #[test]
     fn gnu_names() {
         let data = b"\
@@ -406,6 +513,38 @@ mod tests {
         assert!(members.next().is_none());
     }
 
+    #[test]
+    fn thin() {
+        let data = b"\
+            !<thin>\n\
+            //                                              14        `\n\
+            dir/a.o/\nb.o/\n\
+            /0              0           0     0     644     3         `\n\
+            /9              0           0     0     644     4         `\n";
+        let data = &data[..];
+        let archive = ArchiveFile::parse(data).unwrap();
+        assert_eq!(archive.kind(), ArchiveKind::Gnu);
+        assert!(archive.is_thin());
+        let mut members = archive.members();
+
+        let member = members.next().unwrap().unwrap();
+        assert!(member.is_thin());
+        assert_eq!(member.name(), b"dir/a.o");
+        assert_eq!(member.file_range().1, 3);
+        assert!(member.data(data).is_err());
+        #[cfg(feature = "std")]
+        assert_eq!(
+            member.thin_path(std::path::Path::new("lib")).unwrap(),
+            std::path::Path::new("lib/dir/a.o")
+        );
+
+        let member = members.next().unwrap().unwrap();
+        assert_eq!(member.name(), b"b.o");
+        assert_eq!(member.file_range().1, 4);
+
+        assert!(members.next().is_none());
+    }
+
     #[test]
     fn bsd_names() {
         let data = b"\
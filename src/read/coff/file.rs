@@ -2,15 +2,15 @@ use alloc::vec::Vec;
 use core::str;
 
 use crate::read::{
-    self, Architecture, Export, FileFlags, Import, NoDynamicRelocationIterator, Object,
+    self, Architecture, Error, Export, FileFlags, Import, NoDynamicRelocationIterator, Object,
     ObjectSection, ReadError, ReadRef, Result, SectionIndex, SymbolIndex,
 };
 use crate::{pe, LittleEndian as LE};
 
 use super::{
-    CoffComdat, CoffComdatIterator, CoffSection, CoffSectionIterator, CoffSegment,
-    CoffSegmentIterator, CoffSymbol, CoffSymbolIterator, CoffSymbolTable, SectionTable,
-    SymbolTable,
+    CoffComdat, CoffComdatIterator, CoffSection, CoffSectionGroup, CoffSectionIterator,
+    CoffSegment, CoffSegmentIterator, CoffSymbol, CoffSymbolIterator, CoffSymbolTable,
+    SectionTable, SymbolTable,
 };
 
 /// The common parts of `PeFile` and `CoffFile`.
@@ -48,6 +48,65 @@ impl<'data, R: ReadRef<'data>> CoffFile<'data, R> {
             data,
         })
     }
+
+    /// Parse the raw COFF file data, recovering from errors where possible.
+    ///
+    /// Errors in the file header are fatal. Errors in the section table and
+    /// symbol table are added to `errors`, and the corresponding tables are
+    /// treated as empty.
+    pub fn parse_lenient(data: R, errors: &mut Vec<Error>) -> Result<Self> {
+        let mut offset = 0;
+        let header = pe::ImageFileHeader::parse(data, &mut offset)?;
+        let sections = read::recover(header.sections(data, offset), errors);
+        let symbols = read::recover(header.symbols(data), errors);
+
+        Ok(CoffFile {
+            header,
+            common: CoffCommon {
+                sections,
+                symbols,
+                image_base: 0,
+            },
+            data,
+        })
+    }
+}
+
+impl<'data, R: ReadRef<'data>> CoffFile<'data, R> {
+    /// Return the sections grouped by the portion of their name before any `$`.
+    ///
+    /// This matches how the linker merges grouped sections such as `.text$mn` and
+    /// `.text$di` into `.text`. Within each group, sections are sorted by the
+    /// portion of their name after the `$` using a byte-wise lexicographic
+    /// comparison, and sections without a `$` sort first. Sections with the same
+    /// name remain in the order they appear in the file.
+    ///
+    /// Groups are returned in the order that their first section appears in the file.
+    pub fn grouped_sections<'file>(&'file self) -> Result<Vec<CoffSectionGroup<'data, 'file, R>>> {
+        let strings = self.common.symbols.strings();
+        let mut groups: Vec<CoffSectionGroup<'data, 'file, R>> = Vec::new();
+        for section in self.sections() {
+            let name = section.section.name(strings)?;
+            let base = match name.iter().position(|&b| b == b'$') {
+                Some(end) => &name[..end],
+                None => name,
+            };
+            match groups.iter_mut().find(|group| group.name == base) {
+                Some(group) => group.sections.push(section),
+                None => groups.push(CoffSectionGroup {
+                    name: base,
+                    sections: vec![section],
+                }),
+            }
+        }
+        for group in &mut groups {
+            // This is a stable sort, so sections with the same name keep their order.
+            group
+                .sections
+                .sort_by_key(|section| section.section.name(strings).unwrap_or(&[]));
+        }
+        Ok(groups)
+    }
 }
 
 impl<'data, R: ReadRef<'data>> read::private::Sealed for CoffFile<'data, R> {}
@@ -72,6 +131,10 @@ where
         match self.header.machine.get(LE) {
             pe::IMAGE_FILE_MACHINE_I386 => Architecture::I386,
             pe::IMAGE_FILE_MACHINE_AMD64 => Architecture::X86_64,
+            pe::IMAGE_FILE_MACHINE_ARMNT => Architecture::Arm,
+            pe::IMAGE_FILE_MACHINE_ARM64 => Architecture::Aarch64,
+            pe::IMAGE_FILE_MACHINE_RISCV32 => Architecture::Riscv32,
+            pe::IMAGE_FILE_MACHINE_RISCV64 => Architecture::Riscv64,
             _ => Architecture::Unknown,
         }
     }
@@ -87,6 +150,23 @@ where
         false
     }
 
+    #[inline]
+    fn pointer_size(&self) -> u8 {
+        self.architecture()
+            .address_size()
+            .map_or(4, |size| size.bytes())
+    }
+
+    #[inline]
+    fn is_relocatable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn default_page_size(&self) -> u64 {
+        0x1000
+    }
+
     fn segments(&'file self) -> CoffSegmentIterator<'data, 'file, R> {
         CoffSegmentIterator {
             file: self,
@@ -115,6 +195,15 @@ where
         }
     }
 
+    fn section_by_address(&'file self, address: u64) -> Option<CoffSection<'data, 'file, R>> {
+        // Debugging sections are readable, but they are discarded by the linker.
+        self.sections().find(|section| {
+            section.section.characteristics.get(LE) & pe::IMAGE_SCN_MEM_DISCARDABLE == 0
+                && section.permissions().read()
+                && address.wrapping_sub(section.address()) < section.size()
+        })
+    }
+
     fn comdats(&'file self) -> CoffComdatIterator<'data, 'file, R> {
         CoffComdatIterator {
             file: self,
@@ -177,6 +266,13 @@ where
         self.section_by_name(".debug_info").is_some()
     }
 
+    fn timestamp(&self) -> Option<u64> {
+        match self.header.time_date_stamp.get(LE) {
+            0 => None,
+            timestamp => Some(timestamp.into()),
+        }
+    }
+
     #[inline]
     fn entry(&self) -> u64 {
         0
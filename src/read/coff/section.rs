@@ -1,11 +1,13 @@
+use alloc::vec::Vec;
 use core::{iter, result, slice, str};
 
 use crate::endian::LittleEndian as LE;
 use crate::pe;
 use crate::read::util::StringTable;
 use crate::read::{
-    self, CompressedData, CompressedFileRange, Error, ObjectSection, ObjectSegment, ReadError,
-    ReadRef, Result, SectionFlags, SectionIndex, SectionKind,
+    self, CompressedData, CompressedFileRange, Error, ObjectSection, ObjectSegment, Permissions,
+    RawRelocation, ReadError, ReadRef, Result, SectionFlags, SectionIndex, SectionKind,
+    SymbolIndex,
 };
 
 use super::{CoffFile, CoffRelocationIterator};
@@ -158,6 +160,11 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSegment<'data> for CoffSegment<'data
                 .read_error("Non UTF-8 COFF section name")?,
         ))
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        self.section.permissions()
+    }
 }
 
 /// An iterator over the sections of a `CoffFile`.
@@ -187,12 +194,45 @@ pub struct CoffSection<'data, 'file, R: ReadRef<'data> = &'data [u8]> {
     pub(super) section: &'data pe::ImageSectionHeader,
 }
 
+/// A group of sections in a `CoffFile` that the linker merges into one section.
+///
+/// Returned by `CoffFile::grouped_sections`.
+#[derive(Debug)]
+pub struct CoffSectionGroup<'data, 'file, R: ReadRef<'data> = &'data [u8]> {
+    pub(super) name: &'data [u8],
+    pub(super) sections: Vec<CoffSection<'data, 'file, R>>,
+}
+
+impl<'data, 'file, R: ReadRef<'data>> CoffSectionGroup<'data, 'file, R> {
+    /// The name of the merged section.
+    ///
+    /// This is the portion of the section names before the `$`.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name
+    }
+
+    /// The sections in the group, in the order that the linker merges them.
+    #[inline]
+    pub fn sections(&self) -> &[CoffSection<'data, 'file, R>] {
+        &self.sections
+    }
+}
+
 impl<'data, 'file, R: ReadRef<'data>> CoffSection<'data, 'file, R> {
     fn bytes(&self) -> Result<&'data [u8]> {
         self.section
             .coff_data(self.file.data)
             .read_error("Invalid COFF section offset or size")
     }
+
+    /// Return the line number entries of the section.
+    ///
+    /// Returns an empty `Vec` if the section has no line numbers.
+    pub fn line_numbers(&self) -> Result<Vec<LineNumber>> {
+        let entries = self.section.coff_line_numbers(self.file.data)?;
+        Ok(parse_line_numbers(entries))
+    }
 }
 
 impl<'data, 'file, R: ReadRef<'data>> read::private::Sealed for CoffSection<'data, 'file, R> {}
@@ -276,11 +316,28 @@ impl<'data, 'file, R: ReadRef<'data>> ObjectSection<'data> for CoffSection<'data
         }
     }
 
+    fn raw_relocations(&self) -> Result<Vec<RawRelocation>> {
+        let relocations = self.section.coff_relocations(self.file.data)?;
+        Ok(relocations
+            .iter()
+            .map(|relocation| RawRelocation::Coff {
+                virtual_address: relocation.virtual_address.get(LE),
+                symbol_table_index: relocation.symbol_table_index.get(LE),
+                typ: relocation.typ.get(LE),
+            })
+            .collect())
+    }
+
     fn flags(&self) -> SectionFlags {
         SectionFlags::Coff {
             characteristics: self.section.characteristics.get(LE),
         }
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        self.section.permissions()
+    }
 }
 
 impl pe::ImageSectionHeader {
@@ -289,7 +346,11 @@ impl pe::ImageSectionHeader {
         if characteristics & (pe::IMAGE_SCN_CNT_CODE | pe::IMAGE_SCN_MEM_EXECUTE) != 0 {
             SectionKind::Text
         } else if characteristics & pe::IMAGE_SCN_CNT_INITIALIZED_DATA != 0 {
-            if characteristics & pe::IMAGE_SCN_MEM_DISCARDABLE != 0 {
+            let name = self.raw_name();
+            let name = name.split(|&c| c == b'$').next().unwrap_or(name);
+            if name == b".pdata" || name == b".xdata" {
+                SectionKind::Unwind
+            } else if characteristics & pe::IMAGE_SCN_MEM_DISCARDABLE != 0 {
                 SectionKind::Other
             } else if characteristics & pe::IMAGE_SCN_MEM_WRITE != 0 {
                 SectionKind::Data
@@ -304,6 +365,19 @@ impl pe::ImageSectionHeader {
             SectionKind::Unknown
         }
     }
+
+    pub(crate) fn permissions(&self) -> Permissions {
+        let characteristics = self.characteristics.get(LE);
+        // These sections contain information for the linker and are not loaded.
+        if characteristics & (pe::IMAGE_SCN_LNK_INFO | pe::IMAGE_SCN_LNK_REMOVE) != 0 {
+            return Permissions::default();
+        }
+        Permissions::new(
+            characteristics & pe::IMAGE_SCN_MEM_READ != 0,
+            characteristics & pe::IMAGE_SCN_MEM_WRITE != 0,
+            characteristics & pe::IMAGE_SCN_MEM_EXECUTE != 0,
+        )
+    }
 }
 
 impl pe::ImageSectionHeader {
@@ -313,7 +387,7 @@ impl pe::ImageSectionHeader {
     pub fn name<'data>(&'data self, strings: StringTable<'data>) -> Result<&'data [u8]> {
         let bytes = &self.name;
         Ok(if bytes[0] == b'/' {
-            let mut offset = 0;
+            let mut offset = 0u32;
             if bytes[1] == b'/' {
                 for byte in bytes[2..].iter() {
                     let digit = match byte {
@@ -324,7 +398,10 @@ impl pe::ImageSectionHeader {
                         b'/' => 63,
                         _ => return Err(Error("Invalid COFF section name base-64 offset")),
                     };
-                    offset = offset * 64 + digit as u32;
+                    offset = offset
+                        .checked_mul(64)
+                        .and_then(|offset| offset.checked_add(digit as u32))
+                        .read_error("Invalid COFF section name base-64 offset")?;
                 }
             } else {
                 for byte in bytes[1..].iter() {
@@ -414,4 +491,54 @@ impl pe::ImageSectionHeader {
         data.read_slice_at(pointer, number)
             .read_error("Invalid COFF relocation offset or number")
     }
+
+    /// Read the line number entries in a COFF or PE file.
+    ///
+    /// `data` must be the entire file data.
+    pub fn coff_line_numbers<'data, R: ReadRef<'data>>(
+        &self,
+        data: R,
+    ) -> read::Result<&'data [pe::ImageLinenumber]> {
+        let pointer = self.pointer_to_linenumbers.get(LE).into();
+        let number = self.number_of_linenumbers.get(LE).into();
+        data.read_slice_at(pointer, number)
+            .read_error("Invalid COFF line number offset or number")
+    }
+}
+
+/// A COFF line number entry.
+///
+/// These are the line numbers from the `IMAGE_LINENUMBER` table of a section.
+/// The entries for each function start with a `Function` entry, followed by the
+/// `Line` entries for the function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineNumber {
+    /// The start of the line numbers for a function.
+    ///
+    /// Contains the symbol table index of the function.
+    Function(SymbolIndex),
+    /// A line number within the current function.
+    Line {
+        /// The virtual address of the code for the line.
+        address: u32,
+        /// The line number, relative to the starting line of the function.
+        line: u16,
+    },
+}
+
+/// Convert the `IMAGE_LINENUMBER` entries to `LineNumber`s.
+pub(crate) fn parse_line_numbers(entries: &[pe::ImageLinenumber]) -> Vec<LineNumber> {
+    entries
+        .iter()
+        .map(|entry| {
+            let value = entry.symbol_table_index_or_virtual_address.get(LE);
+            match entry.linenumber.get(LE) {
+                0 => LineNumber::Function(SymbolIndex(value as usize)),
+                line => LineNumber::Line {
+                    address: value,
+                    line,
+                },
+            }
+        })
+        .collect()
 }
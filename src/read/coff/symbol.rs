@@ -16,7 +16,7 @@ use crate::read::{
 /// A table of symbol entries in a COFF or PE file.
 ///
 /// Also includes the string table used for the symbol names.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SymbolTable<'data> {
     symbols: &'data [pe::ImageSymbolBytes],
     strings: StringTable<'data>,
@@ -197,7 +197,7 @@ impl pe::ImageSymbol {
         let section = sections.section(section_number)?;
         let virtual_address = u64::from(section.virtual_address.get(LE));
         let value = u64::from(self.value.get(LE));
-        Ok(image_base + virtual_address + value)
+        Ok(image_base.wrapping_add(virtual_address).wrapping_add(value))
     }
 
     /// Return true if the symbol is a definition of a function or data object.
@@ -302,6 +302,21 @@ pub struct CoffSymbol<'data, 'file> {
     pub(crate) symbol: &'data pe::ImageSymbol,
 }
 
+impl<'data, 'file> CoffSymbol<'data, 'file> {
+    /// Return the auxiliary section definition record for a section symbol.
+    ///
+    /// This contains the section length, the number of relocations and line numbers,
+    /// the COMDAT checksum, and the COMDAT selection.
+    ///
+    /// Returns `None` if the symbol is not a section symbol, or if the record is invalid.
+    pub fn aux_section_definition(&self) -> Option<&'data pe::ImageAuxSymbolSection> {
+        if !self.symbol.has_aux_section() {
+            return None;
+        }
+        self.file.symbols.aux_section(self.index.0).ok()
+    }
+}
+
 impl<'data, 'file> read::private::Sealed for CoffSymbol<'data, 'file> {}
 
 impl<'data, 'file> ObjectSymbol<'data> for CoffSymbol<'data, 'file> {
@@ -0,0 +1,313 @@
+//! Minimal DWARF parsing needed to locate split DWARF objects and to
+//! determine the DWARF version.
+//!
+//! This only reads the attributes of the first DIE of the first unit in
+//! `.debug_info`, and only supports the attribute forms that are used in
+//! skeleton units. Full DWARF parsing is provided by the `gimli` crate.
+
+use crate::endian::{Endianness, U16Bytes, U32Bytes, U64Bytes};
+use crate::pod::Bytes;
+use crate::read::{Error, ReadError, Result};
+
+const DW_UT_SKELETON: u8 = 0x04;
+
+const DW_AT_STR_OFFSETS_BASE: u64 = 0x72;
+const DW_AT_DWO_NAME: u64 = 0x76;
+const DW_AT_GNU_DWO_NAME: u64 = 0x2130;
+
+const DW_FORM_ADDR: u64 = 0x01;
+const DW_FORM_DATA2: u64 = 0x05;
+const DW_FORM_DATA4: u64 = 0x06;
+const DW_FORM_DATA8: u64 = 0x07;
+const DW_FORM_STRING: u64 = 0x08;
+const DW_FORM_DATA1: u64 = 0x0b;
+const DW_FORM_FLAG: u64 = 0x0c;
+const DW_FORM_SDATA: u64 = 0x0d;
+const DW_FORM_STRP: u64 = 0x0e;
+const DW_FORM_UDATA: u64 = 0x0f;
+const DW_FORM_SEC_OFFSET: u64 = 0x17;
+const DW_FORM_FLAG_PRESENT: u64 = 0x19;
+const DW_FORM_STRX: u64 = 0x1a;
+const DW_FORM_ADDRX: u64 = 0x1b;
+const DW_FORM_LINE_STRP: u64 = 0x1f;
+const DW_FORM_IMPLICIT_CONST: u64 = 0x21;
+const DW_FORM_RNGLISTX: u64 = 0x23;
+const DW_FORM_STRX1: u64 = 0x25;
+const DW_FORM_STRX2: u64 = 0x26;
+const DW_FORM_STRX4: u64 = 0x28;
+const DW_FORM_ADDRX1: u64 = 0x29;
+const DW_FORM_ADDRX2: u64 = 0x2a;
+const DW_FORM_ADDRX4: u64 = 0x2c;
+const DW_FORM_GNU_ADDR_INDEX: u64 = 0x1f01;
+const DW_FORM_GNU_STR_INDEX: u64 = 0x1f02;
+
+/// The DWARF sections needed by `dwo_name`.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct DwarfSections<'data> {
+    pub debug_info: &'data [u8],
+    pub debug_abbrev: &'data [u8],
+    pub debug_str: &'data [u8],
+    pub debug_str_offsets: &'data [u8],
+}
+
+/// Return the version of the first unit in `.debug_info`.
+///
+/// Returns `None` if the section is empty or the unit header is invalid.
+pub(crate) fn unit_version(endian: Endianness, debug_info: &[u8]) -> Option<u16> {
+    let mut info = Bytes(debug_info);
+    let unit_length = read_u32(&mut info, endian).ok()?;
+    if unit_length == 0xffff_ffff {
+        read_u64(&mut info, endian).ok()?;
+    } else if unit_length >= 0xffff_fff0 {
+        return None;
+    }
+    read_u16(&mut info, endian).ok()
+}
+
+/// Return the split DWARF object name of the first unit in `.debug_info`.
+///
+/// Returns `Ok(None)` if the unit does not have a `DW_AT_dwo_name` or
+/// `DW_AT_GNU_dwo_name` attribute.
+pub(crate) fn dwo_name<'data>(
+    endian: Endianness,
+    sections: &DwarfSections<'data>,
+) -> Result<Option<&'data [u8]>> {
+    let mut info = Bytes(sections.debug_info);
+    if info.is_empty() {
+        return Ok(None);
+    }
+
+    let mut offset_size = 4;
+    let mut unit_length = u64::from(read_u32(&mut info, endian)?);
+    if unit_length == 0xffff_ffff {
+        offset_size = 8;
+        unit_length = read_u64(&mut info, endian)?;
+    } else if unit_length >= 0xffff_fff0 {
+        return Err(Error("Invalid DWARF unit length"));
+    }
+    let mut unit = info
+        .read_bytes(unit_length as usize)
+        .read_error("Invalid DWARF unit length")?;
+    let (abbrev_offset, address_size) = match read_u16(&mut unit, endian)? {
+        2..=4 => {
+            let abbrev_offset = read_offset(&mut unit, endian, offset_size)?;
+            (abbrev_offset, read_u8(&mut unit)?)
+        }
+        5 => {
+            // Only skeleton units have a dwo_name in DWARF 5.
+            if read_u8(&mut unit)? != DW_UT_SKELETON {
+                return Ok(None);
+            }
+            let address_size = read_u8(&mut unit)?;
+            let abbrev_offset = read_offset(&mut unit, endian, offset_size)?;
+            // The dwo_id.
+            read_u64(&mut unit, endian)?;
+            (abbrev_offset, address_size)
+        }
+        _ => return Err(Error("Unsupported DWARF version")),
+    };
+
+    let code = read_uleb128(&mut unit)?;
+    if code == 0 {
+        return Ok(None);
+    }
+    let mut abbrev = find_abbreviation(sections.debug_abbrev, abbrev_offset, code)?;
+
+    // The string offsets base may follow the name, so string indices are resolved last.
+    let mut name = None;
+    let mut name_index = None;
+    let mut str_offsets_base = None;
+    loop {
+        let attr = read_uleb128(&mut abbrev)?;
+        let form = read_uleb128(&mut abbrev)?;
+        if attr == 0 && form == 0 {
+            break;
+        }
+        if form == DW_FORM_IMPLICIT_CONST {
+            read_sleb128(&mut abbrev)?;
+            continue;
+        }
+        match attr {
+            DW_AT_DWO_NAME | DW_AT_GNU_DWO_NAME => match form {
+                DW_FORM_STRING => {
+                    name = Some(
+                        unit.read_string()
+                            .read_error("Invalid DWARF inline string")?,
+                    );
+                }
+                DW_FORM_STRP => {
+                    let offset = read_offset(&mut unit, endian, offset_size)?;
+                    name = Some(read_string_at(sections.debug_str, offset)?);
+                }
+                DW_FORM_STRX | DW_FORM_GNU_STR_INDEX => {
+                    name_index = Some(read_uleb128(&mut unit)?);
+                }
+                DW_FORM_STRX1 => name_index = Some(read_u8(&mut unit)?.into()),
+                DW_FORM_STRX2 => name_index = Some(read_u16(&mut unit, endian)?.into()),
+                DW_FORM_STRX4 => name_index = Some(read_u32(&mut unit, endian)?.into()),
+                _ => return Err(Error("Unsupported DWARF form for dwo name")),
+            },
+            DW_AT_STR_OFFSETS_BASE if form == DW_FORM_SEC_OFFSET => {
+                str_offsets_base = Some(read_offset(&mut unit, endian, offset_size)?);
+            }
+            _ => skip_value(&mut unit, offset_size, address_size, form)?,
+        }
+    }
+
+    if let Some(index) = name_index {
+        let base =
+            str_offsets_base.read_error("Missing DWARF DW_AT_str_offsets_base for string index")?;
+        let mut entry = Bytes(sections.debug_str_offsets);
+        index
+            .checked_mul(offset_size as u64)
+            .and_then(|offset| offset.checked_add(base))
+            .and_then(|offset| entry.skip(offset as usize).ok())
+            .read_error("Invalid DWARF string index")?;
+        let offset = read_offset(&mut entry, endian, offset_size)?;
+        name = Some(read_string_at(sections.debug_str, offset)?);
+    }
+    Ok(name)
+}
+
+/// Return the attribute specifications for the abbreviation with the given code.
+fn find_abbreviation<'data>(
+    debug_abbrev: &'data [u8],
+    offset: u64,
+    code: u64,
+) -> Result<Bytes<'data>> {
+    let mut abbrev = Bytes(debug_abbrev);
+    abbrev
+        .skip(offset as usize)
+        .read_error("Invalid DWARF abbreviation offset")?;
+    loop {
+        let next = read_uleb128(&mut abbrev)?;
+        if next == 0 {
+            return Err(Error("Missing DWARF abbreviation"));
+        }
+        // The tag and the children flag.
+        read_uleb128(&mut abbrev)?;
+        read_u8(&mut abbrev)?;
+        if next == code {
+            return Ok(abbrev);
+        }
+        loop {
+            let attr = read_uleb128(&mut abbrev)?;
+            let form = read_uleb128(&mut abbrev)?;
+            if attr == 0 && form == 0 {
+                break;
+            }
+            if form == DW_FORM_IMPLICIT_CONST {
+                read_sleb128(&mut abbrev)?;
+            }
+        }
+    }
+}
+
+/// Skip an attribute value.
+///
+/// Only the forms that are used by the attributes of skeleton units are supported.
+fn skip_value(data: &mut Bytes, offset_size: usize, address_size: u8, form: u64) -> Result<()> {
+    let size = match form {
+        DW_FORM_FLAG_PRESENT => 0,
+        DW_FORM_DATA1 | DW_FORM_FLAG | DW_FORM_STRX1 | DW_FORM_ADDRX1 => 1,
+        DW_FORM_DATA2 | DW_FORM_STRX2 | DW_FORM_ADDRX2 => 2,
+        DW_FORM_DATA4 | DW_FORM_STRX4 | DW_FORM_ADDRX4 => 4,
+        DW_FORM_DATA8 => 8,
+        DW_FORM_ADDR => address_size.into(),
+        DW_FORM_STRP | DW_FORM_LINE_STRP | DW_FORM_SEC_OFFSET => offset_size,
+        DW_FORM_UDATA
+        | DW_FORM_STRX
+        | DW_FORM_ADDRX
+        | DW_FORM_RNGLISTX
+        | DW_FORM_GNU_ADDR_INDEX
+        | DW_FORM_GNU_STR_INDEX => {
+            read_uleb128(data)?;
+            0
+        }
+        DW_FORM_SDATA => {
+            read_sleb128(data)?;
+            0
+        }
+        DW_FORM_STRING => {
+            data.read_string()
+                .read_error("Invalid DWARF inline string")?;
+            0
+        }
+        _ => return Err(Error("Unsupported DWARF form")),
+    };
+    data.skip(size).read_error("Invalid DWARF attribute size")
+}
+
+fn read_string_at(data: &[u8], offset: u64) -> Result<&[u8]> {
+    Bytes(data)
+        .read_string_at(offset as usize)
+        .read_error("Invalid DWARF string offset")
+}
+
+fn read_u8(data: &mut Bytes) -> Result<u8> {
+    data.read::<u8>()
+        .ok()
+        .copied()
+        .read_error("Unexpected end of DWARF data")
+}
+
+fn read_u16(data: &mut Bytes, endian: Endianness) -> Result<u16> {
+    data.read::<U16Bytes<Endianness>>()
+        .map(|x| x.get(endian))
+        .read_error("Unexpected end of DWARF data")
+}
+
+fn read_u32(data: &mut Bytes, endian: Endianness) -> Result<u32> {
+    data.read::<U32Bytes<Endianness>>()
+        .map(|x| x.get(endian))
+        .read_error("Unexpected end of DWARF data")
+}
+
+fn read_u64(data: &mut Bytes, endian: Endianness) -> Result<u64> {
+    data.read::<U64Bytes<Endianness>>()
+        .map(|x| x.get(endian))
+        .read_error("Unexpected end of DWARF data")
+}
+
+fn read_offset(data: &mut Bytes, endian: Endianness, offset_size: usize) -> Result<u64> {
+    if offset_size == 8 {
+        read_u64(data, endian)
+    } else {
+        read_u32(data, endian).map(u64::from)
+    }
+}
+
+pub(crate) fn read_uleb128(data: &mut Bytes) -> Result<u64> {
+    let mut result = 0;
+    let mut shift = 0;
+    loop {
+        let byte = read_u8(data)?;
+        if shift == 63 && byte > 1 {
+            return Err(Error("Invalid DWARF ULEB128 value"));
+        }
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+pub(crate) fn read_sleb128(data: &mut Bytes) -> Result<i64> {
+    let mut result = 0;
+    let mut shift = 0;
+    loop {
+        let byte = read_u8(data)?;
+        if shift == 63 && byte != 0 && byte != 0x7f {
+            return Err(Error("Invalid DWARF SLEB128 value"));
+        }
+        result |= i64::from(byte & 0x7f) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            if shift < 64 && (byte & 0x40) != 0 {
+                result |= !0 << shift;
+            }
+            return Ok(result);
+        }
+    }
+}
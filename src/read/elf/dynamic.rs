@@ -43,3 +43,92 @@ impl<Endian: endian::Endian> Dyn for elf::Dyn64<Endian> {
         self.d_val.get(endian)
     }
 }
+
+/// The flags in the `DT_FLAGS` dynamic entry.
+///
+/// Returned by `ElfFile::df_flags`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DfFlags(pub(super) u32);
+
+impl DfFlags {
+    /// The raw value of the flags.
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// `DF_ORIGIN`: the object may use `$ORIGIN`.
+    #[inline]
+    pub fn origin(&self) -> bool {
+        self.0 & elf::DF_ORIGIN != 0
+    }
+
+    /// `DF_SYMBOLIC`: symbol resolution starts with the object itself.
+    #[inline]
+    pub fn symbolic(&self) -> bool {
+        self.0 & elf::DF_SYMBOLIC != 0
+    }
+
+    /// `DF_TEXTREL`: relocations may modify a non-writable segment.
+    #[inline]
+    pub fn textrel(&self) -> bool {
+        self.0 & elf::DF_TEXTREL != 0
+    }
+
+    /// `DF_BIND_NOW`: all relocations must be processed before control is
+    /// transferred to the program.
+    #[inline]
+    pub fn bind_now(&self) -> bool {
+        self.0 & elf::DF_BIND_NOW != 0
+    }
+
+    /// `DF_STATIC_TLS`: the object uses the static TLS model.
+    #[inline]
+    pub fn static_tls(&self) -> bool {
+        self.0 & elf::DF_STATIC_TLS != 0
+    }
+}
+
+/// The flags in the `DT_FLAGS_1` dynamic entry.
+///
+/// Returned by `ElfFile::df_flags_1`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DfFlags1(pub(super) u32);
+
+impl DfFlags1 {
+    /// The raw value of the flags.
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// `DF_1_NOW`: all relocations must be processed when the object is loaded.
+    #[inline]
+    pub fn now(&self) -> bool {
+        self.0 & elf::DF_1_NOW != 0
+    }
+
+    /// `DF_1_NODELETE`: the object can't be unloaded.
+    #[inline]
+    pub fn nodelete(&self) -> bool {
+        self.0 & elf::DF_1_NODELETE != 0
+    }
+
+    /// `DF_1_NOOPEN`: the object can't be loaded with `dlopen`.
+    #[inline]
+    pub fn noopen(&self) -> bool {
+        self.0 & elf::DF_1_NOOPEN != 0
+    }
+
+    /// `DF_1_ORIGIN`: the object may use `$ORIGIN`.
+    #[inline]
+    pub fn origin(&self) -> bool {
+        self.0 & elf::DF_1_ORIGIN != 0
+    }
+
+    /// `DF_1_PIE`: the object is a position independent executable.
+    #[inline]
+    pub fn pie(&self) -> bool {
+        self.0 & elf::DF_1_PIE != 0
+    }
+}
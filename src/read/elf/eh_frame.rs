@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+
+use crate::endian::{Endian, U16Bytes, U32Bytes, U64Bytes};
+use crate::pod::Bytes;
+use crate::read::{self, dwarf, Error, ReadError};
+
+// Values for the low 4 bits of a pointer encoding.
+const DW_EH_PE_ABSPTR: u8 = 0x00;
+const DW_EH_PE_ULEB128: u8 = 0x01;
+const DW_EH_PE_UDATA2: u8 = 0x02;
+const DW_EH_PE_UDATA4: u8 = 0x03;
+const DW_EH_PE_UDATA8: u8 = 0x04;
+const DW_EH_PE_SLEB128: u8 = 0x09;
+const DW_EH_PE_SDATA2: u8 = 0x0a;
+const DW_EH_PE_SDATA4: u8 = 0x0b;
+const DW_EH_PE_SDATA8: u8 = 0x0c;
+
+// Values for bits 4-6 of a pointer encoding.
+const DW_EH_PE_PCREL: u8 = 0x10;
+const DW_EH_PE_DATAREL: u8 = 0x30;
+
+const DW_EH_PE_INDIRECT: u8 = 0x80;
+const DW_EH_PE_OMIT: u8 = 0xff;
+
+/// The contents of an ELF `.eh_frame_hdr` section.
+///
+/// Returned by `ElfFile::eh_frame_hdr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EhFrameHdr {
+    eh_frame: u64,
+    table: Vec<(u64, u64)>,
+}
+
+impl EhFrameHdr {
+    /// Parse the section data, which is loaded at the given address.
+    pub(super) fn parse<E: Endian>(
+        endian: E,
+        is_64: bool,
+        address: u64,
+        data: &[u8],
+    ) -> read::Result<Self> {
+        let reader = PointerReader {
+            endian,
+            is_64,
+            address,
+            len: data.len(),
+        };
+        let mut data = Bytes(data);
+        let header = data
+            .read_bytes(4)
+            .read_error("Invalid ELF .eh_frame_hdr size")?
+            .0;
+        if header[0] != 1 {
+            return Err(Error("Unsupported ELF .eh_frame_hdr version"));
+        }
+        let (eh_frame_ptr_enc, fde_count_enc, table_enc) = (header[1], header[2], header[3]);
+
+        let eh_frame = reader
+            .read(&mut data, eh_frame_ptr_enc)?
+            .read_error("Missing ELF .eh_frame_hdr eh_frame_ptr")?;
+        let mut table = Vec::new();
+        if table_enc != DW_EH_PE_OMIT {
+            if let Some(fde_count) = reader.read(&mut data, fde_count_enc)? {
+                for _ in 0..fde_count {
+                    let initial_location = reader
+                        .read(&mut data, table_enc)?
+                        .read_error("Invalid ELF .eh_frame_hdr table encoding")?;
+                    let fde = reader
+                        .read(&mut data, table_enc)?
+                        .read_error("Invalid ELF .eh_frame_hdr table encoding")?;
+                    table.push((initial_location, fde));
+                }
+            }
+        }
+        Ok(EhFrameHdr { eh_frame, table })
+    }
+
+    /// The address of the `.eh_frame` section.
+    #[inline]
+    pub fn eh_frame_address(&self) -> u64 {
+        self.eh_frame
+    }
+
+    /// The binary search table.
+    ///
+    /// Each entry is the initial location of a function and the address of its FDE.
+    /// The linker sorts the entries by initial location.
+    ///
+    /// This is empty if the section does not contain a table.
+    #[inline]
+    pub fn table(&self) -> &[(u64, u64)] {
+        &self.table
+    }
+
+    /// Find the address of the FDE that may contain the given address.
+    ///
+    /// This is the FDE with the greatest initial location that is less than or equal
+    /// to `address`. The caller must check the range in the FDE itself.
+    pub fn find_fde(&self, address: u64) -> Option<u64> {
+        let index = match self.table.binary_search_by_key(&address, |entry| entry.0) {
+            Ok(index) => index,
+            Err(index) => index.checked_sub(1)?,
+        };
+        Some(self.table[index].1)
+    }
+}
+
+/// Reads encoded pointers from an `.eh_frame_hdr` section.
+struct PointerReader<E: Endian> {
+    endian: E,
+    is_64: bool,
+    address: u64,
+    len: usize,
+}
+
+impl<E: Endian> PointerReader<E> {
+    /// Read a pointer with the given encoding.
+    ///
+    /// Returns `Ok(None)` if the encoding is `DW_EH_PE_omit`.
+    fn read(&self, data: &mut Bytes, encoding: u8) -> read::Result<Option<u64>> {
+        if encoding == DW_EH_PE_OMIT {
+            return Ok(None);
+        }
+        if encoding & DW_EH_PE_INDIRECT != 0 {
+            return Err(Error("Unsupported ELF .eh_frame_hdr indirect pointer"));
+        }
+        let base = match encoding & 0x70 {
+            0 => 0,
+            DW_EH_PE_PCREL => self.address.wrapping_add((self.len - data.len()) as u64),
+            DW_EH_PE_DATAREL => self.address,
+            _ => return Err(Error("Unsupported ELF .eh_frame_hdr pointer application")),
+        };
+        let value = match encoding & 0x0f {
+            DW_EH_PE_ABSPTR if self.is_64 => self.read_u64(data)?,
+            DW_EH_PE_ABSPTR => self.read_u32(data)?.into(),
+            DW_EH_PE_ULEB128 => dwarf::read_uleb128(data)?,
+            DW_EH_PE_UDATA2 => self.read_u16(data)?.into(),
+            DW_EH_PE_UDATA4 => self.read_u32(data)?.into(),
+            DW_EH_PE_UDATA8 => self.read_u64(data)?,
+            DW_EH_PE_SLEB128 => dwarf::read_sleb128(data)? as u64,
+            DW_EH_PE_SDATA2 => self.read_u16(data)? as i16 as u64,
+            DW_EH_PE_SDATA4 => self.read_u32(data)? as i32 as u64,
+            DW_EH_PE_SDATA8 => self.read_u64(data)?,
+            _ => return Err(Error("Unsupported ELF .eh_frame_hdr pointer format")),
+        };
+        Ok(Some(base.wrapping_add(value)))
+    }
+
+    fn read_u16(&self, data: &mut Bytes) -> read::Result<u16> {
+        data.read::<U16Bytes<E>>()
+            .map(|x| x.get(self.endian))
+            .read_error("Invalid ELF .eh_frame_hdr size")
+    }
+
+    fn read_u32(&self, data: &mut Bytes) -> read::Result<u32> {
+        data.read::<U32Bytes<E>>()
+            .map(|x| x.get(self.endian))
+            .read_error("Invalid ELF .eh_frame_hdr size")
+    }
+
+    fn read_u64(&self, data: &mut Bytes) -> read::Result<u64> {
+        data.read::<U64Bytes<E>>()
+            .map(|x| x.get(self.endian))
+            .read_error("Invalid ELF .eh_frame_hdr size")
+    }
+}
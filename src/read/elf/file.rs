@@ -4,13 +4,18 @@ use core::fmt::Debug;
 use core::{mem, str};
 
 use crate::read::{
-    self, util, Architecture, Error, Export, FileFlags, Import, Object, ReadError, ReadRef,
-    SectionIndex, StringTable, SymbolIndex,
+    self, util, validate, Architecture, Error, Export, FileFlags, Import, Object, ReadError,
+    ReadRef, RelocationKind, RelocationTarget, SectionIndex, StringTable, SymbolIndex,
+    ValidationIssue,
+};
+use crate::{
+    elf, endian, ByteString, Bytes, Endian, Endianness, LittleEndian, Pod, U16Bytes, U32Bytes,
+    U64Bytes, U16, U32,
 };
-use crate::{elf, endian, ByteString, Bytes, Endian, Endianness, Pod, U32};
 
 use super::{
-    CompressionHeader, Dyn, ElfComdat, ElfComdatIterator, ElfDynamicRelocationIterator, ElfSection,
+    arm_mapping_symbols, ArmMappingSymbol, CompressionHeader, DfFlags, DfFlags1, Dyn, EhFrameHdr,
+    ElfComdat, ElfComdatIterator, ElfDynamicRelocationIterator, ElfRelrIterator, ElfSection,
     ElfSectionIterator, ElfSegment, ElfSegmentIterator, ElfSymbol, ElfSymbolIterator,
     ElfSymbolTable, NoteHeader, ProgramHeader, Rel, Rela, RelocationSections, SectionHeader,
     SectionTable, Sym, SymbolTable,
@@ -40,6 +45,8 @@ where
     pub(super) relocations: RelocationSections,
     pub(super) symbols: SymbolTable<'data, Elf>,
     pub(super) dynamic_symbols: SymbolTable<'data, Elf>,
+    pub(super) mapping_symbols: Vec<ArmMappingSymbol>,
+    pub(super) dynamic_mapping_symbols: Vec<ArmMappingSymbol>,
 }
 
 impl<'data, Elf, R> ElfFile<'data, Elf, R>
@@ -57,7 +64,15 @@ where
         // TODO: get dynamic symbols from DT_SYMTAB if there are no sections
         let dynamic_symbols = sections.symbols(endian, data, elf::SHT_DYNSYM)?;
         // The API we provide requires a mapping from section to relocations, so build it now.
-        let relocations = sections.relocation_sections(endian, symbols.section())?;
+        let relocations = sections.relocation_sections_with_dynamic(
+            endian,
+            symbols.section(),
+            dynamic_symbols.section(),
+        )?;
+
+        let e_machine = header.e_machine(endian);
+        let mapping_symbols = arm_mapping_symbols(endian, e_machine, &symbols);
+        let dynamic_mapping_symbols = arm_mapping_symbols(endian, e_machine, &dynamic_symbols);
 
         Ok(ElfFile {
             endian,
@@ -67,6 +82,48 @@ where
             relocations,
             symbols,
             dynamic_symbols,
+            mapping_symbols,
+            dynamic_mapping_symbols,
+            data,
+        })
+    }
+
+    /// Parse the raw ELF file data, recovering from errors where possible.
+    ///
+    /// Errors in the file header are fatal. Errors in the program headers,
+    /// section headers, symbol tables, and relocation sections are added to
+    /// `errors`, and the corresponding tables are treated as empty.
+    pub fn parse_lenient(data: R, errors: &mut Vec<Error>) -> read::Result<Self> {
+        let header = Elf::parse(data)?;
+        let endian = header.endian()?;
+        let segments = read::recover(header.program_headers(endian, data), errors);
+        let sections = read::recover(header.sections(endian, data), errors);
+        let symbols = read::recover(sections.symbols(endian, data, elf::SHT_SYMTAB), errors);
+        let dynamic_symbols =
+            read::recover(sections.symbols(endian, data, elf::SHT_DYNSYM), errors);
+        let relocations = read::recover(
+            sections.relocation_sections_with_dynamic(
+                endian,
+                symbols.section(),
+                dynamic_symbols.section(),
+            ),
+            errors,
+        );
+
+        let e_machine = header.e_machine(endian);
+        let mapping_symbols = arm_mapping_symbols(endian, e_machine, &symbols);
+        let dynamic_mapping_symbols = arm_mapping_symbols(endian, e_machine, &dynamic_symbols);
+
+        Ok(ElfFile {
+            endian,
+            header,
+            segments,
+            sections,
+            relocations,
+            symbols,
+            dynamic_symbols,
+            mapping_symbols,
+            dynamic_mapping_symbols,
             data,
         })
     }
@@ -86,11 +143,873 @@ where
         self.header
     }
 
+    /// Returns the fields of the ELF file header.
+    ///
+    /// The extended values of `e_phnum`, `e_shnum`, and `e_shstrndx` are read from
+    /// section 0 when required.
+    pub fn header(&self) -> read::Result<ElfHeader> {
+        let endian = self.endian;
+        let header = self.header;
+        let ident = header.e_ident();
+        let shstrndx = match header.e_shstrndx(endian) {
+            elf::SHN_XINDEX => header
+                .section_0(endian, self.data)?
+                .read_error("Missing ELF section headers for e_shstrndx overflow")?
+                .sh_link(endian),
+            e_shstrndx => e_shstrndx.into(),
+        };
+        Ok(ElfHeader {
+            class: ident.class,
+            data: ident.data,
+            os_abi: ident.os_abi,
+            abi_version: ident.abi_version,
+            file_type: header.e_type(endian),
+            machine: header.e_machine(endian),
+            version: header.e_version(endian),
+            entry: header.e_entry(endian).into(),
+            phoff: header.e_phoff(endian).into(),
+            shoff: header.e_shoff(endian).into(),
+            flags: header.e_flags(endian),
+            ehsize: header.e_ehsize(endian),
+            phentsize: header.e_phentsize(endian),
+            phnum: header.phnum(endian, self.data)?,
+            shentsize: header.e_shentsize(endian),
+            shnum: header.shnum(endian, self.data)?,
+            shstrndx,
+        })
+    }
+
     /// Returns the raw ELF segments.
     pub fn raw_segments(&self) -> &'data [Elf::ProgramHeader] {
         self.segments
     }
 
+    /// Returns the raw data of the string table used by the symbol table.
+    ///
+    /// Returns `None` if there is no `SHT_SYMTAB` section.
+    pub fn symbol_string_table(&self) -> Option<&'data [u8]> {
+        if self.symbols.section() == 0 {
+            return None;
+        }
+        Some(self.symbols.strings().data())
+    }
+
+    /// Returns the raw data of the string table used by the dynamic symbol table.
+    ///
+    /// Returns `None` if there is no `SHT_DYNSYM` section.
+    pub fn dynamic_symbol_string_table(&self) -> Option<&'data [u8]> {
+        if self.dynamic_symbols.section() == 0 {
+            return None;
+        }
+        Some(self.dynamic_symbols.strings().data())
+    }
+
+    /// Return the GDB scripts embedded in the `.debug_gdb_scripts` section.
+    ///
+    /// Returns an empty list if the section is not present.
+    pub fn gdb_scripts(&self) -> read::Result<Vec<GdbScript<'data>>> {
+        let section = match self.raw_section_by_name(".debug_gdb_scripts") {
+            Some(section) => section,
+            None => return Ok(Vec::new()),
+        };
+        let mut data = section
+            .section
+            .data(self.endian, self.data)
+            .read_error("Invalid ELF .debug_gdb_scripts section offset or size")
+            .map(Bytes)?;
+        let mut scripts = Vec::new();
+        while let Ok(kind) = data.read::<u8>() {
+            // Allow padding between entries.
+            if *kind == 0 {
+                continue;
+            }
+            let content = data
+                .read_string()
+                .read_error("Missing ELF .debug_gdb_scripts entry terminator")?;
+            scripts.push(GdbScript {
+                kind: GdbScriptKind::from_u8(*kind),
+                content: ByteString(content),
+            });
+        }
+        Ok(scripts)
+    }
+
+    /// Return the PLT entries for the `R_*_JUMP_SLOT` relocations in the
+    /// `.rela.plt` or `.rel.plt` section.
+    ///
+    /// This assumes the standard PLT layout used by the GNU and LLVM linkers: a
+    /// header followed by fixed size entries, with one entry for each relocation
+    /// in the same order as the relocations. If there is a `.plt.sec` section (as
+    /// used for x86 IBT), then the entries are in that section and there is no header.
+    ///
+    /// Only x86, x86-64, and AArch64 are supported. Returns an empty list for other
+    /// architectures, or if the sections are not present.
+    pub fn plt_entries(&self) -> read::Result<Vec<PltEntry>> {
+        let endian = self.endian;
+        let (jump_slot, header_size, entry_size) = match self.header.e_machine(endian) {
+            elf::EM_386 => (elf::R_386_JMP_SLOT, 16, 16),
+            elf::EM_X86_64 => (elf::R_X86_64_JUMP_SLOT, 16, 16),
+            elf::EM_AARCH64 => (elf::R_AARCH64_JUMP_SLOT, 32, 16),
+            _ => return Ok(Vec::new()),
+        };
+        let (plt, header_size) = match self.sections.section_by_name(endian, b".plt.sec") {
+            Some((_, plt)) => (plt, 0),
+            None => match self.sections.section_by_name(endian, b".plt") {
+                Some((_, plt)) => (plt, header_size),
+                None => return Ok(Vec::new()),
+            },
+        };
+        let relocations = match self
+            .sections
+            .section_by_name(endian, b".rela.plt")
+            .or_else(|| self.sections.section_by_name(endian, b".rel.plt"))
+        {
+            Some((_, relocations)) => relocations,
+            None => return Ok(Vec::new()),
+        };
+
+        // Collect the offset, type, and symbol of each relocation.
+        let mut slots = Vec::new();
+        if let Some(rela) = relocations.rela(endian, self.data)? {
+            let is_mips64el = self.header.is_mips64el(endian);
+            for reloc in rela {
+                slots.push((
+                    reloc.r_offset(endian).into(),
+                    reloc.r_type(endian, is_mips64el),
+                    reloc.r_sym(endian, is_mips64el),
+                ));
+            }
+        } else if let Some(rel) = relocations.rel(endian, self.data)? {
+            for reloc in rel {
+                slots.push((
+                    reloc.r_offset(endian).into(),
+                    reloc.r_type(endian),
+                    reloc.r_sym(endian),
+                ));
+            }
+        }
+
+        let mut entries = Vec::new();
+        let mut address = plt.sh_addr(endian).into().wrapping_add(header_size);
+        for (got_address, r_type, r_sym) in slots {
+            if r_type == jump_slot {
+                entries.push(PltEntry {
+                    address,
+                    got_address,
+                    symbol: SymbolIndex(r_sym as usize),
+                });
+            }
+            address = address.wrapping_add(entry_size);
+        }
+        Ok(entries)
+    }
+
+    /// Return the entries in the `.got` and `.got.plt` sections.
+    ///
+    /// The symbol for each entry is determined from the `R_*_GLOB_DAT` and
+    /// `R_*_JUMP_SLOT` dynamic relocations. Entries that do not have one of these
+    /// relocations (such as reserved entries, or entries for local symbols) are
+    /// included with no symbol.
+    ///
+    /// Only ARM, AArch64, x86, and x86-64 are supported. Returns an empty list for
+    /// other architectures.
+    pub fn got_entries(&self) -> read::Result<Vec<GotEntry>> {
+        let endian = self.endian;
+        let (glob_dat, jump_slot) = match self.header.e_machine(endian) {
+            elf::EM_ARM => (elf::R_ARM_GLOB_DAT, elf::R_ARM_JUMP_SLOT),
+            elf::EM_AARCH64 => (elf::R_AARCH64_GLOB_DAT, elf::R_AARCH64_JUMP_SLOT),
+            elf::EM_386 => (elf::R_386_GLOB_DAT, elf::R_386_JMP_SLOT),
+            elf::EM_X86_64 => (elf::R_X86_64_GLOB_DAT, elf::R_X86_64_JUMP_SLOT),
+            _ => return Ok(Vec::new()),
+        };
+
+        let mut targets = Vec::new();
+        if let Some(relocations) = self.dynamic_relocations() {
+            for (offset, relocation) in relocations {
+                if let (RelocationKind::Elf(r_type), RelocationTarget::Symbol(symbol)) =
+                    (relocation.kind(), relocation.target())
+                {
+                    if r_type == glob_dat || r_type == jump_slot {
+                        targets.push((offset, symbol));
+                    }
+                }
+            }
+        }
+        targets.sort_by_key(|target| target.0);
+
+        let entry_size = if self.header.is_type_64() { 8 } else { 4 };
+        let mut entries = Vec::new();
+        for name in &[&b".got"[..], &b".got.plt"[..]] {
+            let section = match self.sections.section_by_name(endian, name) {
+                Some((_, section)) => section,
+                None => continue,
+            };
+            let data = section
+                .data(endian, self.data)
+                .read_error("Invalid ELF GOT section offset or size")?;
+            let start: u64 = section.sh_addr(endian).into();
+            for i in 0..(data.len() / entry_size) as u64 {
+                let address = start.wrapping_add(i * entry_size as u64);
+                let symbol = targets
+                    .binary_search_by_key(&address, |target| target.0)
+                    .ok()
+                    .map(|index| targets[index].1);
+                entries.push(GotEntry { address, symbol });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Parse the `.eh_frame_hdr` section.
+    ///
+    /// If there are no section headers, then the `PT_GNU_EH_FRAME` segment is used instead.
+    ///
+    /// Returns `Ok(None)` if the section is not present.
+    pub fn eh_frame_hdr(&self) -> read::Result<Option<EhFrameHdr>> {
+        let endian = self.endian;
+        let (address, data) = if !self.sections.is_empty() {
+            match self.sections.section_by_name(endian, b".eh_frame_hdr") {
+                Some((_, section)) => (
+                    section.sh_addr(endian).into(),
+                    section
+                        .data(endian, self.data)
+                        .read_error("Invalid ELF .eh_frame_hdr section offset or size")?,
+                ),
+                None => return Ok(None),
+            }
+        } else {
+            match self
+                .segments
+                .iter()
+                .find(|segment| segment.p_type(endian) == elf::PT_GNU_EH_FRAME)
+            {
+                Some(segment) => (
+                    segment.p_vaddr(endian).into(),
+                    segment
+                        .data(endian, self.data)
+                        .read_error("Invalid ELF PT_GNU_EH_FRAME segment offset or size")?,
+                ),
+                None => return Ok(None),
+            }
+        };
+        EhFrameHdr::parse(endian, self.header.is_type_64(), address, data).map(Some)
+    }
+
+    /// Return the USDT probes in the `.note.stapsdt` section.
+    ///
+    /// Returns an empty list if the section is not present.
+    pub fn usdt_probes(&self) -> read::Result<Vec<UsdtProbe<'data>>> {
+        let endian = self.endian;
+        let section = match self.raw_section_by_name(".note.stapsdt") {
+            Some(section) => section,
+            None => return Ok(Vec::new()),
+        };
+        let mut notes = match section.section.notes(endian, self.data)? {
+            Some(notes) => notes,
+            None => return Ok(Vec::new()),
+        };
+        let is_64 = self.header.is_type_64();
+        let mut probes = Vec::new();
+        while let Some(note) = notes.next()? {
+            if note.name() != elf::ELF_NOTE_STAPSDT || note.n_type(endian) != elf::NT_STAPSDT {
+                continue;
+            }
+            let mut desc = Bytes(note.desc());
+            let mut read_address = || -> read::Result<u64> {
+                if is_64 {
+                    desc.read::<U64Bytes<_>>().map(|x| x.get(endian))
+                } else {
+                    desc.read::<U32Bytes<_>>().map(|x| x.get(endian).into())
+                }
+                .read_error("Invalid ELF USDT probe address")
+            };
+            let location = read_address()?;
+            let base = read_address()?;
+            let semaphore = read_address()?;
+            let mut read_string = || {
+                desc.read_string()
+                    .map(ByteString)
+                    .read_error("Missing ELF USDT probe string terminator")
+            };
+            let provider = read_string()?;
+            let name = read_string()?;
+            let arguments = read_string()?;
+            probes.push(UsdtProbe {
+                provider,
+                name,
+                location,
+                base,
+                semaphore,
+                arguments,
+            });
+        }
+        Ok(probes)
+    }
+
+    /// Return the indices of the dynamic symbols that are in the `SHT_GNU_HASH` section.
+    ///
+    /// These are the symbols that the dynamic linker can find by name, which may be
+    /// fewer than the symbols in the dynamic symbol table.
+    ///
+    /// Returns `Ok(None)` if the section is not present.
+    pub fn gnu_hash_exports(&self) -> read::Result<Option<Vec<SymbolIndex>>> {
+        let endian = self.endian;
+        let table = match self.gnu_hash_table()? {
+            Some(table) => table,
+            None => return Ok(None),
+        };
+        let mut exports = Vec::new();
+        for bucket in table.buckets {
+            let mut index = bucket.get(endian) as usize;
+            if index == 0 {
+                continue;
+            }
+            loop {
+                let chain = table.chain(endian, index)?;
+                exports.push(SymbolIndex(index));
+                // The low bit marks the end of the chain.
+                if chain & 1 != 0 {
+                    break;
+                }
+                index += 1;
+            }
+        }
+        Ok(Some(exports))
+    }
+
+    /// Parse the `SHT_GNU_HASH` section.
+    ///
+    /// Returns `Ok(None)` if the section is not present.
+    fn gnu_hash_table(&self) -> read::Result<Option<GnuHashTable<'data, Elf>>> {
+        let endian = self.endian;
+        let section = match self
+            .sections
+            .iter()
+            .find(|section| section.sh_type(endian) == elf::SHT_GNU_HASH)
+        {
+            Some(section) => section,
+            None => return Ok(None),
+        };
+        let mut data = section
+            .data(endian, self.data)
+            .read_error("Invalid ELF GNU hash section offset or size")
+            .map(Bytes)?;
+        let header = data
+            .read::<elf::GnuHashHeader<_>>()
+            .read_error("Invalid ELF GNU hash header")?;
+        let bloom_size = if self.header.is_type_64() { 8 } else { 4 };
+        let bloom_count = header.bloom_count.get(endian) as usize;
+        let bloom = bloom_count
+            .checked_mul(bloom_size)
+            .and_then(|size| data.read_bytes(size).ok())
+            .read_error("Invalid ELF GNU hash bloom filter")?;
+        let buckets = data
+            .read_slice::<U32<_>>(header.bucket_count.get(endian) as usize)
+            .read_error("Invalid ELF GNU hash buckets")?;
+        let chains = data
+            .read_slice::<U32<_>>(data.len() / 4)
+            .read_error("Invalid ELF GNU hash chains")?;
+        Ok(Some(GnuHashTable {
+            symbol_base: header.symbol_base.get(endian) as usize,
+            bloom_shift: header.bloom_shift.get(endian),
+            bloom,
+            bloom_size,
+            buckets,
+            chains,
+        }))
+    }
+
+    /// Return true if the dynamic linker exports the given dynamic symbol.
+    ///
+    /// This requires the symbol to be defined, to have global, weak or unique
+    /// binding, and to have default or protected visibility. Additionally, if
+    /// there is a `SHT_GNU_VERSYM` section, then the symbol version must not be
+    /// `VER_NDX_LOCAL`, which is used for symbols that a version script made local.
+    ///
+    /// `index` is an index into the dynamic symbol table. Returns false if the
+    /// index is invalid.
+    pub fn is_symbol_exported(&self, index: SymbolIndex) -> bool {
+        let endian = self.endian;
+        let symbol = match self.dynamic_symbols.symbol(index.0) {
+            Ok(symbol) => symbol,
+            Err(_) => return false,
+        };
+        if symbol.is_undefined(endian)
+            || !matches!(
+                symbol.st_bind(),
+                elf::STB_GLOBAL | elf::STB_WEAK | elf::STB_GNU_UNIQUE
+            )
+            || !matches!(
+                symbol.st_visibility(),
+                elf::STV_DEFAULT | elf::STV_PROTECTED
+            )
+        {
+            return false;
+        }
+        let versym = self
+            .sections
+            .iter()
+            .find(|section| section.sh_type(endian) == elf::SHT_GNU_VERSYM)
+            .and_then(|section| section.data_as_array::<U16<_>, _>(endian, self.data).ok())
+            .and_then(|versyms| versyms.get(index.0));
+        match versym {
+            Some(versym) => versym.get(endian) & elf::VERSYM_VERSION != elf::VER_NDX_LOCAL,
+            None => true,
+        }
+    }
+
+    /// Return the symbol versions defined in the `SHT_GNU_VERDEF` section.
+    ///
+    /// This includes the base version, which has the `VER_FLG_BASE` flag and
+    /// whose name is the name of the file.
+    ///
+    /// Returns an empty list if the section is not present.
+    pub fn version_definitions(&self) -> read::Result<Vec<VersionDefinition<'data>>> {
+        let endian = self.endian;
+        let section = match self
+            .sections
+            .iter()
+            .find(|section| section.sh_type(endian) == elf::SHT_GNU_VERDEF)
+        {
+            Some(section) => section,
+            None => return Ok(Vec::new()),
+        };
+        let data = section
+            .data(endian, self.data)
+            .read_error("Invalid ELF version definition section offset or size")
+            .map(Bytes)?;
+        let strings = self
+            .sections
+            .section(section.sh_link(endian) as usize)?
+            .data(endian, self.data)
+            .read_error("Invalid ELF string table data")
+            .map(StringTable::new)?;
+
+        let mut definitions = Vec::new();
+        let mut offset = 0;
+        // `sh_info` is the number of entries.
+        for _ in 0..section.sh_info(endian) {
+            let verdef = data
+                .read_at::<elf::Verdef<_>>(offset)
+                .read_error("Invalid ELF version definition")?;
+            let mut names = Vec::new();
+            let mut aux_offset = offset
+                .checked_add(verdef.vd_aux.get(endian) as usize)
+                .read_error("Invalid ELF version definition auxiliary offset")?;
+            for _ in 0..verdef.vd_cnt.get(endian) {
+                let verdaux = data
+                    .read_at::<elf::Verdaux<_>>(aux_offset)
+                    .read_error("Invalid ELF version definition auxiliary entry")?;
+                let name = strings
+                    .get(verdaux.vda_name.get(endian))
+                    .read_error("Invalid ELF version definition name offset")?;
+                names.push(ByteString(name));
+                let next = verdaux.vda_next.get(endian) as usize;
+                if next == 0 {
+                    break;
+                }
+                aux_offset = aux_offset
+                    .checked_add(next)
+                    .read_error("Invalid ELF version definition auxiliary offset")?;
+            }
+            if names.is_empty() {
+                return Err(Error("Missing ELF version definition name"));
+            }
+            let name = names.remove(0);
+            definitions.push(VersionDefinition {
+                index: verdef.vd_ndx.get(endian),
+                flags: verdef.vd_flags.get(endian),
+                name,
+                parents: names,
+            });
+            let next = verdef.vd_next.get(endian) as usize;
+            if next == 0 {
+                break;
+            }
+            offset = offset
+                .checked_add(next)
+                .read_error("Invalid ELF version definition next offset")?;
+        }
+        Ok(definitions)
+    }
+
+    /// Return the Go build ID from the `.note.go.buildid` section.
+    ///
+    /// This is the build ID that is used by the Go toolchain, and is separate
+    /// from the GNU build ID returned by `Object::build_id`.
+    ///
+    /// Returns `Ok(None)` if there is no Go build ID note.
+    pub fn go_build_id(&self) -> read::Result<Option<&'data [u8]>> {
+        let endian = self.endian;
+        let section = match self.raw_section_by_name(".note.go.buildid") {
+            Some(section) => section,
+            None => return Ok(None),
+        };
+        let mut notes = match section.section.notes(endian, self.data)? {
+            Some(notes) => notes,
+            None => return Ok(None),
+        };
+        while let Some(note) = notes.next()? {
+            // The Go linker pads the name with null bytes to a length of 4.
+            let name = note.name();
+            let name_len = name.iter().rposition(|&c| c != 0).map_or(0, |x| x + 1);
+            if &name[..name_len] == elf::ELF_NOTE_GO && note.n_type(endian) == elf::NT_GO_BUILD_ID {
+                return Ok(Some(note.desc()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return the xz compressed data of the `.gnu_debugdata` section.
+    ///
+    /// This section contains MiniDebugInfo, which is an ELF file containing a
+    /// `.symtab` section with the symbols that were removed when the file was
+    /// stripped. The ELF file can be parsed with `File::parse` after the data is
+    /// decompressed. Use `mini_debug_info` to decompress the data.
+    ///
+    /// Returns `Ok(None)` if there is no `.gnu_debugdata` section.
+    /// Returns `Err` if the section data does not have an xz header.
+    pub fn gnu_debugdata(&self) -> read::Result<Option<&'data [u8]>> {
+        const XZ_MAGIC: &[u8] = b"\xfd7zXZ\0";
+
+        let section = match self.raw_section_by_name(".gnu_debugdata") {
+            Some(section) => section,
+            None => return Ok(None),
+        };
+        let data = section
+            .section
+            .data(self.endian, self.data)
+            .read_error("Invalid ELF .gnu_debugdata section offset or size")?;
+        if !data.starts_with(XZ_MAGIC) {
+            return Err(Error("Invalid ELF .gnu_debugdata xz header"));
+        }
+        Ok(Some(data))
+    }
+
+    /// Return the decompressed MiniDebugInfo from the `.gnu_debugdata` section.
+    ///
+    /// The returned data is an ELF file which can be parsed with `File::parse`.
+    /// The parsed file borrows from the returned data, so it is not parsed here.
+    ///
+    /// Returns `Ok(None)` if there is no `.gnu_debugdata` section.
+    #[cfg(feature = "xz")]
+    pub fn mini_debug_info(&self) -> read::Result<Option<Vec<u8>>> {
+        let data = match self.gnu_debugdata()? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        let mut decompressed = Vec::new();
+        lzma_rs::xz_decompress(&mut &*data, &mut decompressed)
+            .ok()
+            .read_error("Invalid ELF .gnu_debugdata xz data")?;
+        Ok(Some(decompressed))
+    }
+
+    /// Return the minimum kernel ABI from the `NT_GNU_ABI_TAG` note.
+    ///
+    /// This note is normally in the `.note.ABI-tag` section. If there are no
+    /// section headers, then the `PT_NOTE` segments are searched instead.
+    ///
+    /// Returns `Ok(None)` if there is no ABI tag note.
+    pub fn abi_tag(&self) -> read::Result<Option<AbiTag>> {
+        let endian = self.endian;
+        let parse = |desc: &'data [u8]| -> read::Result<AbiTag> {
+            let words = Bytes(desc)
+                .read_slice::<U32Bytes<_>>(4)
+                .read_error("Invalid ELF ABI tag note size")?;
+            Ok(AbiTag {
+                os: words[0].get(endian),
+                major: words[1].get(endian),
+                minor: words[2].get(endian),
+                patch: words[3].get(endian),
+            })
+        };
+        // Use section headers if present, otherwise use program headers.
+        if !self.sections.is_empty() {
+            for section in self.sections.iter() {
+                if let Some(mut notes) = section.notes(endian, self.data)? {
+                    while let Some(note) = notes.next()? {
+                        if note.name() == elf::ELF_NOTE_GNU
+                            && note.n_type(endian) == elf::NT_GNU_ABI_TAG
+                        {
+                            return parse(note.desc()).map(Some);
+                        }
+                    }
+                }
+            }
+        } else {
+            for segment in self.segments {
+                if let Some(mut notes) = segment.notes(endian, self.data)? {
+                    while let Some(note) = notes.next()? {
+                        if note.name() == elf::ELF_NOTE_GNU
+                            && note.n_type(endian) == elf::NT_GNU_ABI_TAG
+                        {
+                            return parse(note.desc()).map(Some);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return the data of the CTF (Compact C Type Format) section.
+    ///
+    /// This is the `.ctf` section, or the `.SUNW_ctf` section used by Solaris
+    /// and FreeBSD. The data is returned as is. It may be a single CTF dictionary,
+    /// or a CTF archive containing multiple dictionaries as written by GNU ld.
+    ///
+    /// Returns `Ok(None)` if there is no CTF section.
+    pub fn ctf_data(&self) -> read::Result<Option<&'data [u8]>> {
+        let endian = self.endian;
+        for name in &[&b".ctf"[..], &b".SUNW_ctf"[..]] {
+            if let Some((_, section)) = self.sections.section_by_name(endian, name) {
+                let data = section
+                    .data(endian, self.data)
+                    .read_error("Invalid ELF CTF section offset or size")?;
+                return Ok(Some(data));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Parse the header of the CTF section.
+    ///
+    /// For a CTF archive, this is the header of the first dictionary in the
+    /// archive, which is the shared dictionary for archives written by GNU ld.
+    ///
+    /// Returns `Ok(None)` if there is no CTF section.
+    pub fn ctf_header(&self) -> read::Result<Option<CtfHeader>> {
+        const CTF_MAGIC: u16 = 0xdff2;
+        const CTFA_MAGIC: u64 = 0x8b47_f2a4_d762_3eeb;
+
+        let data = match self.ctf_data()? {
+            Some(data) => Bytes(data),
+            None => return Ok(None),
+        };
+        // Archives are always little endian.
+        let archive = data
+            .read_at::<U64Bytes<_>>(0)
+            .ok()
+            .map(|magic| magic.get(LittleEndian))
+            == Some(CTFA_MAGIC);
+        let dict = if archive {
+            // The offset of the dictionaries follows the magic, model, count, and
+            // name table offset. Each dictionary is prefixed by its length.
+            let offset = data
+                .read_at::<U64Bytes<_>>(32)
+                .read_error("Invalid CTF archive header")?
+                .get(LittleEndian);
+            offset
+                .checked_add(8)
+                .and_then(|offset| data.read_bytes_at(offset as usize, 12).ok())
+                .read_error("Invalid CTF archive dictionary offset")?
+        } else {
+            data
+        };
+        // Dictionaries use the byte order of the system that produced them.
+        let magic = dict
+            .read_at::<U16Bytes<Endianness>>(0)
+            .read_error("Invalid CTF header")?;
+        let endian = if magic.get(Endianness::Little) == CTF_MAGIC {
+            Endianness::Little
+        } else if magic.get(Endianness::Big) == CTF_MAGIC {
+            Endianness::Big
+        } else {
+            return Err(Error("Invalid CTF magic"));
+        };
+        let preamble = dict.read_bytes_at(2, 2).read_error("Invalid CTF header")?;
+        // `cth_parname` is at the same offset in all versions.
+        let parent_name = dict
+            .read_at::<U32Bytes<_>>(8)
+            .read_error("Invalid CTF header")?
+            .get(endian);
+        Ok(Some(CtfHeader {
+            archive,
+            version: preamble.0[0],
+            flags: preamble.0[1],
+            has_parent: parent_name != 0,
+        }))
+    }
+
+    /// Return the segments that contain each allocated section.
+    ///
+    /// This is similar to the section to segment mapping that is displayed by
+    /// `readelf -l`, although the rules for TLS sections vary between readelf versions.
+    /// The result contains an entry for each section with the `SHF_ALLOC` flag,
+    /// in section order, and each entry contains the indices into `raw_segments`
+    /// of the segments that contain the section. This includes all segment types,
+    /// not only the `PT_LOAD` segments returned by `Object::segments`.
+    ///
+    /// A section is contained in a segment if its address range is within the
+    /// memory range of the segment and, unless the section is `SHT_NOBITS`, its file
+    /// range is within the file range of the segment. `SHF_TLS` sections are only
+    /// contained in `PT_TLS`, `PT_LOAD` and `PT_GNU_RELRO` segments, and `SHT_NOBITS`
+    /// `SHF_TLS` sections are only contained in `PT_TLS` segments.
+    pub fn section_segment_map(&self) -> Vec<(SectionIndex, Vec<usize>)> {
+        let endian = self.endian;
+        let mut map = Vec::new();
+        for (index, section) in self.sections.iter().enumerate() {
+            if section.sh_flags(endian).into() & u64::from(elf::SHF_ALLOC) == 0 {
+                continue;
+            }
+            let segments = self
+                .segments
+                .iter()
+                .enumerate()
+                .filter(|&(_, segment)| section_in_segment::<Elf>(endian, section, segment))
+                .map(|(index, _)| index)
+                .collect();
+            map.push((SectionIndex(index), segments));
+        }
+        map
+    }
+
+    /// Return the flags in the `DT_FLAGS` dynamic entry.
+    ///
+    /// Returns empty flags if there is no dynamic section or no `DT_FLAGS` entry.
+    pub fn df_flags(&self) -> read::Result<DfFlags> {
+        self.dynamic_value(elf::DT_FLAGS)
+            .map(|value| DfFlags(value.unwrap_or(0) as u32))
+    }
+
+    /// Return the flags in the `DT_FLAGS_1` dynamic entry.
+    ///
+    /// Returns empty flags if there is no dynamic section or no `DT_FLAGS_1` entry.
+    pub fn df_flags_1(&self) -> read::Result<DfFlags1> {
+        self.dynamic_value(elf::DT_FLAGS_1)
+            .map(|value| DfFlags1(value.unwrap_or(0) as u32))
+    }
+
+    /// Return the number of relocations that the dynamic loader processes.
+    ///
+    /// This is the sum of the `DT_RELA`, `DT_REL` and `DT_JMPREL` relocation counts,
+    /// which are calculated from the table sizes in the dynamic section, plus the
+    /// number of relative relocations encoded in the `DT_RELR` table.
+    ///
+    /// Returns 0 if there is no dynamic section.
+    pub fn dynamic_relocation_count(&self) -> read::Result<usize> {
+        let table = |addr, size, ent, default| -> read::Result<Option<(u64, u64, u64)>> {
+            let addr = match self.dynamic_value(addr)? {
+                Some(addr) => addr,
+                None => return Ok(None),
+            };
+            let size = self.dynamic_value(size)?.unwrap_or(0);
+            let ent = match ent {
+                Some(ent) => self.dynamic_value(ent)?.unwrap_or(default),
+                None => default,
+            };
+            if ent == 0 {
+                return Err(Error("Invalid ELF dynamic relocation entry size"));
+            }
+            Ok(Some((addr, size, ent)))
+        };
+        let rela_size = mem::size_of::<Elf::Rela>() as u64;
+        let rel_size = mem::size_of::<Elf::Rel>() as u64;
+        let rela = table(
+            elf::DT_RELA,
+            elf::DT_RELASZ,
+            Some(elf::DT_RELAENT),
+            rela_size,
+        )?;
+        let rel = table(elf::DT_REL, elf::DT_RELSZ, Some(elf::DT_RELENT), rel_size)?;
+        let plt_size = match self.dynamic_value(elf::DT_PLTREL)? {
+            Some(value) if value == u64::from(elf::DT_REL) => rel_size,
+            _ => rela_size,
+        };
+        let plt = table(elf::DT_JMPREL, elf::DT_PLTRELSZ, None, plt_size)?;
+
+        let mut count = 0;
+        for &(_, size, ent) in rela.iter().chain(rel.iter()) {
+            count += size / ent;
+        }
+        if let Some((addr, size, ent)) = plt {
+            // Some linkers include the PLT relocations in the `DT_RELA` or `DT_REL` table.
+            let contains = |table: Option<(u64, u64, u64)>| {
+                table.map(|(start, size, _)| addr.wrapping_sub(start) < size) == Some(true)
+            };
+            if !contains(rela) && !contains(rel) {
+                count += size / ent;
+            }
+        }
+        let relr = self.relr_relocations()?.map(Iterator::count).unwrap_or(0);
+        Ok(count as usize + relr)
+    }
+
+    /// Return an iterator for the relative relocations in the `DT_RELR` table.
+    ///
+    /// The table is found using the `DT_RELR` and `DT_RELRSZ` dynamic entries.
+    /// Its data is read from the `SHT_RELR` section at that address, or from the
+    /// segment containing that address if there are no section headers.
+    ///
+    /// Returns `Ok(None)` if there is no `DT_RELR` entry.
+    pub fn relr_relocations(&self) -> read::Result<Option<ElfRelrIterator<'data, Elf>>> {
+        let endian = self.endian;
+        let address = match self.dynamic_value(elf::DT_RELR)? {
+            Some(address) => address,
+            None => return Ok(None),
+        };
+        let size = self.dynamic_value(elf::DT_RELRSZ)?.unwrap_or(0);
+        if let Some(section) = self.sections.iter().find(|section| {
+            section.sh_type(endian) == elf::SHT_RELR && section.sh_addr(endian).into() == address
+        }) {
+            let data = section
+                .data(endian, self.data)
+                .read_error("Invalid ELF RELR section offset or size")?;
+            return Ok(Some(ElfRelrIterator::new(self.header, endian, data)));
+        }
+        for segment in self.segments {
+            if let Some(data) = segment
+                .data_range(endian, self.data, address, size)
+                .read_error("Invalid ELF segment offset or size")?
+            {
+                return Ok(Some(ElfRelrIterator::new(self.header, endian, data)));
+            }
+        }
+        Err(Error("Invalid ELF DT_RELR address"))
+    }
+
+    /// Return the value of the first dynamic entry with the given tag.
+    ///
+    /// The entries are read from the `SHT_DYNAMIC` section, or from the
+    /// `PT_DYNAMIC` segment if there are no section headers.
+    fn dynamic_value(&self, tag: u32) -> read::Result<Option<u64>> {
+        let endian = self.endian;
+        for entry in self.dynamic_entries()? {
+            let d_tag = entry.d_tag(endian).into();
+            if d_tag == u64::from(elf::DT_NULL) {
+                break;
+            }
+            if d_tag == u64::from(tag) {
+                return Ok(Some(entry.d_val(endian).into()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return the entries of the dynamic section.
+    ///
+    /// The entries are read from the `SHT_DYNAMIC` section, or from the
+    /// `PT_DYNAMIC` segment if there are no section headers.
+    fn dynamic_entries(&self) -> read::Result<&'data [Elf::Dyn]> {
+        let endian = self.endian;
+        if let Some(section) = self
+            .sections
+            .iter()
+            .find(|section| section.sh_type(endian) == elf::SHT_DYNAMIC)
+        {
+            return section
+                .data_as_array::<Elf::Dyn, _>(endian, self.data)
+                .read_error("Invalid ELF dynamic section offset or size");
+        }
+        for segment in self.segments {
+            if let Some(entries) = segment.dynamic(endian, self.data)? {
+                return Ok(entries);
+            }
+        }
+        Ok(&[])
+    }
+
     fn raw_section_by_name<'file>(
         &'file self,
         section_name: &str,
@@ -124,6 +1043,553 @@ where
     }
 }
 
+/// A parsed `SHT_GNU_HASH` section.
+#[derive(Debug)]
+struct GnuHashTable<'data, Elf: FileHeader> {
+    symbol_base: usize,
+    bloom_shift: u32,
+    bloom: Bytes<'data>,
+    bloom_size: usize,
+    buckets: &'data [U32<Elf::Endian>],
+    chains: &'data [U32<Elf::Endian>],
+}
+
+impl<'data, Elf: FileHeader> GnuHashTable<'data, Elf> {
+    /// Return the chain entry for the dynamic symbol with the given index.
+    fn chain(&self, endian: Elf::Endian, index: usize) -> read::Result<u32> {
+        index
+            .checked_sub(self.symbol_base)
+            .and_then(|chain_index| self.chains.get(chain_index))
+            .map(|chain| chain.get(endian))
+            .read_error("Invalid ELF GNU hash chain index")
+    }
+
+    /// Use the hash table to find the index of the dynamic symbol with the given name.
+    fn find(
+        &self,
+        endian: Elf::Endian,
+        name: &[u8],
+        symbols: &SymbolTable<'data, Elf>,
+    ) -> read::Result<Option<usize>> {
+        let hash = name.iter().fold(5381u32, |hash, &c| {
+            hash.wrapping_mul(33).wrapping_add(c.into())
+        });
+
+        // Check the bloom filter first. A zero bit means the name is not present.
+        let bloom_count = self.bloom.len() / self.bloom_size;
+        if bloom_count != 0 {
+            let bits = self.bloom_size as u32 * 8;
+            let offset = (hash / bits) as usize % bloom_count * self.bloom_size;
+            let word: u64 = if self.bloom_size == 8 {
+                self.bloom
+                    .read_at::<U64Bytes<Elf::Endian>>(offset)
+                    .map(|word| word.get(endian))
+            } else {
+                self.bloom
+                    .read_at::<U32Bytes<Elf::Endian>>(offset)
+                    .map(|word| word.get(endian).into())
+            }
+            .read_error("Invalid ELF GNU hash bloom filter")?;
+            let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> self.bloom_shift) % bits));
+            if word & mask != mask {
+                return Ok(None);
+            }
+        }
+
+        if self.buckets.is_empty() {
+            return Ok(None);
+        }
+        let mut index = self.buckets[hash as usize % self.buckets.len()].get(endian) as usize;
+        if index == 0 {
+            return Ok(None);
+        }
+        loop {
+            let chain = self.chain(endian, index)?;
+            // The low bit of the chain entry is not part of the hash.
+            if chain | 1 == hash | 1
+                && symbols.symbol(index)?.name(endian, symbols.strings())? == name
+            {
+                return Ok(Some(index));
+            }
+            if chain & 1 != 0 {
+                return Ok(None);
+            }
+            index += 1;
+        }
+    }
+}
+
+/// A symbol version defined in the `SHT_GNU_VERDEF` section of an `ElfFile`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDefinition<'data> {
+    index: u16,
+    flags: u16,
+    name: ByteString<'data>,
+    parents: Vec<ByteString<'data>>,
+}
+
+impl<'data> VersionDefinition<'data> {
+    /// The version index, as used in the version symbol table.
+    #[inline]
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// The version flags.
+    ///
+    /// This is a combination of the `VER_FLG_*` constants.
+    #[inline]
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// Return true if this is the base version definition for the file.
+    #[inline]
+    pub fn is_base(&self) -> bool {
+        self.flags & elf::VER_FLG_BASE != 0
+    }
+
+    /// The version name.
+    #[inline]
+    pub fn version_name(&self) -> &'data [u8] {
+        self.name.0
+    }
+
+    /// The name of the first parent version, if any.
+    #[inline]
+    pub fn parent(&self) -> Option<&'data [u8]> {
+        self.parents.first().map(|parent| parent.0)
+    }
+
+    /// The names of all parent versions, in the order they are defined.
+    pub fn parents(&self) -> impl Iterator<Item = &'data [u8]> + '_ {
+        self.parents.iter().map(|parent| parent.0)
+    }
+}
+
+/// The kind of an entry in the `.debug_gdb_scripts` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GdbScriptKind {
+    /// The name of a Python script file.
+    PythonFile,
+    /// The name of a Scheme script file.
+    SchemeFile,
+    /// An inline Python script.
+    PythonText,
+    /// An inline Scheme script.
+    SchemeText,
+    /// An unknown kind.
+    Unknown(u8),
+}
+
+impl GdbScriptKind {
+    fn from_u8(kind: u8) -> Self {
+        match kind {
+            1 => GdbScriptKind::PythonFile,
+            3 => GdbScriptKind::SchemeFile,
+            4 => GdbScriptKind::PythonText,
+            6 => GdbScriptKind::SchemeText,
+            _ => GdbScriptKind::Unknown(kind),
+        }
+    }
+
+    /// Return true if the script is inline, rather than a file name.
+    #[inline]
+    pub fn is_inline(self) -> bool {
+        matches!(self, GdbScriptKind::PythonText | GdbScriptKind::SchemeText)
+    }
+}
+
+/// An entry in the `.debug_gdb_scripts` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GdbScript<'data> {
+    kind: GdbScriptKind,
+    content: ByteString<'data>,
+}
+
+impl<'data> GdbScript<'data> {
+    /// The kind of the entry.
+    #[inline]
+    pub fn kind(&self) -> GdbScriptKind {
+        self.kind
+    }
+
+    /// The file name of the script, or the script source if the kind is inline.
+    ///
+    /// For inline scripts, the first line is the name of the script.
+    #[inline]
+    pub fn name_or_source(&self) -> &'data [u8] {
+        self.content.0
+    }
+}
+
+/// An entry in the procedure linkage table.
+///
+/// Returned by `ElfFile::plt_entries`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PltEntry {
+    address: u64,
+    got_address: u64,
+    symbol: SymbolIndex,
+}
+
+impl PltEntry {
+    /// The address of the PLT entry.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The address of the GOT entry that is used by the PLT entry.
+    #[inline]
+    pub fn got_address(&self) -> u64 {
+        self.got_address
+    }
+
+    /// The index of the symbol that the PLT entry resolves to.
+    ///
+    /// This is an index into the dynamic symbol table.
+    #[inline]
+    pub fn symbol(&self) -> SymbolIndex {
+        self.symbol
+    }
+}
+
+/// An entry in the global offset table.
+///
+/// Returned by `ElfFile::got_entries`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GotEntry {
+    address: u64,
+    symbol: Option<SymbolIndex>,
+}
+
+impl GotEntry {
+    /// The address of the GOT entry.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The index of the symbol whose address is stored in the GOT entry.
+    ///
+    /// This is an index into the dynamic symbol table.
+    ///
+    /// Returns `None` if the entry does not have a `R_*_GLOB_DAT` or `R_*_JUMP_SLOT`
+    /// relocation.
+    #[inline]
+    pub fn symbol(&self) -> Option<SymbolIndex> {
+        self.symbol
+    }
+}
+
+/// The header of a CTF (Compact C Type Format) dictionary.
+///
+/// Returned by `ElfFile::ctf_header`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CtfHeader {
+    archive: bool,
+    version: u8,
+    flags: u8,
+    has_parent: bool,
+}
+
+impl CtfHeader {
+    /// Return true if the section is a CTF archive containing multiple dictionaries.
+    #[inline]
+    pub fn is_archive(&self) -> bool {
+        self.archive
+    }
+
+    /// The CTF format version of the dictionary.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// The flags of the dictionary.
+    #[inline]
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Return true if the type data of the dictionary is compressed.
+    #[inline]
+    pub fn is_compressed(&self) -> bool {
+        // `CTF_F_COMPRESS`
+        self.flags & 0x1 != 0
+    }
+
+    /// Return true if the dictionary references types in a parent dictionary.
+    #[inline]
+    pub fn has_parent(&self) -> bool {
+        self.has_parent
+    }
+}
+
+/// The operating system of an `NT_GNU_ABI_TAG` note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiTagOs {
+    /// `ELF_NOTE_OS_LINUX`
+    Linux,
+    /// `ELF_NOTE_OS_GNU`
+    Gnu,
+    /// `ELF_NOTE_OS_SOLARIS2`
+    Solaris2,
+    /// `ELF_NOTE_OS_FREEBSD`
+    FreeBsd,
+    /// An unknown operating system.
+    Unknown(u32),
+}
+
+/// The minimum kernel ABI recorded in an `NT_GNU_ABI_TAG` note.
+///
+/// Returned by `ElfFile::abi_tag`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbiTag {
+    os: u32,
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl AbiTag {
+    /// The operating system. One of the `ELF_NOTE_OS_*` constants.
+    #[inline]
+    pub fn os(&self) -> u32 {
+        self.os
+    }
+
+    /// The operating system as an enum.
+    pub fn os_kind(&self) -> AbiTagOs {
+        match self.os {
+            elf::ELF_NOTE_OS_LINUX => AbiTagOs::Linux,
+            elf::ELF_NOTE_OS_GNU => AbiTagOs::Gnu,
+            elf::ELF_NOTE_OS_SOLARIS2 => AbiTagOs::Solaris2,
+            elf::ELF_NOTE_OS_FREEBSD => AbiTagOs::FreeBsd,
+            os => AbiTagOs::Unknown(os),
+        }
+    }
+
+    /// The major version of the minimum kernel ABI.
+    #[inline]
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// The minor version of the minimum kernel ABI.
+    #[inline]
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// The patch version of the minimum kernel ABI.
+    #[inline]
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    /// The minimum kernel ABI as a `(major, minor, patch)` tuple.
+    ///
+    /// This can be used to compare versions.
+    #[inline]
+    pub fn version(&self) -> (u32, u32, u32) {
+        (self.major, self.minor, self.patch)
+    }
+}
+
+/// The fields of an ELF file header.
+///
+/// Returned by `ElfFile::header`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfHeader {
+    class: u8,
+    data: u8,
+    os_abi: u8,
+    abi_version: u8,
+    file_type: u16,
+    machine: u16,
+    version: u32,
+    entry: u64,
+    phoff: u64,
+    shoff: u64,
+    flags: u32,
+    ehsize: u16,
+    phentsize: u16,
+    phnum: usize,
+    shentsize: u16,
+    shnum: usize,
+    shstrndx: u32,
+}
+
+impl ElfHeader {
+    /// The file class. One of the `ELFCLASS*` constants.
+    #[inline]
+    pub fn class(&self) -> u8 {
+        self.class
+    }
+
+    /// The data encoding. One of the `ELFDATA*` constants.
+    #[inline]
+    pub fn data(&self) -> u8 {
+        self.data
+    }
+
+    /// The OS ABI. One of the `ELFOSABI*` constants.
+    #[inline]
+    pub fn os_abi(&self) -> u8 {
+        self.os_abi
+    }
+
+    /// The ABI version.
+    #[inline]
+    pub fn abi_version(&self) -> u8 {
+        self.abi_version
+    }
+
+    /// The object file type. One of the `ET_*` constants.
+    #[inline]
+    pub fn file_type(&self) -> u16 {
+        self.file_type
+    }
+
+    /// The architecture. One of the `EM_*` constants.
+    #[inline]
+    pub fn machine(&self) -> u16 {
+        self.machine
+    }
+
+    /// The object file version. This should be `EV_CURRENT`.
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The entry point virtual address.
+    #[inline]
+    pub fn entry(&self) -> u64 {
+        self.entry
+    }
+
+    /// The file offset of the program header table.
+    #[inline]
+    pub fn phoff(&self) -> u64 {
+        self.phoff
+    }
+
+    /// The file offset of the section header table.
+    #[inline]
+    pub fn shoff(&self) -> u64 {
+        self.shoff
+    }
+
+    /// The processor-specific flags.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// The size in bytes of the ELF file header.
+    #[inline]
+    pub fn ehsize(&self) -> u16 {
+        self.ehsize
+    }
+
+    /// The size in bytes of a program header table entry.
+    #[inline]
+    pub fn phentsize(&self) -> u16 {
+        self.phentsize
+    }
+
+    /// The number of program header table entries.
+    ///
+    /// If `e_phnum` is `PN_XNUM`, then this is the `sh_info` field of section 0.
+    #[inline]
+    pub fn phnum(&self) -> usize {
+        self.phnum
+    }
+
+    /// The size in bytes of a section header table entry.
+    #[inline]
+    pub fn shentsize(&self) -> u16 {
+        self.shentsize
+    }
+
+    /// The number of section header table entries.
+    ///
+    /// If `e_shnum` is 0 and there is a section header table, then this is the
+    /// `sh_size` field of section 0.
+    #[inline]
+    pub fn shnum(&self) -> usize {
+        self.shnum
+    }
+
+    /// The index of the section header string table, or 0 if there is none.
+    ///
+    /// If `e_shstrndx` is `SHN_XINDEX`, then this is the `sh_link` field of section 0.
+    #[inline]
+    pub fn shstrndx(&self) -> u32 {
+        self.shstrndx
+    }
+}
+
+/// A USDT probe in the `.note.stapsdt` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsdtProbe<'data> {
+    provider: ByteString<'data>,
+    name: ByteString<'data>,
+    location: u64,
+    base: u64,
+    semaphore: u64,
+    arguments: ByteString<'data>,
+}
+
+impl<'data> UsdtProbe<'data> {
+    /// The name of the provider of the probe.
+    #[inline]
+    pub fn provider(&self) -> &'data [u8] {
+        self.provider.0
+    }
+
+    /// The name of the probe.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name.0
+    }
+
+    /// The link-time address of the probe.
+    ///
+    /// If the file has been prelinked, this must be adjusted by the difference
+    /// between the address of the `.stapsdt.base` section and `base`.
+    #[inline]
+    pub fn location(&self) -> u64 {
+        self.location
+    }
+
+    /// The link-time address of the `.stapsdt.base` section.
+    #[inline]
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// The address of the semaphore that enables the probe, or 0 if there is none.
+    #[inline]
+    pub fn semaphore(&self) -> u64 {
+        self.semaphore
+    }
+
+    /// The description of the probe arguments.
+    ///
+    /// This is a space separated list of arguments in the form `size@location`,
+    /// where the location uses assembler syntax.
+    #[inline]
+    pub fn arguments(&self) -> &'data [u8] {
+        self.arguments.0
+    }
+}
+
 impl<'data, Elf, R> read::private::Sealed for ElfFile<'data, Elf, R>
 where
     Elf: FileHeader,
@@ -155,6 +1621,15 @@ where
             elf::EM_386 => Architecture::I386,
             elf::EM_X86_64 => Architecture::X86_64,
             elf::EM_MIPS => Architecture::Mips,
+            elf::EM_PPC => Architecture::PowerPc,
+            elf::EM_PPC64 => Architecture::PowerPc64,
+            elf::EM_RISCV => {
+                if self.is_64() {
+                    Architecture::Riscv64
+                } else {
+                    Architecture::Riscv32
+                }
+            }
             elf::EM_S390 => {
                 // This is either s390 or s390x, depending on the ELF class.
                 // We only support the 64-bit variant s390x here.
@@ -178,6 +1653,16 @@ where
         self.header.is_class_64()
     }
 
+    #[inline]
+    fn is_relocatable(&self) -> bool {
+        self.header.e_type(self.endian) == elf::ET_REL
+    }
+
+    #[inline]
+    fn default_page_size(&self) -> u64 {
+        0x1000
+    }
+
     fn segments(&'file self) -> ElfSegmentIterator<'data, 'file, Elf, R> {
         ElfSegmentIterator {
             file: self,
@@ -205,6 +1690,28 @@ where
         })
     }
 
+    fn section_by_address(&'file self, address: u64) -> Option<ElfSection<'data, 'file, Elf, R>> {
+        let endian = self.endian;
+        self.sections
+            .iter()
+            .enumerate()
+            .find(|(_, section)| {
+                let sh_flags = section.sh_flags(endian).into();
+                // Ignore sections that are not loaded, and `.tbss` sections, which do
+                // not occupy any address range outside of the TLS template.
+                sh_flags & u64::from(elf::SHF_ALLOC) != 0
+                    && (sh_flags & u64::from(elf::SHF_TLS) == 0
+                        || section.sh_type(endian) != elf::SHT_NOBITS)
+                    && address.wrapping_sub(section.sh_addr(endian).into())
+                        < section.sh_size(endian).into()
+            })
+            .map(|(index, section)| ElfSection {
+                file: self,
+                index: SectionIndex(index),
+                section,
+            })
+    }
+
     fn sections(&'file self) -> ElfSectionIterator<'data, 'file, Elf, R> {
         ElfSectionIterator {
             file: self,
@@ -226,7 +1733,9 @@ where
         let symbol = self.symbols.symbol(index.0)?;
         Ok(ElfSymbol {
             endian: self.endian,
+            e_machine: self.header.e_machine(self.endian),
             symbols: &self.symbols,
+            mapping_symbols: &self.mapping_symbols,
             index,
             symbol,
         })
@@ -235,7 +1744,9 @@ where
     fn symbols(&'file self) -> ElfSymbolIterator<'data, 'file, Elf> {
         ElfSymbolIterator {
             endian: self.endian,
+            e_machine: self.header.e_machine(self.endian),
             symbols: &self.symbols,
+            mapping_symbols: &self.mapping_symbols,
             index: 0,
         }
     }
@@ -243,14 +1754,18 @@ where
     fn symbol_table(&'file self) -> Option<ElfSymbolTable<'data, 'file, Elf>> {
         Some(ElfSymbolTable {
             endian: self.endian,
+            e_machine: self.header.e_machine(self.endian),
             symbols: &self.symbols,
+            mapping_symbols: &self.mapping_symbols,
         })
     }
 
     fn dynamic_symbols(&'file self) -> ElfSymbolIterator<'data, 'file, Elf> {
         ElfSymbolIterator {
             endian: self.endian,
+            e_machine: self.header.e_machine(self.endian),
             symbols: &self.dynamic_symbols,
+            mapping_symbols: &self.dynamic_mapping_symbols,
             index: 0,
         }
     }
@@ -258,10 +1773,32 @@ where
     fn dynamic_symbol_table(&'file self) -> Option<ElfSymbolTable<'data, 'file, Elf>> {
         Some(ElfSymbolTable {
             endian: self.endian,
+            e_machine: self.header.e_machine(self.endian),
             symbols: &self.dynamic_symbols,
+            mapping_symbols: &self.dynamic_mapping_symbols,
         })
     }
 
+    fn contains_symbol(&'file self, name: &str) -> bool {
+        let endian = self.endian;
+        let name = name.as_bytes();
+        let contains = |symbols: &SymbolTable<'data, Elf>, range: &[Elf::Sym]| {
+            range
+                .iter()
+                .any(|symbol| matches!(symbol.name(endian, symbols.strings()), Ok(n) if n == name))
+        };
+        // Symbols before `symbol_base` are not in the hash table, and may be imports.
+        let mut dynamic_symbols = self.dynamic_symbols.iter().as_slice();
+        if let Ok(Some(table)) = self.gnu_hash_table() {
+            if let Ok(Some(_)) = table.find(endian, name, &self.dynamic_symbols) {
+                return true;
+            }
+            dynamic_symbols = &dynamic_symbols[..table.symbol_base.min(dynamic_symbols.len())];
+        }
+        contains(&self.symbols, self.symbols.iter().as_slice())
+            || contains(&self.dynamic_symbols, dynamic_symbols)
+    }
+
     fn dynamic_relocations(
         &'file self,
     ) -> Option<ElfDynamicRelocationIterator<'data, 'file, Elf, R>> {
@@ -300,6 +1837,7 @@ where
                 exports.push(Export {
                     name: ByteString(name),
                     address,
+                    forward: None,
                 });
             }
         }
@@ -390,6 +1928,33 @@ where
         self.header.e_entry(self.endian).into()
     }
 
+    fn entry_symbol(&'file self) -> Option<ElfSymbol<'data, 'file, Elf>> {
+        let mut entry = self.entry();
+        if self.header.e_machine(self.endian) == elf::EM_ARM {
+            // Clear the Thumb bit to match `ElfSymbol::address`.
+            entry &= !1;
+        }
+        match entry {
+            0 => None,
+            entry => read::traits::definition_at_address(self, entry),
+        }
+    }
+
+    fn validate(&'file self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if let Ok(header) = self.header() {
+            if header.shnum() != 0 && header.shstrndx() as usize >= header.shnum() {
+                issues.push(ValidationIssue::SectionStringTableIndex(header.shstrndx()));
+            }
+        }
+        let entry = match self.entry() {
+            0 => None,
+            entry => Some(entry),
+        };
+        validate::validate(self, entry, &mut issues);
+        issues
+    }
+
     fn flags(&self) -> FileFlags {
         FileFlags::Elf {
             e_flags: self.header.e_flags(self.endian),
@@ -397,6 +1962,50 @@ where
     }
 }
 
+/// Return true if the section is contained in the segment.
+///
+/// See `ElfFile::section_segment_map`.
+fn section_in_segment<Elf: FileHeader>(
+    endian: Elf::Endian,
+    section: &Elf::SectionHeader,
+    segment: &Elf::ProgramHeader,
+) -> bool {
+    let is_tls = section.sh_flags(endian).into() & u64::from(elf::SHF_TLS) != 0;
+    let is_nobits = section.sh_type(endian) == elf::SHT_NOBITS;
+    let p_type = segment.p_type(endian);
+    if is_tls {
+        let tls_segment = match p_type {
+            elf::PT_TLS => true,
+            elf::PT_LOAD | elf::PT_GNU_RELRO => !is_nobits,
+            _ => false,
+        };
+        if !tls_segment {
+            return false;
+        }
+    } else if p_type == elf::PT_TLS {
+        return false;
+    }
+
+    let size = section.sh_size(endian).into();
+    // An empty section at the end of a non-empty segment is not contained in it.
+    let contains = |start: u64, segment_start: u64, segment_size: u64| {
+        start >= segment_start
+            && size <= segment_size
+            && start - segment_start <= segment_size - size
+            && (size != 0 || segment_size == 0 || start - segment_start < segment_size)
+    };
+    contains(
+        section.sh_addr(endian).into(),
+        segment.p_vaddr(endian).into(),
+        segment.p_memsz(endian).into(),
+    ) && (is_nobits
+        || contains(
+            section.sh_offset(endian).into(),
+            segment.p_offset(endian).into(),
+            segment.p_filesz(endian).into(),
+        ))
+}
+
 /// A trait for generic access to `FileHeader32` and `FileHeader64`.
 #[allow(missing_docs)]
 pub trait FileHeader: Debug + Pod {
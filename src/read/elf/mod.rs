@@ -31,3 +31,6 @@ pub use compression::*;
 
 mod note;
 pub use note::*;
+
+mod eh_frame;
+pub use eh_frame::*;
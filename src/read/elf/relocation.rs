@@ -5,7 +5,7 @@ use core::slice;
 
 use crate::elf;
 use crate::endian::{self, Endianness};
-use crate::pod::Pod;
+use crate::pod::{Bytes, Pod};
 use crate::read::{
     self, Error, ReadRef, Relocation, RelocationEncoding, RelocationKind, RelocationTarget,
     SymbolIndex,
@@ -14,7 +14,7 @@ use crate::read::{
 use super::{ElfFile, FileHeader, SectionHeader, SectionTable};
 
 /// A mapping from section index to associated relocation sections.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct RelocationSections {
     relocations: Vec<usize>,
 }
@@ -27,15 +27,31 @@ impl RelocationSections {
         endian: Elf::Endian,
         sections: &SectionTable<Elf>,
         symbol_section: usize,
+    ) -> read::Result<Self> {
+        Self::parse_with_dynamic(endian, sections, symbol_section, 0)
+    }
+
+    /// Create a new mapping using the section table.
+    ///
+    /// Skips relocation sections that do not use either the given symbol table section
+    /// or the given dynamic symbol table section. The dynamic symbol table section
+    /// index is 0 if there is no dynamic symbol table.
+    pub fn parse_with_dynamic<Elf: FileHeader>(
+        endian: Elf::Endian,
+        sections: &SectionTable<Elf>,
+        symbol_section: usize,
+        dynamic_symbol_section: usize,
     ) -> read::Result<Self> {
         let mut relocations = vec![0; sections.len()];
         for (index, section) in sections.iter().enumerate().rev() {
             let sh_type = section.sh_type(endian);
             if sh_type == elf::SHT_REL || sh_type == elf::SHT_RELA {
-                // The symbol indices used in relocations must be for the symbol table
+                // The symbol indices used in relocations must be for a symbol table
                 // we are expecting to use.
                 let sh_link = section.sh_link(endian) as usize;
-                if sh_link != symbol_section {
+                if sh_link != symbol_section
+                    && (dynamic_symbol_section == 0 || sh_link != dynamic_symbol_section)
+                {
                     continue;
                 }
 
@@ -164,6 +180,100 @@ where
     }
 }
 
+/// An iterator over the relative relocations in a `DT_RELR` table.
+///
+/// Returned by `ElfFile::relr_relocations`.
+///
+/// Each relocation has the target `RelocationTarget::Absolute` and an implicit
+/// addend. The kind is the relative relocation type for the architecture, such
+/// as `R_X86_64_RELATIVE`, so that these relocations are reported in the same way
+/// as the equivalent entries in a `DT_RELA` or `DT_REL` table. The kind is
+/// `RelocationKind::Elf(0)` for architectures that are not recognized.
+#[derive(Debug)]
+pub struct ElfRelrIterator<'data, Elf: FileHeader> {
+    endian: Elf::Endian,
+    is_64: bool,
+    data: Bytes<'data>,
+    kind: RelocationKind,
+    /// The address following the most recent address entry.
+    address: u64,
+    /// The remaining bits of the current bitmap entry.
+    bitmap: u64,
+    /// The address corresponding to bit 0 of `bitmap`.
+    bitmap_address: u64,
+}
+
+impl<'data, Elf: FileHeader> ElfRelrIterator<'data, Elf> {
+    pub(super) fn new(header: &Elf, endian: Elf::Endian, data: &'data [u8]) -> Self {
+        let kind = match header.e_machine(endian) {
+            elf::EM_386 => RelocationKind::Elf(elf::R_386_RELATIVE),
+            elf::EM_X86_64 => RelocationKind::Elf(elf::R_X86_64_RELATIVE),
+            elf::EM_ARM => RelocationKind::Elf(elf::R_ARM_RELATIVE),
+            elf::EM_AARCH64 => RelocationKind::Elf(elf::R_AARCH64_RELATIVE),
+            elf::EM_PPC => RelocationKind::Elf(elf::R_PPC_RELATIVE),
+            elf::EM_PPC64 => RelocationKind::Elf(elf::R_PPC64_RELATIVE),
+            elf::EM_RISCV => RelocationKind::Elf(elf::R_RISCV_RELATIVE),
+            elf::EM_S390 => RelocationKind::Elf(elf::R_390_RELATIVE),
+            elf::EM_SPARC | elf::EM_SPARCV9 => RelocationKind::Elf(elf::R_SPARC_RELATIVE),
+            _ => RelocationKind::Elf(0),
+        };
+        ElfRelrIterator {
+            endian,
+            is_64: header.is_type_64(),
+            data: Bytes(data),
+            kind,
+            address: 0,
+            bitmap: 0,
+            bitmap_address: 0,
+        }
+    }
+
+    fn read_word(&mut self) -> Option<u64> {
+        if self.is_64 {
+            let word = self.data.read::<endian::U64Bytes<Elf::Endian>>().ok()?;
+            Some(word.get(self.endian))
+        } else {
+            let word = self.data.read::<endian::U32Bytes<Elf::Endian>>().ok()?;
+            Some(word.get(self.endian).into())
+        }
+    }
+}
+
+impl<'data, Elf: FileHeader> Iterator for ElfRelrIterator<'data, Elf> {
+    type Item = (u64, Relocation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let word_size = if self.is_64 { 8 } else { 4 };
+        let offset = loop {
+            if self.bitmap != 0 {
+                let bit = u64::from(self.bitmap.trailing_zeros());
+                self.bitmap &= self.bitmap - 1;
+                break self.bitmap_address.wrapping_add(bit * word_size);
+            }
+            let word = self.read_word()?;
+            if word & 1 == 0 {
+                // An address entry.
+                self.address = word.wrapping_add(word_size);
+                break word;
+            }
+            // A bitmap entry. Bit `n` of the entry relocates the word at `n - 1` words
+            // after the current address.
+            self.bitmap = word >> 1;
+            self.bitmap_address = self.address;
+            self.address = self.address.wrapping_add((word_size * 8 - 1) * word_size);
+        };
+        let relocation = Relocation {
+            kind: self.kind,
+            encoding: RelocationEncoding::Generic,
+            size: word_size as u8 * 8,
+            target: RelocationTarget::Absolute,
+            addend: 0,
+            implicit_addend: true,
+        };
+        Some((offset, relocation))
+    }
+}
+
 /// An iterator over the relocations for an `ElfSection32`.
 pub type ElfSectionRelocationIterator32<'data, 'file, Endian = Endianness, R = &'data [u8]> =
     ElfSectionRelocationIterator<'data, 'file, elf::FileHeader32<Endian>, R>;
@@ -172,6 +282,9 @@ pub type ElfSectionRelocationIterator64<'data, 'file, Endian = Endianness, R = &
     ElfSectionRelocationIterator<'data, 'file, elf::FileHeader64<Endian>, R>;
 
 /// An iterator over the relocations for an `ElfSection`.
+///
+/// Relocations from relocation sections that use the dynamic symbol table, such as
+/// `.rela.plt`, have a target of `RelocationTarget::DynamicSymbol`.
 pub struct ElfSectionRelocationIterator<'data, 'file, Elf, R = &'data [u8]>
 where
     Elf: FileHeader,
@@ -181,6 +294,8 @@ where
     pub(super) section_index: usize,
     pub(super) file: &'file ElfFile<'data, Elf, R>,
     pub(super) relocations: Option<ElfRelaIterator<'data, Elf>>,
+    /// Whether the current relocation section uses the dynamic symbol table.
+    pub(super) dynamic: bool,
 }
 
 impl<'data, 'file, Elf, R> Iterator for ElfSectionRelocationIterator<'data, 'file, Elf, R>
@@ -195,15 +310,22 @@ where
         loop {
             if let Some(ref mut relocations) = self.relocations {
                 if let Some(reloc) = relocations.next() {
-                    let relocation =
+                    let mut relocation =
                         parse_relocation(self.file.header, endian, reloc, relocations.is_rel());
+                    if self.dynamic {
+                        if let RelocationTarget::Symbol(symbol) = relocation.target {
+                            relocation.target = RelocationTarget::DynamicSymbol(symbol);
+                        }
+                    }
                     return Some((reloc.r_offset(endian).into(), relocation));
                 }
                 self.relocations = None;
             }
             self.section_index = self.file.relocations.get(self.section_index)?;
-            // The construction of RelocationSections ensures section_index is valid.
-            let section = self.file.sections.section(self.section_index).unwrap();
+            // The construction of RelocationSections ensures section_index is valid,
+            // but avoid panicking if it isn't.
+            let section = self.file.sections.section(self.section_index).ok()?;
+            self.dynamic = section.sh_link(endian) as usize != self.file.symbols.section();
             match section.sh_type(endian) {
                 elf::SHT_REL => {
                     if let Ok(relocations) = section.data_as_array(endian, self.file.data) {
@@ -346,6 +468,10 @@ fn parse_relocation<Elf: FileHeader>(
         elf::EM_RISCV => match reloc.r_type(endian, false) {
             elf::R_RISCV_32 => (RelocationKind::Absolute, 32),
             elf::R_RISCV_64 => (RelocationKind::Absolute, 64),
+            elf::R_RISCV_32_PCREL => (RelocationKind::Relative, 32),
+            elf::R_RISCV_SET8 => (RelocationKind::Absolute, 8),
+            elf::R_RISCV_SET16 => (RelocationKind::Absolute, 16),
+            elf::R_RISCV_SET32 => (RelocationKind::Absolute, 32),
             r_type => (RelocationKind::Elf(r_type), 0),
         },
         elf::EM_SPARC | elf::EM_SPARC32PLUS | elf::EM_SPARCV9 => {
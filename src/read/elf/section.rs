@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::{iter, mem, slice, str};
 
@@ -5,24 +6,34 @@ use crate::elf;
 use crate::endian::{self, Endianness, U32Bytes};
 use crate::pod::{Bytes, Pod};
 use crate::read::{
-    self, CompressedData, CompressedFileRange, CompressionFormat, Error, ObjectSection, ReadError,
-    ReadRef, SectionFlags, SectionIndex, SectionKind, StringTable,
+    self, CompressedData, CompressedFileRange, CompressionFormat, Error, ObjectSection,
+    Permissions, RawRelocation, ReadError, ReadRef, SectionFlags, SectionIndex, SectionKind,
+    StringTable,
 };
 
 use super::{
-    CompressionHeader, ElfFile, ElfSectionRelocationIterator, FileHeader, NoteIterator,
+    CompressionHeader, ElfFile, ElfSectionRelocationIterator, FileHeader, NoteIterator, Rel, Rela,
     RelocationSections, SymbolTable,
 };
 
 /// The table of section headers in an ELF file.
 ///
 /// Also includes the string table used for the section names.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct SectionTable<'data, Elf: FileHeader> {
     sections: &'data [Elf::SectionHeader],
     strings: StringTable<'data>,
 }
 
+impl<'data, Elf: FileHeader> Default for SectionTable<'data, Elf> {
+    fn default() -> Self {
+        SectionTable {
+            sections: &[],
+            strings: Default::default(),
+        }
+    }
+}
+
 impl<'data, Elf: FileHeader> SectionTable<'data, Elf> {
     /// Create a new section table.
     #[inline]
@@ -129,6 +140,20 @@ impl<'data, Elf: FileHeader> SectionTable<'data, Elf> {
     ) -> read::Result<RelocationSections> {
         RelocationSections::parse(endian, self, symbol_section)
     }
+
+    /// Create a mapping from section index to associated relocation sections,
+    /// including relocation sections that use the dynamic symbol table.
+    ///
+    /// The dynamic symbol table section index is 0 if there is no dynamic symbol table.
+    #[inline]
+    pub fn relocation_sections_with_dynamic(
+        &self,
+        endian: Elf::Endian,
+        symbol_section: usize,
+        dynamic_symbol_section: usize,
+    ) -> read::Result<RelocationSections> {
+        RelocationSections::parse_with_dynamic(endian, self, symbol_section, dynamic_symbol_section)
+    }
 }
 
 /// An iterator over the sections of an `ElfFile32`.
@@ -186,6 +211,20 @@ where
 }
 
 impl<'data, 'file, Elf: FileHeader, R: ReadRef<'data>> ElfSection<'data, 'file, Elf, R> {
+    /// Return the index of the symbol table used by this relocation section.
+    ///
+    /// This is given by the `sh_link` field. Returns `None` if this is not a
+    /// relocation section.
+    pub fn relocation_symbol_table(&self) -> Option<SectionIndex> {
+        let endian = self.file.endian;
+        match self.section.sh_type(endian) {
+            elf::SHT_REL | elf::SHT_RELA => {
+                Some(SectionIndex(self.section.sh_link(endian) as usize))
+            }
+            _ => None,
+        }
+    }
+
     fn bytes(&self) -> read::Result<&'data [u8]> {
         self.section
             .data(self.file.endian, self.file.data)
@@ -207,21 +246,35 @@ impl<'data, 'file, Elf: FileHeader, R: ReadRef<'data>> ElfSection<'data, 'file,
             .data
             .read::<Elf::CompressionHeader>(&mut offset)
             .read_error("Invalid ELF compressed section offset")?;
-        if header.ch_type(endian) != elf::ELFCOMPRESS_ZLIB {
-            return Err(Error("Unsupported ELF compression type"));
-        }
+        let format = match header.ch_type(endian) {
+            elf::ELFCOMPRESS_ZLIB => CompressionFormat::Zlib,
+            elf::ELFCOMPRESS_ZSTD => CompressionFormat::Zstandard,
+            _ => return Err(Error("Unsupported ELF compression type")),
+        };
         let uncompressed_size = header.ch_size(endian).into();
         let compressed_size = section_size
             .checked_sub(offset - section_offset)
             .read_error("Invalid ELF compressed section size")?;
         Ok(Some(CompressedFileRange {
-            format: CompressionFormat::Zlib,
+            format,
             offset,
             compressed_size,
             uncompressed_size,
         }))
     }
 
+    /// Return true if the section name is `.eh_frame` or `.eh_frame_hdr`.
+    fn is_eh_frame(&self) -> bool {
+        match self
+            .file
+            .sections
+            .section_name(self.file.endian, self.section)
+        {
+            Ok(name) => name == b".eh_frame" || name == b".eh_frame_hdr",
+            Err(_) => false,
+        }
+    }
+
     /// Try GNU-style "ZLIB" header decompression.
     fn maybe_compressed_gnu(&self) -> read::Result<Option<CompressedFileRange>> {
         let name = match self.name() {
@@ -351,12 +404,18 @@ where
         let flags = self.section.sh_flags(self.file.endian).into();
         let sh_type = self.section.sh_type(self.file.endian);
         match sh_type {
-            elf::SHT_PROGBITS => {
+            elf::SHT_PROGBITS
+            | elf::SHT_INIT_ARRAY
+            | elf::SHT_FINI_ARRAY
+            | elf::SHT_PREINIT_ARRAY => {
                 if flags & u64::from(elf::SHF_ALLOC) != 0 {
                     if flags & u64::from(elf::SHF_EXECINSTR) != 0 {
                         SectionKind::Text
                     } else if flags & u64::from(elf::SHF_TLS) != 0 {
                         SectionKind::Tls
+                    } else if sh_type == elf::SHT_PROGBITS && self.is_eh_frame() {
+                        // Only read the name for sections that could be unwind information.
+                        SectionKind::Unwind
                     } else if flags & u64::from(elf::SHF_WRITE) != 0 {
                         SectionKind::Data
                     } else if flags & u64::from(elf::SHF_STRINGS) != 0 {
@@ -378,12 +437,21 @@ where
                 }
             }
             elf::SHT_NOTE => SectionKind::Note,
+            elf::SHT_DYNAMIC
+            | elf::SHT_HASH
+            | elf::SHT_GNU_HASH
+            | elf::SHT_GNU_VERDEF
+            | elf::SHT_GNU_VERNEED
+            | elf::SHT_GNU_VERSYM => SectionKind::DynamicLinkInfo,
+            elf::SHT_X86_64_UNWIND
+                if self.file.header.e_machine(self.file.endian) == elf::EM_X86_64 =>
+            {
+                SectionKind::Unwind
+            }
             elf::SHT_NULL
             | elf::SHT_SYMTAB
             | elf::SHT_STRTAB
             | elf::SHT_RELA
-            | elf::SHT_HASH
-            | elf::SHT_DYNAMIC
             | elf::SHT_REL
             | elf::SHT_DYNSYM
             | elf::SHT_GROUP => SectionKind::Metadata,
@@ -396,14 +464,65 @@ where
             section_index: self.index.0,
             file: self.file,
             relocations: None,
+            dynamic: false,
         }
     }
 
+    fn raw_relocations(&self) -> read::Result<Vec<RawRelocation>> {
+        let endian = self.file.endian;
+        let mut raw_relocations = Vec::new();
+        let mut section_index = self.index.0;
+        while let Some(index) = self.file.relocations.get(section_index) {
+            section_index = index;
+            let section = self.file.sections.section(section_index)?;
+            match section.sh_type(endian) {
+                elf::SHT_REL => {
+                    let relocations = section
+                        .data_as_array::<Elf::Rel, _>(endian, self.file.data)
+                        .read_error("Invalid ELF relocation section offset or size")?;
+                    raw_relocations.extend(relocations.iter().map(|relocation| {
+                        RawRelocation::Elf {
+                            r_offset: relocation.r_offset(endian).into(),
+                            r_info: relocation.r_info(endian).into(),
+                            r_addend: None,
+                        }
+                    }));
+                }
+                elf::SHT_RELA => {
+                    let relocations = section
+                        .data_as_array::<Elf::Rela, _>(endian, self.file.data)
+                        .read_error("Invalid ELF relocation section offset or size")?;
+                    raw_relocations.extend(relocations.iter().map(|relocation| {
+                        RawRelocation::Elf {
+                            r_offset: relocation.r_offset(endian).into(),
+                            r_info: relocation.r_info(endian, false).into(),
+                            r_addend: Some(relocation.r_addend(endian).into()),
+                        }
+                    }));
+                }
+                _ => {}
+            }
+        }
+        Ok(raw_relocations)
+    }
+
     fn flags(&self) -> SectionFlags {
         SectionFlags::Elf {
             sh_flags: self.section.sh_flags(self.file.endian).into(),
         }
     }
+
+    fn permissions(&self) -> Permissions {
+        let sh_flags = self.section.sh_flags(self.file.endian).into();
+        if sh_flags & u64::from(elf::SHF_ALLOC) == 0 {
+            return Permissions::default();
+        }
+        Permissions::new(
+            true,
+            sh_flags & u64::from(elf::SHF_WRITE) != 0,
+            sh_flags & u64::from(elf::SHF_EXECINSTR) != 0,
+        )
+    }
 }
 
 /// A trait for generic access to `SectionHeader32` and `SectionHeader64`.
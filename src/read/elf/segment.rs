@@ -4,7 +4,7 @@ use core::{mem, slice, str};
 use crate::elf;
 use crate::endian::{self, Endianness};
 use crate::pod::{Bytes, Pod};
-use crate::read::{self, ObjectSegment, ReadError, ReadRef};
+use crate::read::{self, ObjectSegment, Permissions, ReadError, ReadRef};
 
 use super::{ElfFile, FileHeader, NoteIterator};
 
@@ -123,6 +123,16 @@ where
     fn name(&self) -> read::Result<Option<&str>> {
         Ok(None)
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        let p_flags = self.segment.p_flags(self.file.endian);
+        Permissions::new(
+            p_flags & elf::PF_R != 0,
+            p_flags & elf::PF_W != 0,
+            p_flags & elf::PF_X != 0,
+        )
+    }
 }
 
 /// A trait for generic access to `ProgramHeader32` and `ProgramHeader64`.
@@ -1,5 +1,6 @@
 use alloc::fmt;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::slice;
 use core::str;
@@ -169,7 +170,9 @@ where
     Elf: FileHeader,
 {
     pub(super) endian: Elf::Endian,
+    pub(super) e_machine: u16,
     pub(super) symbols: &'file SymbolTable<'data, Elf>,
+    pub(super) mapping_symbols: &'file [ArmMappingSymbol],
 }
 
 impl<'data, 'file, Elf: FileHeader> read::private::Sealed for ElfSymbolTable<'data, 'file, Elf> {}
@@ -181,7 +184,9 @@ impl<'data, 'file, Elf: FileHeader> ObjectSymbolTable<'data> for ElfSymbolTable<
     fn symbols(&self) -> Self::SymbolIterator {
         ElfSymbolIterator {
             endian: self.endian,
+            e_machine: self.e_machine,
             symbols: self.symbols,
+            mapping_symbols: self.mapping_symbols,
             index: 0,
         }
     }
@@ -190,7 +195,9 @@ impl<'data, 'file, Elf: FileHeader> ObjectSymbolTable<'data> for ElfSymbolTable<
         let symbol = self.symbols.symbol(index.0)?;
         Ok(ElfSymbol {
             endian: self.endian,
+            e_machine: self.e_machine,
             symbols: self.symbols,
+            mapping_symbols: self.mapping_symbols,
             index,
             symbol,
         })
@@ -211,7 +218,9 @@ where
     Elf: FileHeader,
 {
     pub(super) endian: Elf::Endian,
+    pub(super) e_machine: u16,
     pub(super) symbols: &'file SymbolTable<'data, Elf>,
+    pub(super) mapping_symbols: &'file [ArmMappingSymbol],
     pub(super) index: usize,
 }
 
@@ -230,7 +239,9 @@ impl<'data, 'file, Elf: FileHeader> Iterator for ElfSymbolIterator<'data, 'file,
         self.index += 1;
         Some(ElfSymbol {
             endian: self.endian,
+            e_machine: self.e_machine,
             symbols: self.symbols,
+            mapping_symbols: self.mapping_symbols,
             index: SymbolIndex(index),
             symbol,
         })
@@ -252,7 +263,9 @@ where
     Elf: FileHeader,
 {
     pub(super) endian: Elf::Endian,
+    pub(super) e_machine: u16,
     pub(super) symbols: &'file SymbolTable<'data, Elf>,
+    pub(super) mapping_symbols: &'file [ArmMappingSymbol],
     pub(super) index: SymbolIndex,
     pub(super) symbol: &'data Elf::Sym,
 }
@@ -272,9 +285,13 @@ impl<'data, 'file, Elf: FileHeader> ObjectSymbol<'data> for ElfSymbol<'data, 'fi
             .read_error("Non UTF-8 ELF symbol name")
     }
 
-    #[inline]
     fn address(&self) -> u64 {
-        self.symbol.st_value(self.endian).into()
+        let address = self.symbol.st_value(self.endian).into();
+        if self.is_thumb_function() {
+            address & !1
+        } else {
+            address
+        }
     }
 
     #[inline]
@@ -371,6 +388,102 @@ impl<'data, 'file, Elf: FileHeader> ObjectSymbol<'data> for ElfSymbol<'data, 'fi
             st_other: self.symbol.st_other(),
         }
     }
+
+    fn is_thumb(&self) -> bool {
+        if self.e_machine != elf::EM_ARM {
+            return false;
+        }
+        if self.symbol.st_type() == elf::STT_FUNC {
+            return self.is_thumb_function();
+        }
+        // Use the closest preceding mapping symbol in the same section.
+        let key = (
+            self.symbol.st_shndx(self.endian),
+            self.symbol.st_value(self.endian).into(),
+        );
+        let index = match self
+            .mapping_symbols
+            .binary_search_by(|mapping| {
+                (mapping.section, mapping.address)
+                    .cmp(&key)
+                    .then(Ordering::Less)
+            })
+            .unwrap_err()
+            .checked_sub(1)
+        {
+            Some(index) => index,
+            None => return false,
+        };
+        let mapping = &self.mapping_symbols[index];
+        mapping.section == key.0 && mapping.kind == b't'
+    }
+}
+
+impl<'data, 'file, Elf: FileHeader> ElfSymbol<'data, 'file, Elf> {
+    /// Return true if this is an ARM function symbol with the Thumb bit set.
+    fn is_thumb_function(&self) -> bool {
+        self.e_machine == elf::EM_ARM
+            && self.symbol.st_type() == elf::STT_FUNC
+            && self.symbol.st_value(self.endian).into() & 1 != 0u64
+    }
+}
+
+/// An ARM mapping symbol.
+///
+/// Mapping symbols mark the start of a sequence of ARM code, Thumb code or data
+/// within a section.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ArmMappingSymbol {
+    section: u16,
+    address: u64,
+    kind: u8,
+}
+
+/// Return the ARM mapping symbols in a symbol table, sorted by section and address.
+///
+/// Returns an empty vector if the machine is not ARM. This is done once when the
+/// file is parsed so that `ElfSymbol::is_thumb` can use a binary search.
+pub(super) fn arm_mapping_symbols<'data, Elf: FileHeader>(
+    endian: Elf::Endian,
+    e_machine: u16,
+    symbols: &SymbolTable<'data, Elf>,
+) -> Vec<ArmMappingSymbol> {
+    let mut mapping_symbols = Vec::new();
+    if e_machine != elf::EM_ARM {
+        return mapping_symbols;
+    }
+    for symbol in symbols.iter() {
+        let name = symbols.symbol_name(endian, symbol).ok();
+        if let Some(kind) = name.and_then(arm_mapping_symbol) {
+            mapping_symbols.push(ArmMappingSymbol {
+                section: symbol.st_shndx(endian),
+                address: symbol.st_value(endian).into(),
+                kind,
+            });
+        }
+    }
+    // Use a stable sort so that the last of several mapping symbols at the same
+    // address is used.
+    mapping_symbols.sort_by_key(|mapping| (mapping.section, mapping.address));
+    mapping_symbols
+}
+
+/// Return the kind of an ARM mapping symbol name.
+///
+/// Mapping symbols are named `$a`, `$t` or `$d`, optionally followed by a `.` and
+/// any other characters. The kind is `a` for ARM code, `t` for Thumb code, or `d`
+/// for data.
+fn arm_mapping_symbol(name: &[u8]) -> Option<u8> {
+    match name {
+        [b'$', kind @ b'a', rest @ ..]
+        | [b'$', kind @ b't', rest @ ..]
+        | [b'$', kind @ b'd', rest @ ..]
+            if rest.is_empty() || rest[0] == b'.' =>
+        {
+            Some(*kind)
+        }
+        _ => None,
+    }
 }
 
 /// A trait for generic access to `Sym32` and `Sym64`.
@@ -1,13 +1,14 @@
+use alloc::format;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::{mem, str};
 
 use crate::read::{
-    self, Architecture, ComdatKind, Error, Export, FileFlags, Import, NoDynamicRelocationIterator,
-    Object, ObjectComdat, ObjectMap, ObjectSection, ReadError, ReadRef, Result, SectionIndex,
-    SymbolIndex,
+    self, dwarf, toolchain, validate, Architecture, ComdatKind, Error, Export, FileFlags, Import,
+    NoDynamicRelocationIterator, Object, ObjectComdat, ObjectMap, ObjectSection, ReadError,
+    ReadRef, Result, SectionIndex, SymbolIndex, Toolchain, ValidationIssue,
 };
-use crate::{endian, macho, BigEndian, ByteString, Endian, Endianness, Pod};
+use crate::{endian, macho, BigEndian, ByteString, Bytes, Endian, Endianness, Pod};
 
 use super::{
     LoadCommandIterator, MachOSection, MachOSectionInternal, MachOSectionIterator, MachOSegment,
@@ -73,6 +74,66 @@ where
         })
     }
 
+    /// Parse the raw Mach-O file data, recovering from errors where possible.
+    ///
+    /// Errors in the file header are fatal. Errors in the load commands,
+    /// segment commands, and symbol table are added to `errors`, and the
+    /// remaining load commands are skipped.
+    pub fn parse_lenient(data: R, errors: &mut Vec<Error>) -> Result<Self> {
+        let header = Mach::parse(data)?;
+        let endian = header.endian()?;
+
+        let mut symbols = SymbolTable::default();
+        let mut sections = Vec::new();
+        match header.load_commands(endian, data) {
+            Ok(mut commands) => loop {
+                let command = match commands.next() {
+                    Ok(Some(command)) => command,
+                    Ok(None) => break,
+                    Err(error) => {
+                        errors.push(error);
+                        break;
+                    }
+                };
+                match Mach::Segment::from_command(command) {
+                    Ok(Some((segment, section_data))) => {
+                        match segment.sections(endian, section_data) {
+                            Ok(segment_sections) => {
+                                for section in segment_sections {
+                                    let index = SectionIndex(sections.len() + 1);
+                                    sections.push(MachOSectionInternal::parse(index, section));
+                                }
+                            }
+                            Err(error) => errors.push(error),
+                        }
+                        continue;
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        errors.push(error);
+                        continue;
+                    }
+                }
+                match command.symtab() {
+                    Ok(Some(symtab)) => {
+                        symbols = read::recover(symtab.symbols(endian, data), errors)
+                    }
+                    Ok(None) => {}
+                    Err(error) => errors.push(error),
+                }
+            },
+            Err(error) => errors.push(error),
+        }
+
+        Ok(MachOFile {
+            endian,
+            header,
+            sections,
+            symbols,
+            data,
+        })
+    }
+
     /// Return the section at the given index.
     #[inline]
     pub(super) fn section_internal(
@@ -85,6 +146,374 @@ where
             .and_then(|index| self.sections.get(index))
             .read_error("Invalid Mach-O section index")
     }
+
+    /// Return the entries of the data-in-code table.
+    ///
+    /// These entries describe ranges within code sections that contain data,
+    /// such as jump tables, and which should not be disassembled.
+    ///
+    /// Returns an empty `Vec` if there is no `LC_DATA_IN_CODE` load command.
+    pub fn data_in_code(&self) -> Result<Vec<DataInCode>> {
+        let mut commands = self.header.load_commands(self.endian, self.data)?;
+        while let Some(command) = commands.next()? {
+            if command.cmd() != macho::LC_DATA_IN_CODE {
+                continue;
+            }
+            let linkedit = command.data::<macho::LinkeditDataCommand<Mach::Endian>>()?;
+            let count = linkedit.datasize.get(self.endian) as usize
+                / mem::size_of::<macho::DataInCodeEntry<Mach::Endian>>();
+            let entries = self
+                .data
+                .read_slice_at::<macho::DataInCodeEntry<Mach::Endian>>(
+                    linkedit.dataoff.get(self.endian).into(),
+                    count,
+                )
+                .read_error("Invalid Mach-O data-in-code table offset or size")?;
+            return Ok(entries
+                .iter()
+                .map(|entry| DataInCode {
+                    offset: entry.offset.get(self.endian),
+                    length: entry.length.get(self.endian),
+                    kind: DataInCodeKind::from_u16(entry.kind.get(self.endian)),
+                })
+                .collect());
+        }
+        Ok(Vec::new())
+    }
+
+    /// Return the entries of the indirect symbol table.
+    ///
+    /// This table is referenced by the `LC_DYSYMTAB` load command. Sections of type
+    /// `S_SYMBOL_STUBS`, `S_LAZY_SYMBOL_POINTERS`, and `S_NON_LAZY_SYMBOL_POINTERS`
+    /// use it to give the symbol for each entry in the section. The `reserved1`
+    /// field of the section header is the index of the first entry for the section,
+    /// and for stubs the `reserved2` field is the size of each stub.
+    ///
+    /// An entry is `None` if it is `INDIRECT_SYMBOL_LOCAL` or `INDIRECT_SYMBOL_ABS`.
+    ///
+    /// Returns an empty `Vec` if there is no `LC_DYSYMTAB` load command.
+    pub fn indirect_symbols(&self) -> Result<Vec<Option<SymbolIndex>>> {
+        let mut commands = self.header.load_commands(self.endian, self.data)?;
+        while let Some(command) = commands.next()? {
+            if let Some(dysymtab) = command.dysymtab()? {
+                let entries = self
+                    .data
+                    .read_slice_at::<endian::U32<Mach::Endian>>(
+                        dysymtab.indirectsymoff.get(self.endian).into(),
+                        dysymtab.nindirectsyms.get(self.endian) as usize,
+                    )
+                    .read_error("Invalid Mach-O indirect symbol table offset or size")?;
+                return Ok(entries
+                    .iter()
+                    .map(|entry| {
+                        let index = entry.get(self.endian);
+                        if index & (macho::INDIRECT_SYMBOL_LOCAL | macho::INDIRECT_SYMBOL_ABS) != 0
+                        {
+                            None
+                        } else {
+                            Some(SymbolIndex(index as usize))
+                        }
+                    })
+                    .collect());
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    /// Return the umbrella framework information from the `LC_SUB_FRAMEWORK`,
+    /// `LC_SUB_UMBRELLA`, `LC_SUB_LIBRARY`, and `LC_SUB_CLIENT` load commands.
+    pub fn umbrella_info(&self) -> Result<UmbrellaInfo<'data>> {
+        let endian = self.endian;
+        let mut info = UmbrellaInfo::default();
+        let mut commands = self.header.load_commands(endian, self.data)?;
+        while let Some(command) = commands.next()? {
+            match command.cmd() {
+                macho::LC_SUB_FRAMEWORK => {
+                    let sub = command.data::<macho::SubFrameworkCommand<Mach::Endian>>()?;
+                    info.umbrella = Some(command.string(endian, sub.umbrella)?);
+                }
+                macho::LC_SUB_UMBRELLA => {
+                    let sub = command.data::<macho::SubUmbrellaCommand<Mach::Endian>>()?;
+                    info.sub_umbrellas
+                        .push(command.string(endian, sub.sub_umbrella)?);
+                }
+                macho::LC_SUB_LIBRARY => {
+                    let sub = command.data::<macho::SubLibraryCommand<Mach::Endian>>()?;
+                    info.sub_libraries
+                        .push(command.string(endian, sub.sub_library)?);
+                }
+                macho::LC_SUB_CLIENT => {
+                    let sub = command.data::<macho::SubClientCommand<Mach::Endian>>()?;
+                    info.clients.push(command.string(endian, sub.client)?);
+                }
+                _ => {}
+            }
+        }
+        Ok(info)
+    }
+
+    /// Return the size of the `__PAGEZERO` segment.
+    ///
+    /// This segment reserves the low part of the address space so that it is unmapped.
+    ///
+    /// Returns 0 if there is no `__PAGEZERO` segment, such as for object files.
+    pub fn pagezero_size(&self) -> Result<u64> {
+        let mut commands = self.header.load_commands(self.endian, self.data)?;
+        while let Some(command) = commands.next()? {
+            if let Some((segment, _)) = Mach::Segment::from_command(command)? {
+                if segment.name() == macho::SEG_PAGEZERO.as_bytes() {
+                    return Ok(segment.vmsize(self.endian).into());
+                }
+            }
+        }
+        Ok(0)
+    }
+
+    /// Return the fields of the Mach-O header.
+    ///
+    /// This includes the `cpusubtype` field, which is needed to distinguish
+    /// variants such as arm64e that are not visible in `Object::architecture`.
+    pub fn header(&self) -> MachOHeader {
+        let endian = self.endian;
+        let header = self.header;
+        MachOHeader {
+            magic: header.magic(),
+            cputype: header.cputype(endian),
+            cpusubtype: header.cpusubtype(endian),
+            filetype: header.filetype(endian),
+            ncmds: header.ncmds(endian),
+            sizeofcmds: header.sizeofcmds(endian),
+            flags: header.flags(endian),
+        }
+    }
+
+    /// Return the address of the entry point.
+    ///
+    /// The entry point in `LC_MAIN` is a file offset, so this finds the segment that
+    /// contains it. Returns `None` if there is no entry point, or if it is not
+    /// within a segment.
+    fn entry_address(&self) -> Option<u64> {
+        let entry = self.entry();
+        if entry == 0 {
+            return None;
+        }
+        let mut commands = self.header.load_commands(self.endian, self.data).ok()?;
+        while let Ok(Some(command)) = commands.next() {
+            if let Ok(Some((segment, _))) = Mach::Segment::from_command(command) {
+                let (offset, size) = segment.file_range(self.endian);
+                let delta = entry.wrapping_sub(offset);
+                if delta < size {
+                    return Some(segment.vmaddr(self.endian).into().wrapping_add(delta));
+                }
+            }
+        }
+        None
+    }
+
+    /// Return true if the exports trie contains the given symbol name.
+    ///
+    /// The trie is read from `LC_DYLD_INFO`, `LC_DYLD_INFO_ONLY` or `LC_DYLD_EXPORTS_TRIE`.
+    /// Returns `Ok(false)` if there is no exports trie.
+    fn exports_trie_contains(&self, name: &[u8]) -> Result<bool> {
+        let endian = self.endian;
+        let mut trie = None;
+        let mut commands = self.header.load_commands(endian, self.data)?;
+        while let Some(command) = commands.next()? {
+            if let Some(dyld_info) = command.dyld_info()? {
+                trie = Some((dyld_info.export_off, dyld_info.export_size));
+                break;
+            }
+            if command.cmd() == macho::LC_DYLD_EXPORTS_TRIE {
+                let linkedit = command.data::<macho::LinkeditDataCommand<Mach::Endian>>()?;
+                trie = Some((linkedit.dataoff, linkedit.datasize));
+                break;
+            }
+        }
+        let trie = match trie {
+            Some((offset, size)) => self
+                .data
+                .read_bytes_at(offset.get(endian).into(), size.get(endian).into())
+                .read_error("Invalid Mach-O exports trie offset or size")?,
+            None => return Ok(false),
+        };
+
+        // Each node has an optional terminal with the export information, followed
+        // by a list of edges. Each edge has a string that is a part of the symbol
+        // name and the offset of the child node.
+        let mut name = name;
+        let mut offset = 0;
+        loop {
+            let mut node = Bytes(trie);
+            node.skip(offset)
+                .read_error("Invalid Mach-O exports trie node offset")?;
+            let terminal_size = dwarf::read_uleb128(&mut node)?;
+            if name.is_empty() {
+                return Ok(terminal_size != 0);
+            }
+            node.skip(terminal_size as usize)
+                .read_error("Invalid Mach-O exports trie terminal size")?;
+            let child_count = *node
+                .read::<u8>()
+                .read_error("Missing Mach-O exports trie child count")?;
+            let mut child = None;
+            for _ in 0..child_count {
+                let edge = node
+                    .read_string()
+                    .read_error("Invalid Mach-O exports trie edge")?;
+                let child_offset = dwarf::read_uleb128(&mut node)?;
+                if !edge.is_empty() && name.starts_with(edge) {
+                    child = Some((edge.len(), child_offset as usize));
+                    break;
+                }
+            }
+            match child {
+                Some((len, child_offset)) => {
+                    name = &name[len..];
+                    offset = child_offset;
+                }
+                None => return Ok(false),
+            }
+        }
+    }
+
+    /// Return true if the file is for the arm64e architecture.
+    ///
+    /// arm64e is a variant of AArch64 that uses pointer authentication.
+    /// `Object::architecture` returns `Architecture::Aarch64` for both variants.
+    pub fn is_arm64e(&self) -> bool {
+        let endian = self.endian;
+        self.header.cputype(endian) == macho::CPU_TYPE_ARM64
+            && self.header.cpusubtype(endian) & !macho::CPU_SUBTYPE_MASK
+                == macho::CPU_SUBTYPE_ARM64E
+    }
+
+    /// Return the library that an undefined symbol is bound to.
+    ///
+    /// This uses the library ordinal in the `n_desc` field of the symbol, which is
+    /// only valid for files with the `MH_TWOLEVEL` flag. Ordinals refer to the dylib
+    /// load commands in the order they appear in the file.
+    ///
+    /// Returns `Ok(None)` if the file does not use a two-level namespace, or if the
+    /// symbol is not an undefined symbol. This includes common symbols, which use
+    /// the `n_desc` field for their alignment instead.
+    pub fn symbol_library(&self, index: SymbolIndex) -> Result<Option<SymbolLibrary<'data>>> {
+        let endian = self.endian;
+        if self.header.flags(endian) & macho::MH_TWOLEVEL == 0 {
+            return Ok(None);
+        }
+        let symbol = self.symbols.symbol(index.0)?;
+        if !symbol.is_undefined() || symbol.n_value(endian).into() != 0 {
+            return Ok(None);
+        }
+        let ordinal = symbol.library_ordinal(endian);
+        let mut libraries = 0;
+        let mut commands = self.header.load_commands(endian, self.data)?;
+        while let Some(command) = commands.next()? {
+            if let Some(dylib) = command.dylib()? {
+                libraries += 1;
+                if libraries == usize::from(ordinal) {
+                    let name = command.string(endian, dylib.dylib.name)?;
+                    return Ok(Some(SymbolLibrary::Dylib(name)));
+                }
+            }
+        }
+        // `DYNAMIC_LOOKUP_ORDINAL` is only used if there are fewer dylibs than
+        // the ordinal, so it was handled above if there are enough dylibs.
+        match ordinal {
+            macho::SELF_LIBRARY_ORDINAL => Ok(Some(SymbolLibrary::SelfImage)),
+            macho::DYNAMIC_LOOKUP_ORDINAL => Ok(Some(SymbolLibrary::DynamicLookup)),
+            macho::EXECUTABLE_ORDINAL => Ok(Some(SymbolLibrary::MainExecutable)),
+            _ => Err(Error("Invalid Mach-O symbol library ordinal")),
+        }
+    }
+
+    /// Return the Objective-C class names in the `__TEXT,__objc_classname` section.
+    ///
+    /// This section contains the null terminated names of the classes that are
+    /// defined in the file. The class metadata that refers to these names is not
+    /// parsed.
+    ///
+    /// Returns an empty `Vec` if there is no `__objc_classname` section.
+    pub fn objc_class_names(&self) -> Result<Vec<&'data [u8]>> {
+        let endian = self.endian;
+        let section = self.sections.iter().find(|internal| {
+            internal.section.segment_name() == b"__TEXT"
+                && internal.section.name() == b"__objc_classname"
+        });
+        let data = match section {
+            Some(internal) => internal
+                .section
+                .data(endian, self.data)
+                .read_error("Invalid Mach-O __objc_classname section offset or size")?,
+            None => return Ok(Vec::new()),
+        };
+        Ok(data
+            .split(|&byte| byte == 0)
+            .filter(|name| !name.is_empty())
+            .collect())
+    }
+
+    /// Return the names and data of the Swift metadata sections.
+    ///
+    /// These are the sections with names starting with `__swift5_`, such as
+    /// `__swift5_types`, `__swift5_proto`, and `__swift5_fieldmd`. The data is
+    /// returned as is.
+    pub fn swift_sections(&self) -> Result<Vec<(&'data [u8], &'data [u8])>> {
+        let endian = self.endian;
+        let mut sections = Vec::new();
+        for internal in &self.sections {
+            let name = internal.section.name();
+            if name.starts_with(b"__swift5_") {
+                let data = internal
+                    .section
+                    .data(endian, self.data)
+                    .read_error("Invalid Mach-O Swift section offset or size")?;
+                sections.push((name, data));
+            }
+        }
+        Ok(sections)
+    }
+
+    /// Return the type descriptor references in the `__TEXT,__swift5_types` section.
+    ///
+    /// This section is an array of 32-bit relative pointers to the type descriptors
+    /// that are defined in the file.
+    ///
+    /// Returns an empty `Vec` if there is no `__swift5_types` section.
+    pub fn swift5_types(&self) -> Result<Vec<SwiftTypeReference>> {
+        let endian = self.endian;
+        let section = self.sections.iter().find(|internal| {
+            internal.section.segment_name() == b"__TEXT"
+                && internal.section.name() == b"__swift5_types"
+        });
+        let section = match section {
+            Some(internal) => internal.section,
+            None => return Ok(Vec::new()),
+        };
+        let data = section
+            .data(endian, self.data)
+            .read_error("Invalid Mach-O __swift5_types section offset or size")?;
+        let entries = Bytes(data)
+            .read_slice_at::<endian::I32Bytes<Mach::Endian>>(0, data.len() / 4)
+            .read_error("Invalid Mach-O __swift5_types section size")?;
+        let address: u64 = section.addr(endian).into();
+        Ok(entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                // The offset is relative to the address of the entry itself,
+                // and the low 2 bits are the kind of reference.
+                let offset = entry.get(endian);
+                let target = address
+                    .wrapping_add(i as u64 * 4)
+                    .wrapping_add(i64::from(offset & !3) as u64);
+                SwiftTypeReference {
+                    address: target,
+                    kind: (offset & 3) as u8,
+                }
+            })
+            .collect())
+    }
 }
 
 impl<'data, Mach, R> read::private::Sealed for MachOFile<'data, Mach, R>
@@ -118,6 +547,8 @@ where
             macho::CPU_TYPE_X86 => Architecture::I386,
             macho::CPU_TYPE_X86_64 => Architecture::X86_64,
             macho::CPU_TYPE_MIPS => Architecture::Mips,
+            macho::CPU_TYPE_POWERPC => Architecture::PowerPc,
+            macho::CPU_TYPE_POWERPC64 => Architecture::PowerPc64,
             _ => Architecture::Unknown,
         }
     }
@@ -132,6 +563,18 @@ where
         self.header.is_type_64()
     }
 
+    #[inline]
+    fn is_relocatable(&self) -> bool {
+        self.header.filetype(self.endian) == macho::MH_OBJECT
+    }
+
+    fn default_page_size(&self) -> u64 {
+        match self.architecture() {
+            Architecture::Aarch64 => 0x4000,
+            _ => 0x1000,
+        }
+    }
+
     fn segments(&'file self) -> MachOSegmentIterator<'data, 'file, Mach, R> {
         MachOSegmentIterator {
             file: self,
@@ -297,12 +740,18 @@ where
                 exports.push(Export {
                     name: ByteString(name),
                     address,
+                    forward: None,
                 });
             }
         }
         Ok(exports)
     }
 
+    fn contains_symbol(&'file self, name: &str) -> bool {
+        matches!(self.exports_trie_contains(name.as_bytes()), Ok(true))
+            || read::symbol_tables_contain(self, name)
+    }
+
     #[inline]
     fn dynamic_relocations(&'file self) -> Option<NoDynamicRelocationIterator> {
         None
@@ -312,6 +761,42 @@ where
         self.section_by_name(".debug_info").is_some()
     }
 
+    fn toolchain(&'file self) -> Toolchain {
+        let mut toolchain = toolchain::toolchain(self);
+        if let Ok(mut commands) = self.header.load_commands(self.endian, self.data) {
+            while let Ok(Some(command)) = commands.next() {
+                if let Ok(Some((build_version, data))) = command.build_version() {
+                    let tools = Bytes(data)
+                        .read_slice::<macho::BuildToolVersion<Mach::Endian>>(
+                            build_version.ntools.get(self.endian) as usize,
+                        )
+                        .unwrap_or(&[]);
+                    for tool in tools {
+                        // X.Y.Z is encoded in nibbles xxxx.yy.zz
+                        let version = tool.version.get(self.endian);
+                        let version = format!(
+                            "{}.{}.{}",
+                            version >> 16,
+                            (version >> 8) & 0xff,
+                            version & 0xff
+                        );
+                        match tool.tool.get(self.endian) {
+                            macho::TOOL_CLANG => {
+                                toolchain.add_compiler(format!("clang {}", version), None)
+                            }
+                            macho::TOOL_SWIFT => {
+                                toolchain.add_compiler(format!("swift {}", version), Some("Swift"))
+                            }
+                            macho::TOOL_LD => toolchain.add_linker(format!("ld {}", version)),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        toolchain
+    }
+
     fn mach_uuid(&self) -> Result<Option<[u8; 16]>> {
         self.header.uuid(self.endian, self.data)
     }
@@ -327,6 +812,22 @@ where
         0
     }
 
+    fn entry_symbol(&'file self) -> Option<MachOSymbol<'data, 'file, Mach, R>> {
+        let address = self.entry_address()?;
+        read::traits::definition_at_address(self, address)
+    }
+
+    fn validate(&'file self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let entry = self.entry();
+        let address = self.entry_address();
+        if entry != 0 && address.is_none() {
+            issues.push(ValidationIssue::EntryPoint(entry));
+        }
+        validate::validate(self, address, &mut issues);
+        issues
+    }
+
     fn flags(&self) -> FileFlags {
         FileFlags::MachO {
             flags: self.header.flags(self.endian),
@@ -447,6 +948,233 @@ where
     }
 }
 
+/// The fields of a Mach-O file header.
+///
+/// Returned by `MachOFile::header`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MachOHeader {
+    magic: u32,
+    cputype: u32,
+    cpusubtype: u32,
+    filetype: u32,
+    ncmds: u32,
+    sizeofcmds: u32,
+    flags: u32,
+}
+
+impl MachOHeader {
+    /// The magic number, read as big endian.
+    ///
+    /// One of `MH_MAGIC`, `MH_CIGAM`, `MH_MAGIC_64`, or `MH_CIGAM_64`.
+    #[inline]
+    pub fn magic(&self) -> u32 {
+        self.magic
+    }
+
+    /// The CPU type. One of the `CPU_TYPE_*` constants.
+    #[inline]
+    pub fn cputype(&self) -> u32 {
+        self.cputype
+    }
+
+    /// The CPU subtype.
+    ///
+    /// The low bits are one of the `CPU_SUBTYPE_*` constants for the CPU type.
+    /// The bits in `CPU_SUBTYPE_MASK` are capability bits, such as
+    /// `CPU_SUBTYPE_LIB64` or `CPU_SUBTYPE_PTRAUTH_ABI`.
+    #[inline]
+    pub fn cpusubtype(&self) -> u32 {
+        self.cpusubtype
+    }
+
+    /// The type of file. One of the `MH_*` file type constants.
+    #[inline]
+    pub fn filetype(&self) -> u32 {
+        self.filetype
+    }
+
+    /// The number of load commands.
+    #[inline]
+    pub fn ncmds(&self) -> u32 {
+        self.ncmds
+    }
+
+    /// The size of all the load commands.
+    #[inline]
+    pub fn sizeofcmds(&self) -> u32 {
+        self.sizeofcmds
+    }
+
+    /// The header flags. A combination of the `MH_*` flag constants.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+/// An entry in the Mach-O data-in-code table.
+///
+/// Returned by `MachOFile::data_in_code`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataInCode {
+    offset: u32,
+    length: u16,
+    kind: DataInCodeKind,
+}
+
+impl DataInCode {
+    /// The file offset of the start of the data range.
+    ///
+    /// This is relative to the start of the Mach-O header.
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// The number of bytes in the data range.
+    #[inline]
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// The kind of data in the range.
+    #[inline]
+    pub fn kind(&self) -> DataInCodeKind {
+        self.kind
+    }
+}
+
+/// The kind of a Mach-O data-in-code entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DataInCodeKind {
+    /// Generic data. `DICE_KIND_DATA`
+    Data,
+    /// A jump table of 8-bit entries. `DICE_KIND_JUMP_TABLE8`
+    JumpTable8,
+    /// A jump table of 16-bit entries. `DICE_KIND_JUMP_TABLE16`
+    JumpTable16,
+    /// A jump table of 32-bit entries. `DICE_KIND_JUMP_TABLE32`
+    JumpTable32,
+    /// A jump table of absolute 32-bit entries. `DICE_KIND_ABS_JUMP_TABLE32`
+    AbsJumpTable32,
+    /// An unknown kind.
+    Unknown(u16),
+}
+
+impl DataInCodeKind {
+    fn from_u16(kind: u16) -> Self {
+        match u32::from(kind) {
+            macho::DICE_KIND_DATA => DataInCodeKind::Data,
+            macho::DICE_KIND_JUMP_TABLE8 => DataInCodeKind::JumpTable8,
+            macho::DICE_KIND_JUMP_TABLE16 => DataInCodeKind::JumpTable16,
+            macho::DICE_KIND_JUMP_TABLE32 => DataInCodeKind::JumpTable32,
+            macho::DICE_KIND_ABS_JUMP_TABLE32 => DataInCodeKind::AbsJumpTable32,
+            _ => DataInCodeKind::Unknown(kind),
+        }
+    }
+}
+
+/// The library that an undefined Mach-O symbol is bound to.
+///
+/// Returned by `MachOFile::symbol_library`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolLibrary<'data> {
+    /// A library from a dylib load command, such as `LC_LOAD_DYLIB`.
+    ///
+    /// Contains the install name of the library.
+    Dylib(&'data [u8]),
+    /// The symbol is in this image. `SELF_LIBRARY_ORDINAL`
+    SelfImage,
+    /// The symbol is looked up using flat namespace semantics. `DYNAMIC_LOOKUP_ORDINAL`
+    DynamicLookup,
+    /// The symbol is in the main executable that loads this image. `EXECUTABLE_ORDINAL`
+    MainExecutable,
+}
+
+/// A reference to a Swift type descriptor in the `__swift5_types` section.
+///
+/// Returned by `MachOFile::swift5_types`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwiftTypeReference {
+    address: u64,
+    kind: u8,
+}
+
+impl SwiftTypeReference {
+    /// The address that the reference points to.
+    ///
+    /// For direct references, this is the address of the type descriptor.
+    /// For indirect references, this is the address of a pointer to the
+    /// type descriptor.
+    #[inline]
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The kind of reference, which is stored in the low 2 bits of the relative
+    /// pointer.
+    ///
+    /// This is 0 for a direct reference and 1 for an indirect reference.
+    #[inline]
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    /// Return true if the address is a pointer to the type descriptor, rather than
+    /// the type descriptor itself.
+    #[inline]
+    pub fn is_indirect(&self) -> bool {
+        self.kind & 1 != 0
+    }
+}
+
+/// The umbrella framework information for a Mach-O dynamic library.
+///
+/// Returned by `MachOFile::umbrella_info`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UmbrellaInfo<'data> {
+    umbrella: Option<&'data [u8]>,
+    sub_umbrellas: Vec<&'data [u8]>,
+    sub_libraries: Vec<&'data [u8]>,
+    clients: Vec<&'data [u8]>,
+}
+
+impl<'data> UmbrellaInfo<'data> {
+    /// The name of the umbrella framework that this library is a part of.
+    ///
+    /// This is from the `LC_SUB_FRAMEWORK` load command.
+    #[inline]
+    pub fn umbrella(&self) -> Option<&'data [u8]> {
+        self.umbrella
+    }
+
+    /// The names of the sub-umbrella frameworks whose symbols are re-exported.
+    ///
+    /// These are from the `LC_SUB_UMBRELLA` load commands.
+    #[inline]
+    pub fn sub_umbrellas(&self) -> &[&'data [u8]] {
+        &self.sub_umbrellas
+    }
+
+    /// The names of the sub-libraries whose symbols are re-exported.
+    ///
+    /// These are from the `LC_SUB_LIBRARY` load commands.
+    #[inline]
+    pub fn sub_libraries(&self) -> &[&'data [u8]] {
+        &self.sub_libraries
+    }
+
+    /// The names of the clients that are allowed to link directly to this
+    /// subframework.
+    ///
+    /// These are from the `LC_SUB_CLIENT` load commands.
+    #[inline]
+    pub fn clients(&self) -> &[&'data [u8]] {
+        &self.clients
+    }
+}
+
 /// A trait for generic access to `MachHeader32` and `MachHeader64`.
 #[allow(missing_docs)]
 pub trait MachHeader: Debug + Pod {
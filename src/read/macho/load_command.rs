@@ -238,6 +238,21 @@ impl<'data, E: Endian> LoadCommandData<'data, E> {
             Ok(None)
         }
     }
+
+    /// Try to parse this command as a `BuildVersionCommand`.
+    ///
+    /// Also returns the data for the `BuildToolVersion` entries that follow the command.
+    pub fn build_version(
+        self,
+    ) -> Result<Option<(&'data macho::BuildVersionCommand<E>, &'data [u8])>> {
+        if self.cmd == macho::LC_BUILD_VERSION {
+            let mut data = self.data;
+            let command = data.read().read_error("Invalid Mach-O command size")?;
+            Ok(Some((command, data.0)))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 /// A `LoadCommand` that has been interpreted according to its `cmd` field.
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::{fmt, result, slice, str};
 
@@ -5,11 +6,11 @@ use crate::endian::{self, Endianness};
 use crate::macho;
 use crate::pod::Pod;
 use crate::read::{
-    self, CompressedData, CompressedFileRange, ObjectSection, ReadError, ReadRef, Result,
-    SectionFlags, SectionIndex, SectionKind,
+    self, CompressedData, CompressedFileRange, ObjectSection, Permissions, RawRelocation,
+    ReadError, ReadRef, Result, SectionFlags, SectionIndex, SectionKind,
 };
 
-use super::{MachHeader, MachOFile, MachORelocationIterator};
+use super::{vm_prot_permissions, MachHeader, MachOFile, MachORelocationIterator, Segment};
 
 /// An iterator over the sections of a `MachOFile32`.
 pub type MachOSectionIterator32<'data, 'file, Endian = Endianness, R = &'data [u8]> =
@@ -118,7 +119,9 @@ where
 
     #[inline]
     fn align(&self) -> u64 {
-        1 << self.internal.section.align(self.file.endian)
+        // The `align` field is the log2 of the alignment.
+        1u64.checked_shl(self.internal.section.align(self.file.endian))
+            .unwrap_or(0)
     }
 
     #[inline]
@@ -182,11 +185,56 @@ where
         }
     }
 
+    fn raw_relocations(&self) -> Result<Vec<RawRelocation>> {
+        let endian = self.file.endian;
+        let relocations = self.internal.section.relocations(endian, self.file.data)?;
+        Ok(relocations
+            .iter()
+            .map(|relocation| RawRelocation::MachO {
+                r_word0: relocation.r_word0.get(endian),
+                r_word1: relocation.r_word1.get(endian),
+            })
+            .collect())
+    }
+
     fn flags(&self) -> SectionFlags {
         SectionFlags::MachO {
             flags: self.internal.section.flags(self.file.endian),
         }
     }
+
+    fn permissions(&self) -> Permissions {
+        let endian = self.file.endian;
+        let flags = self.internal.section.flags(endian);
+        if flags & macho::S_ATTR_DEBUG != 0 || self.internal.kind == SectionKind::Debug {
+            return Permissions::default();
+        }
+        // Sections in a relocatable object are in a single segment without a name,
+        // so this only finds a segment for linked images.
+        let segment_name = self.internal.section.segment_name();
+        if let Ok(mut commands) = self.file.header.load_commands(endian, self.file.data) {
+            while let Ok(Some(command)) = commands.next() {
+                if let Ok(Some((segment, _))) = Mach::Segment::from_command(command) {
+                    if segment.name() == segment_name {
+                        return vm_prot_permissions(segment.initprot(endian));
+                    }
+                }
+            }
+        }
+        let write = match self.internal.kind {
+            SectionKind::Data
+            | SectionKind::UninitializedData
+            | SectionKind::Common
+            | SectionKind::Tls
+            | SectionKind::UninitializedTls
+            | SectionKind::TlsVariables => true,
+            SectionKind::Unknown => segment_name.starts_with(b"__DATA"),
+            _ => false,
+        };
+        let execute =
+            flags & (macho::S_ATTR_PURE_INSTRUCTIONS | macho::S_ATTR_SOME_INSTRUCTIONS) != 0;
+        Permissions::new(true, write, execute)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -206,7 +254,9 @@ impl<'data, Mach: MachHeader> MachOSectionInternal<'data, Mach> {
             (b"__TEXT", b"__literal4") => SectionKind::ReadOnlyData,
             (b"__TEXT", b"__literal8") => SectionKind::ReadOnlyData,
             (b"__TEXT", b"__literal16") => SectionKind::ReadOnlyData,
-            (b"__TEXT", b"__eh_frame") => SectionKind::ReadOnlyData,
+            (b"__TEXT", b"__eh_frame") => SectionKind::Unwind,
+            (b"__TEXT", b"__unwind_info") => SectionKind::Unwind,
+            (b"__LD", b"__compact_unwind") => SectionKind::Unwind,
             (b"__TEXT", b"__gcc_except_tab") => SectionKind::ReadOnlyData,
             (b"__DATA", b"__data") => SectionKind::Data,
             (b"__DATA", b"__const") => SectionKind::ReadOnlyData,
@@ -4,7 +4,7 @@ use core::{result, str};
 use crate::endian::{self, Endianness};
 use crate::macho;
 use crate::pod::Pod;
-use crate::read::{self, ObjectSegment, ReadError, ReadRef, Result};
+use crate::read::{self, ObjectSegment, Permissions, ReadError, ReadRef, Result};
 
 use super::{LoadCommandData, LoadCommandIterator, MachHeader, MachOFile, Section};
 
@@ -76,6 +76,23 @@ where
             .data(self.file.endian, self.file.data)
             .read_error("Invalid Mach-O segment size or offset")
     }
+
+    /// Returns the maximum memory access permissions of the segment.
+    ///
+    /// This is derived from the `maxprot` field of the segment command,
+    /// whereas `ObjectSegment::permissions` uses the `initprot` field.
+    #[inline]
+    pub fn max_permissions(&self) -> Permissions {
+        vm_prot_permissions(self.segment.maxprot(self.file.endian))
+    }
+}
+
+pub(super) fn vm_prot_permissions(prot: u32) -> Permissions {
+    Permissions::new(
+        prot & macho::VM_PROT_READ != 0,
+        prot & macho::VM_PROT_WRITE != 0,
+        prot & macho::VM_PROT_EXECUTE != 0,
+    )
 }
 
 impl<'data, 'file, Mach, R> read::private::Sealed for MachOSegment<'data, 'file, Mach, R>
@@ -132,6 +149,11 @@ where
                 .read_error("Non UTF-8 Mach-O segment name")?,
         ))
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        vm_prot_permissions(self.segment.initprot(self.file.endian))
+    }
 }
 
 /// A trait for generic access to `SegmentCommand32` and `SegmentCommand64`.
@@ -387,6 +387,11 @@ where
         let n_desc = self.nlist.n_desc(self.file.endian);
         SymbolFlags::MachO { n_desc }
     }
+
+    fn is_thumb(&self) -> bool {
+        self.file.header.cputype(self.file.endian) == macho::CPU_TYPE_ARM
+            && self.nlist.n_desc(self.file.endian) & macho::N_ARM_THUMB_DEF != 0
+    }
 }
 
 /// A trait for generic access to `Nlist32` and `Nlist64`.
@@ -2,10 +2,13 @@
 
 use alloc::borrow::Cow;
 use alloc::vec::Vec;
-use core::{fmt, result};
+use core::cmp::{Ordering, Reverse};
+use core::convert::TryInto;
+use core::{fmt, result, str};
 
 use crate::common::*;
-use crate::{ByteString, Endianness};
+use crate::endian::{U16Bytes, U32Bytes, U64Bytes};
+use crate::{ByteString, Bytes, Endianness};
 
 mod read_ref;
 pub use read_ref::*;
@@ -21,6 +24,8 @@ pub use util::StringTable;
 mod any;
 pub use any::*;
 
+mod dwarf;
+
 #[cfg(feature = "archive")]
 pub mod archive;
 
@@ -36,9 +41,15 @@ pub mod macho;
 #[cfg(feature = "pe")]
 pub mod pe;
 
+mod toolchain;
+pub use toolchain::Toolchain;
+
 mod traits;
 pub use traits::*;
 
+mod validate;
+pub use validate::ValidationIssue;
+
 #[cfg(feature = "wasm")]
 pub mod wasm;
 
@@ -79,6 +90,17 @@ impl<T> ReadError<T> for Option<T> {
     }
 }
 
+/// Record a recoverable error, and use a default value instead.
+///
+/// Used when parsing files leniently.
+#[cfg(any(feature = "coff", feature = "elf", feature = "macho"))]
+fn recover<T: Default>(result: Result<T>, errors: &mut Vec<Error>) -> T {
+    result.unwrap_or_else(|error| {
+        errors.push(error);
+        T::default()
+    })
+}
+
 /// The native executable file for the target platform.
 #[cfg(all(
     unix,
@@ -252,37 +274,145 @@ impl SymbolSection {
     }
 }
 
+/// The source language of a symbol, as determined from its mangled name.
+///
+/// Returned by `ObjectSymbol::language`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SymbolLanguage {
+    /// The name is not mangled using a recognized scheme.
+    ///
+    /// This includes C symbols.
+    Unknown,
+    /// Rust, using either the legacy or the v0 mangling scheme.
+    Rust,
+    /// C++, using either the Itanium or the MSVC mangling scheme.
+    Cpp,
+    /// Swift.
+    Swift,
+}
+
+impl SymbolLanguage {
+    /// Determine the language from the mangling scheme of a symbol name.
+    ///
+    /// A single leading underscore is ignored, as is used by Mach-O.
+    pub fn from_name(name: &[u8]) -> SymbolLanguage {
+        let language = Self::from_unprefixed_name(name);
+        if language == SymbolLanguage::Unknown && name.first() == Some(&b'_') {
+            Self::from_unprefixed_name(&name[1..])
+        } else {
+            language
+        }
+    }
+
+    fn from_unprefixed_name(name: &[u8]) -> SymbolLanguage {
+        let is_rust_v0 = name.starts_with(b"_R")
+            && name
+                .get(2)
+                .map(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+                == Some(true);
+        if is_rust_v0 || (name.starts_with(b"_ZN") && is_rust_legacy_hash(name)) {
+            SymbolLanguage::Rust
+        } else if name.starts_with(b"_Z") || name.starts_with(b"?") {
+            SymbolLanguage::Cpp
+        } else if name.starts_with(b"$s") || name.starts_with(b"$S") || name.starts_with(b"_T0") {
+            SymbolLanguage::Swift
+        } else {
+            SymbolLanguage::Unknown
+        }
+    }
+}
+
+/// Return true if the name contains the hash that ends a Rust legacy mangled name.
+///
+/// The hash is encoded as a path component of the form `17h` followed by 16 hex digits,
+/// and is followed by the `E` that ends the path.
+fn is_rust_legacy_hash(name: &[u8]) -> bool {
+    name.windows(20).any(|w| {
+        w.starts_with(b"17h") && w[3..19].iter().all(u8::is_ascii_hexdigit) && w[19] == b'E'
+    })
+}
+
 /// An entry in a `SymbolMap`.
 pub trait SymbolMapEntry {
     /// The symbol address.
     fn address(&self) -> u64;
+
+    /// The symbol size, or 0 if the size is unknown.
+    #[inline]
+    fn size(&self) -> u64 {
+        0
+    }
 }
 
 /// A map from addresses to symbols.
 #[derive(Debug, Default, Clone)]
 pub struct SymbolMap<T: SymbolMapEntry> {
     symbols: Vec<T>,
+    /// For each symbol, the index of the closest earlier symbol that extends past
+    /// the start of the symbol.
+    ///
+    /// Following these links from a symbol visits every earlier symbol that may
+    /// contain an address after the start of the symbol. For nested symbols, the
+    /// number of links is the nesting depth.
+    parents: Vec<Option<usize>>,
 }
 
 impl<T: SymbolMapEntry> SymbolMap<T> {
     /// Construct a new symbol map.
     ///
-    /// This function will sort the symbols by address.
+    /// This function will sort the symbols by address, and then by decreasing size.
     pub fn new(mut symbols: Vec<T>) -> Self {
-        symbols.sort_unstable_by_key(|s| s.address());
-        SymbolMap { symbols }
+        symbols.sort_unstable_by_key(|s| (s.address(), Reverse(s.size())));
+        let mut parents: Vec<Option<usize>> = Vec::with_capacity(symbols.len());
+        let mut previous = None;
+        for (index, symbol) in symbols.iter().enumerate() {
+            // Skip the symbols that end before this one starts. These cannot contain
+            // any address that is looked up via this symbol or any later symbol.
+            let mut parent = previous;
+            while let Some(parent_index) = parent {
+                let parent_symbol: &T = &symbols[parent_index];
+                if parent_symbol.address().saturating_add(parent_symbol.size()) > symbol.address() {
+                    break;
+                }
+                parent = parents[parent_index];
+            }
+            parents.push(parent);
+            previous = Some(index);
+        }
+        SymbolMap { symbols, parents }
     }
 
-    /// Get the symbol before the given address.
+    /// Get the symbol for the given address.
+    ///
+    /// If any symbols with a known size contain the address, then this returns the
+    /// one with the highest address, and then the smallest size. For nested symbols,
+    /// this is the innermost symbol. For partially overlapping symbols, this is the
+    /// symbol that starts last.
+    ///
+    /// Otherwise, this returns the symbol with the highest address that is not greater
+    /// than the given address, even if its size is known and does not contain the
+    /// address.
+    ///
+    /// This uses a binary search to find the symbol with the highest address, and then
+    /// checks the symbols that it is nested within.
     pub fn get(&self, address: u64) -> Option<&T> {
-        let index = match self
+        // Find the last symbol with an address that is not greater than `address`.
+        // For symbols with the same address, this is the one with the smallest size.
+        let last = self
             .symbols
-            .binary_search_by_key(&address, |symbol| symbol.address())
-        {
-            Ok(index) => index,
-            Err(index) => index.checked_sub(1)?,
-        };
-        self.symbols.get(index)
+            .binary_search_by(|symbol| symbol.address().cmp(&address).then(Ordering::Less))
+            .unwrap_err()
+            .checked_sub(1)?;
+        let mut index = Some(last);
+        while let Some(i) = index {
+            let symbol = &self.symbols[i];
+            if address - symbol.address() < symbol.size() {
+                return Some(symbol);
+            }
+            index = self.parents[i];
+        }
+        self.symbols.get(last)
     }
 
     /// Get all symbols in the map.
@@ -292,17 +422,99 @@ impl<T: SymbolMapEntry> SymbolMap<T> {
     }
 }
 
+impl<'data> SymbolMap<SymbolMapName<'data>> {
+    /// Construct a map from addresses to symbol names using a custom filter.
+    ///
+    /// Only the symbols for which `filter` returns true will be included in the map.
+    /// Symbols with invalid names are always skipped.
+    ///
+    /// `Object::symbol_map` uses this with a filter that only accepts symbol definitions.
+    pub fn with_filter<I, F>(symbols: I, filter: F) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ObjectSymbol<'data>,
+        F: Fn(&I::Item) -> bool,
+    {
+        let mut entries = Vec::new();
+        for symbol in symbols {
+            if !filter(&symbol) {
+                continue;
+            }
+            if let Ok(name) = symbol.name() {
+                entries.push(SymbolMapName::new(symbol.address(), name).with_size(symbol.size()));
+            }
+        }
+        SymbolMap::new(entries)
+    }
+}
+
+/// A map from absolute addresses to symbols in multiple modules.
+///
+/// Each module has a `SymbolMap` and the base address at which it is loaded.
+/// The base is added to the symbol addresses in the module's map, so for ELF
+/// shared libraries it is the load bias rather than the start of the mapping.
+#[derive(Debug, Default, Clone)]
+pub struct MultiSymbolMap<T: SymbolMapEntry> {
+    modules: Vec<(u64, SymbolMap<T>)>,
+    /// Indices into `modules`, sorted by base address.
+    order: Vec<usize>,
+}
+
+impl<T: SymbolMapEntry> MultiSymbolMap<T> {
+    /// Construct a new map from a list of base addresses and symbol maps.
+    pub fn new(modules: Vec<(u64, SymbolMap<T>)>) -> Self {
+        let mut order = (0..modules.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| modules[index].0);
+        MultiSymbolMap { modules, order }
+    }
+
+    /// Get the module and symbol before the given absolute address.
+    ///
+    /// The module is the one with the highest base address that is not greater than
+    /// `address`. The returned index is the index of that module in `modules`, and
+    /// the symbol address is relative to the module's base address.
+    pub fn get(&self, address: u64) -> Option<(usize, &T)> {
+        // Find the first module with a base that is greater than `address`.
+        let position = self
+            .order
+            .binary_search_by(|&index| self.modules[index].0.cmp(&address).then(Ordering::Less))
+            .unwrap_err()
+            .checked_sub(1)?;
+        let index = self.order[position];
+        let (base, map) = &self.modules[index];
+        map.get(address - base).map(|symbol| (index, symbol))
+    }
+
+    /// Get all modules in the map, in the order they were given to `new`.
+    #[inline]
+    pub fn modules(&self) -> &[(u64, SymbolMap<T>)] {
+        &self.modules
+    }
+}
+
 /// A `SymbolMap` entry for symbol names.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SymbolMapName<'data> {
     address: u64,
+    size: u64,
     name: &'data str,
 }
 
 impl<'data> SymbolMapName<'data> {
-    /// Construct a `SymbolMapName`.
+    /// Construct a `SymbolMapName` with an unknown size.
     pub fn new(address: u64, name: &'data str) -> Self {
-        SymbolMapName { address, name }
+        SymbolMapName {
+            address,
+            size: 0,
+            name,
+        }
+    }
+
+    /// Set the symbol size.
+    #[inline]
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = size;
+        self
     }
 
     /// The symbol address.
@@ -311,6 +523,12 @@ impl<'data> SymbolMapName<'data> {
         self.address
     }
 
+    /// The symbol size, or 0 if the size is unknown.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
     /// The symbol name.
     #[inline]
     pub fn name(&self) -> &'data str {
@@ -323,6 +541,84 @@ impl<'data> SymbolMapEntry for SymbolMapName<'data> {
     fn address(&self) -> u64 {
         self.address
     }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// A map from symbol names to addresses.
+///
+/// This is the name-keyed equivalent of `SymbolMap`.
+#[derive(Debug, Default, Clone)]
+pub struct SymbolNameMap<'data> {
+    symbols: Vec<SymbolMapName<'data>>,
+}
+
+impl<'data> SymbolNameMap<'data> {
+    /// Construct a new symbol name map.
+    ///
+    /// This function will sort the symbols by name. Symbols with the same name
+    /// retain their relative order.
+    pub fn new(mut symbols: Vec<SymbolMapName<'data>>) -> Self {
+        symbols.sort_by_key(|s| s.name());
+        SymbolNameMap { symbols }
+    }
+
+    /// Construct a map containing the symbol definitions in the given object file.
+    ///
+    /// This uses the same symbols as `Object::symbol_map`: the dynamic symbol table
+    /// will only be used if there are no debugging symbols.
+    pub fn from_object<'file, O>(object: &'file O) -> Self
+    where
+        'data: 'file,
+        O: Object<'data, 'file>,
+    {
+        let mut entries = Vec::new();
+        if let Some(table) = object
+            .symbol_table()
+            .or_else(|| object.dynamic_symbol_table())
+        {
+            for symbol in table.symbols() {
+                if !symbol.is_definition() {
+                    continue;
+                }
+                if let Ok(name) = symbol.name() {
+                    entries.push(SymbolMapName::new(symbol.address(), name));
+                }
+            }
+        }
+        SymbolNameMap::new(entries)
+    }
+
+    /// Get the symbol with the given name.
+    ///
+    /// If there are multiple symbols with this name, then the first one in the
+    /// symbol table is returned. Use `get_all` to find all of them.
+    pub fn get(&self, name: &str) -> Option<&SymbolMapName<'data>> {
+        self.get_all(name).first()
+    }
+
+    /// Get all symbols with the given name, in symbol table order.
+    pub fn get_all(&self, name: &str) -> &[SymbolMapName<'data>] {
+        // Find the first symbol that is not less than `name`.
+        let start = self
+            .symbols
+            .binary_search_by(|symbol| symbol.name().cmp(name).then(Ordering::Greater))
+            .unwrap_err();
+        let len = self.symbols[start..]
+            .iter()
+            .take_while(|symbol| symbol.name() == name)
+            .count();
+        &self.symbols[start..][..len]
+    }
+
+    /// Get all symbols in the map.
+    #[inline]
+    pub fn symbols(&self) -> &[SymbolMapName<'data>] {
+        &self.symbols
+    }
 }
 
 /// A map from addresses to symbol names and object files.
@@ -403,6 +699,11 @@ impl<'data> SymbolMapEntry for ObjectMapEntry<'data> {
     fn address(&self) -> u64 {
         self.address
     }
+
+    #[inline]
+    fn size(&self) -> u64 {
+        self.size
+    }
 }
 
 /// An imported symbol.
@@ -433,6 +734,7 @@ pub struct Export<'data> {
     // TODO: and ordinal?
     name: ByteString<'data>,
     address: u64,
+    forward: Option<ByteString<'data>>,
 }
 
 impl<'data> Export<'data> {
@@ -443,10 +745,90 @@ impl<'data> Export<'data> {
     }
 
     /// The symbol address.
+    ///
+    /// This is 0 for forwarded exports.
     #[inline]
     pub fn address(&self) -> u64 {
         self.address
     }
+
+    /// The forwarder string, if this is a forwarded PE export.
+    ///
+    /// This has the form `DLL.Symbol` or `DLL.#Ordinal`.
+    #[inline]
+    pub fn forward(&self) -> Option<&'data [u8]> {
+        self.forward.map(|forward| forward.0)
+    }
+
+    /// The DLL name and symbol that this export is forwarded to, if this is a
+    /// forwarded PE export.
+    ///
+    /// The DLL name does not include the file extension.
+    ///
+    /// Returns `None` if this export is not forwarded, or if the forwarder
+    /// string is invalid.
+    pub fn forward_target(&self) -> Option<(&'data [u8], ForwardName<'data>)> {
+        let forward = self.forward?.0;
+        let dot = forward.iter().rposition(|&b| b == b'.')?;
+        let (dll, name) = (&forward[..dot], &forward[dot + 1..]);
+        if dll.is_empty() || name.is_empty() {
+            return None;
+        }
+        let name = if name[0] == b'#' {
+            let ordinal = str::from_utf8(&name[1..]).ok()?.parse().ok()?;
+            ForwardName::Ordinal(ordinal)
+        } else {
+            ForwardName::Name(name)
+        };
+        Some((dll, name))
+    }
+}
+
+/// The target symbol of a forwarded export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ForwardName<'data> {
+    /// The export is forwarded to a symbol name.
+    Name(&'data [u8]),
+    /// The export is forwarded to an ordinal.
+    Ordinal(u16),
+}
+
+/// Memory access permissions for a segment or section.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permissions {
+    read: bool,
+    write: bool,
+    execute: bool,
+}
+
+impl Permissions {
+    /// Construct a `Permissions`.
+    #[inline]
+    pub fn new(read: bool, write: bool, execute: bool) -> Self {
+        Permissions {
+            read,
+            write,
+            execute,
+        }
+    }
+
+    /// Return true if the memory is readable.
+    #[inline]
+    pub fn read(&self) -> bool {
+        self.read
+    }
+
+    /// Return true if the memory is writable.
+    #[inline]
+    pub fn write(&self) -> bool {
+        self.write
+    }
+
+    /// Return true if the memory is executable.
+    #[inline]
+    pub fn execute(&self) -> bool {
+        self.execute
+    }
 }
 
 /// PDB Information
@@ -486,6 +868,11 @@ pub enum RelocationTarget {
     Section(SectionIndex),
     /// The offset is an absolute address.
     Absolute,
+    /// The target is a symbol in the dynamic symbol table.
+    ///
+    /// This is used for ELF relocation sections that use the dynamic symbol table,
+    /// such as `.rela.plt`. The symbol can be found using `Object::dynamic_symbol_table`.
+    DynamicSymbol(SymbolIndex),
 }
 
 /// A relocation entry.
@@ -544,6 +931,92 @@ impl Relocation {
     pub fn has_implicit_addend(&self) -> bool {
         self.implicit_addend
     }
+
+    /// Read the implicit addend that is stored in the data at the place of the relocation.
+    ///
+    /// `data` is the data of the section that the relocation applies to, and `offset`
+    /// is the offset of the relocation within that data. The value is read using the
+    /// size of the relocation and sign extended.
+    ///
+    /// Returns `Ok(0)` if the relocation does not have an implicit addend.
+    /// Returns an error if the size of the relocation is unknown, if the encoding
+    /// stores the addend in some other way, or if the data is too short.
+    pub fn read_implicit_addend(
+        &self,
+        data: &[u8],
+        offset: u64,
+        endian: Endianness,
+    ) -> Result<i64> {
+        if !self.implicit_addend {
+            return Ok(0);
+        }
+        match self.encoding {
+            RelocationEncoding::Generic
+            | RelocationEncoding::X86Signed
+            | RelocationEncoding::X86RipRelative
+            | RelocationEncoding::X86RipRelativeMovq
+            | RelocationEncoding::X86Branch => {}
+            _ => return Err(Error("Unsupported relocation encoding for implicit addend")),
+        }
+        let offset = offset
+            .try_into()
+            .map_err(|_| Error("Invalid relocation offset"))?;
+        let data = Bytes(data);
+        let addend = match self.size {
+            8 => data.read_at::<u8>(offset).map(|x| i64::from(*x as i8)),
+            16 => data
+                .read_at::<U16Bytes<_>>(offset)
+                .map(|x| i64::from(x.get(endian) as i16)),
+            32 => data
+                .read_at::<U32Bytes<_>>(offset)
+                .map(|x| i64::from(x.get(endian) as i32)),
+            64 => data
+                .read_at::<U64Bytes<_>>(offset)
+                .map(|x| x.get(endian) as i64),
+            _ => return Err(Error("Unsupported relocation size for implicit addend")),
+        };
+        addend.read_error("Invalid relocation offset for implicit addend")
+    }
+}
+
+/// A relocation entry as it is stored in the file.
+///
+/// Returned by `ObjectSection::raw_relocations`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawRelocation {
+    /// ELF relocation.
+    Elf {
+        /// `r_offset` field in the relocation.
+        r_offset: u64,
+        /// `r_info` field in the relocation.
+        ///
+        /// This is the value as stored, without the byte swapping that is
+        /// needed to extract the symbol and type for MIPS64 little endian.
+        r_info: u64,
+        /// `r_addend` field in the relocation, or `None` for `SHT_REL` sections.
+        r_addend: Option<i64>,
+    },
+    /// Mach-O relocation.
+    MachO {
+        /// The first word of the relocation.
+        ///
+        /// This is `r_address`, or the packed fields of a scattered relocation.
+        r_word0: u32,
+        /// The second word of the relocation.
+        ///
+        /// This is the packed fields of a non-scattered relocation, or
+        /// `r_value` of a scattered relocation.
+        r_word1: u32,
+    },
+    /// COFF relocation.
+    Coff {
+        /// `VirtualAddress` field in the relocation.
+        virtual_address: u32,
+        /// `SymbolTableIndex` field in the relocation.
+        symbol_table_index: u32,
+        /// `Type` field in the relocation.
+        typ: u16,
+    },
 }
 
 /// A data compression format.
@@ -557,6 +1030,10 @@ pub enum CompressionFormat {
     ///
     /// Used for ELF compression and GNU compressed debug information.
     Zlib,
+    /// Zstandard.
+    ///
+    /// Used for ELF compression.
+    Zstandard,
 }
 
 /// A range in a file that may be compressed.
@@ -606,6 +1083,23 @@ impl CompressedFileRange {
     }
 }
 
+/// The sizes of a section.
+///
+/// Returned by `ObjectSection::sizes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SectionSizes {
+    /// The size of the section data in the file.
+    ///
+    /// This is 0 for sections that do not have data in the file.
+    pub file_size: u64,
+    /// The size of the section in memory.
+    pub memory_size: u64,
+    /// The size of the section data in the file after decompression.
+    ///
+    /// This is equal to `file_size` if the section is not compressed.
+    pub uncompressed_size: u64,
+}
+
 /// Data that may be compressed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct CompressedData<'data> {
@@ -632,13 +1126,12 @@ impl<'data> CompressedData<'data> {
     ///
     /// Returns an error for invalid data or unsupported compression.
     /// This includes if the data is compressed but the `compression` feature
-    /// for this crate is disabled.
+    /// (or the `compression-zstd` feature for Zstandard) for this crate is disabled.
     pub fn decompress(self) -> Result<Cow<'data, [u8]>> {
         match self.format {
             CompressionFormat::None => Ok(Cow::Borrowed(self.data)),
             #[cfg(feature = "compression")]
             CompressionFormat::Zlib => {
-                use core::convert::TryInto;
                 let size = self
                     .uncompressed_size
                     .try_into()
@@ -656,6 +1149,28 @@ impl<'data> CompressedData<'data> {
                     .read_error("Invalid zlib compressed data")?;
                 Ok(Cow::Owned(decompressed))
             }
+            #[cfg(feature = "compression-zstd")]
+            CompressionFormat::Zstandard => {
+                use std::io::Read;
+                let size = self
+                    .uncompressed_size
+                    .try_into()
+                    .ok()
+                    .read_error("Uncompressed data size is too large.")?;
+                let mut decompressed = Vec::with_capacity(size);
+                // The data may contain multiple frames.
+                let mut input = self.data;
+                while !input.is_empty() {
+                    let mut decoder = ruzstd::StreamingDecoder::new(&mut input)
+                        .ok()
+                        .read_error("Invalid zstd compressed data")?;
+                    decoder
+                        .read_to_end(&mut decompressed)
+                        .ok()
+                        .read_error("Invalid zstd compressed data")?;
+                }
+                Ok(Cow::Owned(decompressed))
+            }
             _ => Err(Error("Unsupported compressed data.")),
         }
     }
@@ -1,4 +1,6 @@
+use alloc::format;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::{mem, str};
 
@@ -6,11 +8,12 @@ use core::convert::TryInto;
 
 use crate::read::coff::{CoffCommon, CoffSymbol, CoffSymbolIterator, CoffSymbolTable, SymbolTable};
 use crate::read::{
-    self, Architecture, ComdatKind, Error, Export, FileFlags, Import, NoDynamicRelocationIterator,
-    Object, ObjectComdat, ReadError, ReadRef, Result, SectionIndex, SymbolIndex,
+    self, toolchain, Architecture, ComdatKind, Error, Export, FileFlags, Import,
+    NoDynamicRelocationIterator, Object, ObjectComdat, ReadError, ReadRef, Result, SectionIndex,
+    SymbolIndex, Toolchain,
 };
 use crate::{
-    pe, ByteString, Bytes, CodeView, LittleEndian as LE, Pod, U16Bytes, U32Bytes, U32, U64,
+    pe, pod, ByteString, Bytes, CodeView, LittleEndian as LE, Pod, U16Bytes, U32Bytes, U32, U64,
 };
 
 use super::{PeSection, PeSectionIterator, PeSegment, PeSegmentIterator, SectionTable};
@@ -61,6 +64,32 @@ where
         })
     }
 
+    /// Parse the raw PE file data, recovering from errors where possible.
+    ///
+    /// Errors in the DOS header and NT headers are fatal. Errors in the section
+    /// table and symbol table are added to `errors`, and the corresponding tables
+    /// are treated as empty.
+    pub fn parse_lenient(data: R, errors: &mut Vec<Error>) -> Result<Self> {
+        let dos_header = pe::ImageDosHeader::parse(data)?;
+        let mut offset = dos_header.nt_headers_offset().into();
+        let (nt_headers, data_directories) = Pe::parse(data, &mut offset)?;
+        let sections = read::recover(nt_headers.sections(data, offset), errors);
+        let symbols = read::recover(nt_headers.symbols(data), errors);
+        let image_base = nt_headers.optional_header().image_base();
+
+        Ok(PeFile {
+            dos_header,
+            nt_headers,
+            data_directories,
+            common: CoffCommon {
+                sections,
+                symbols,
+                image_base,
+            },
+            data,
+        })
+    }
+
     pub(super) fn section_alignment(&self) -> u64 {
         u64::from(self.nt_headers.optional_header().section_alignment())
     }
@@ -70,11 +99,424 @@ where
         self.dos_header
     }
 
+    /// Return the MS-DOS stub of this file.
+    ///
+    /// This is the data between the DOS header and the NT headers, as given by
+    /// `ImageDosHeader::nt_headers_offset`. It includes the Rich header, if present.
+    pub fn dos_stub(&self) -> Result<&'data [u8]> {
+        let start = mem::size_of::<pe::ImageDosHeader>() as u64;
+        let end = u64::from(self.dos_header.nt_headers_offset());
+        self.data
+            .read_bytes_at(start, end.saturating_sub(start))
+            .read_error("Invalid PE DOS stub size")
+    }
+
+    /// Return true if the export name table contains the given name.
+    ///
+    /// The export name table is sorted, so this uses a binary search.
+    fn export_name_exists(&self, name: &[u8]) -> Result<bool> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_EXPORT) {
+            Some(data_dir) => data_dir,
+            None => return Ok(false),
+        };
+        let export_va = data_dir.virtual_address.get(LE);
+        let export_data = data_dir.data(self.data, &self.common.sections).map(Bytes)?;
+        let export_dir = export_data
+            .read_at::<pe::ImageExportDirectory>(0)
+            .read_error("Invalid PE export dir size")?;
+        let names = export_data
+            .read_slice_at::<U32Bytes<_>>(
+                export_dir.address_of_names.get(LE).wrapping_sub(export_va) as usize,
+                export_dir.number_of_names.get(LE) as usize,
+            )
+            .read_error("Invalid PE export name table")?;
+        let mut low = 0;
+        let mut high = names.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let mid_name = export_data
+                .read_string_at(names[mid].get(LE).wrapping_sub(export_va) as usize)
+                .read_error("Invalid PE export name entry")?;
+            match mid_name.cmp(name) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(true),
+            }
+        }
+        Ok(false)
+    }
+
+    /// Call `f` with the library and name of each import by name, stopping when
+    /// `f` returns true.
+    ///
+    /// Returns true if `f` returned true. This does not allocate.
+    fn visit_imports<F>(&self, mut f: F) -> Result<bool>
+    where
+        F: FnMut(&'data [u8], &'data [u8]) -> bool,
+    {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_IMPORT) {
+            Some(data_dir) => data_dir,
+            None => return Ok(false),
+        };
+        let mut import_descriptors = data_dir.data(self.data, &self.common.sections).map(Bytes)?;
+        loop {
+            let import_desc = import_descriptors
+                .read::<pe::ImageImportDescriptor>()
+                .read_error("Missing PE null import descriptor")?;
+            if import_desc.original_first_thunk.get(LE) == 0 {
+                break;
+            }
+
+            let library = self
+                .data_at(import_desc.name.get(LE))
+                .read_error("Invalid PE import descriptor name")?
+                .read_string()
+                .read_error("Invalid PE import descriptor name")?;
+
+            let thunk_va = import_desc.original_first_thunk.get(LE);
+            let mut thunk_data = self
+                .data_at(thunk_va)
+                .read_error("Invalid PE import thunk address")?;
+            loop {
+                let hint_name = if self.is_64() {
+                    let thunk = thunk_data
+                        .read::<U64<_>>()
+                        .read_error("Missing PE null import thunk")?
+                        .get(LE);
+                    if thunk == 0 {
+                        break;
+                    }
+                    if thunk & pe::IMAGE_ORDINAL_FLAG64 != 0 {
+                        // TODO: handle import by ordinal
+                        continue;
+                    }
+                    thunk as u32
+                } else {
+                    let thunk = thunk_data
+                        .read::<U32<_>>()
+                        .read_error("Missing PE null import thunk")?
+                        .get(LE);
+                    if thunk == 0 {
+                        break;
+                    }
+                    if thunk & pe::IMAGE_ORDINAL_FLAG32 != 0 {
+                        // TODO: handle import by ordinal
+                        continue;
+                    }
+                    thunk
+                };
+                let name = self
+                    .data_at(hint_name)
+                    .read_error("Invalid PE import thunk name")?
+                    .read_string_at(2)
+                    .read_error("Invalid PE import thunk name")?;
+
+                if f(library, name) {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
     /// Return the NT Headers of this file
     pub fn nt_headers(&self) -> &'data Pe {
         self.nt_headers
     }
 
+    /// Get the DLLs recorded in the bound import directory.
+    ///
+    /// Returns an empty vector if the file does not have a bound import directory.
+    pub fn bound_imports(&self) -> Result<Vec<BoundImport<'data>>> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT) {
+            Some(data_dir) => data_dir,
+            None => return Ok(Vec::new()),
+        };
+        // The bound import directory is stored in the headers, which are not part
+        // of any section, so the address is a file offset.
+        let bound_data = self
+            .data
+            .read_bytes_at(
+                data_dir.virtual_address.get(LE).into(),
+                data_dir.size.get(LE).into(),
+            )
+            .read_error("Invalid PE bound import directory size or offset")
+            .map(Bytes)?;
+        let mut descriptors = bound_data;
+        let mut bound_imports = Vec::new();
+        loop {
+            let descriptor = descriptors
+                .read::<pe::ImageBoundImportDescriptor>()
+                .read_error("Missing PE null bound import descriptor")?;
+            if descriptor.time_date_stamp.get(LE) == 0 && descriptor.offset_module_name.get(LE) == 0
+            {
+                break;
+            }
+            let name = bound_data
+                .read_string_at(descriptor.offset_module_name.get(LE).into())
+                .read_error("Invalid PE bound import descriptor name")?;
+            bound_imports.push(BoundImport {
+                name: ByteString(name),
+                time_date_stamp: descriptor.time_date_stamp.get(LE),
+            });
+            // Skip the forwarder references that follow the descriptor.
+            descriptors
+                .read_slice::<pe::ImageBoundForwarderRef>(
+                    descriptor.number_of_module_forwarder_refs.get(LE).into(),
+                )
+                .read_error("Invalid PE bound import forwarder references")?;
+        }
+        Ok(bound_imports)
+    }
+
+    /// Return the load configuration directory.
+    ///
+    /// The size of this directory depends on the version of the linker that created
+    /// the image. Fields that are not within the size recorded in the directory are
+    /// returned as zero.
+    pub fn load_config(&self) -> Result<Option<LoadConfig>> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG) {
+            Some(data_dir) => data_dir,
+            None => return Ok(None),
+        };
+        let data = self
+            .data_at(data_dir.virtual_address.get(LE))
+            .read_error("Invalid PE load config directory virtual address")?;
+        // Use the size in the directory itself, rather than the data directory size.
+        let size = data
+            .read_at::<U32Bytes<LE>>(0)
+            .read_error("Invalid PE load config directory size")?
+            .get(LE);
+        let data = data
+            .read_bytes_at(0, size as usize)
+            .read_error("Invalid PE load config directory size")?
+            .0;
+        let config = if self.nt_headers.is_type_64() {
+            let config = read_versioned::<pe::ImageLoadConfigDirectory64>(data)?;
+            LoadConfig {
+                size,
+                security_cookie: config.security_cookie.get(LE),
+                se_handler_table: config.sehandler_table.get(LE),
+                se_handler_count: config.sehandler_count.get(LE),
+                guard_cf_check_function_pointer: config.guard_cf_check_function_pointer.get(LE),
+                guard_cf_dispatch_function_pointer: config
+                    .guard_cf_dispatch_function_pointer
+                    .get(LE),
+                guard_cf_function_table: config.guard_cf_function_table.get(LE),
+                guard_cf_function_count: config.guard_cf_function_count.get(LE),
+                guard_flags: config.guard_flags.get(LE),
+            }
+        } else {
+            let config = read_versioned::<pe::ImageLoadConfigDirectory32>(data)?;
+            LoadConfig {
+                size,
+                security_cookie: config.security_cookie.get(LE).into(),
+                se_handler_table: config.sehandler_table.get(LE).into(),
+                se_handler_count: config.sehandler_count.get(LE).into(),
+                guard_cf_check_function_pointer: config
+                    .guard_cf_check_function_pointer
+                    .get(LE)
+                    .into(),
+                guard_cf_dispatch_function_pointer: config
+                    .guard_cf_dispatch_function_pointer
+                    .get(LE)
+                    .into(),
+                guard_cf_function_table: config.guard_cf_function_table.get(LE).into(),
+                guard_cf_function_count: config.guard_cf_function_count.get(LE).into(),
+                guard_flags: config.guard_flags.get(LE),
+            }
+        };
+        Ok(Some(config))
+    }
+
+    /// Return true if the image was built reproducibly.
+    ///
+    /// This checks for an `IMAGE_DEBUG_TYPE_REPRO` entry in the debug directory.
+    /// If present, the `TimeDateStamp` fields in the image are a hash of the image
+    /// contents rather than a time.
+    pub fn is_reproducible(&self) -> Result<bool> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_DEBUG) {
+            Some(data_dir) => data_dir,
+            None => return Ok(false),
+        };
+        let debug_data = data_dir.data(self.data, &self.common.sections).map(Bytes)?;
+        let debug_dirs = debug_data
+            .read_slice_at::<pe::ImageDebugDirectory>(
+                0,
+                debug_data.len() / mem::size_of::<pe::ImageDebugDirectory>(),
+            )
+            .read_error("Invalid PE debug dir size")?;
+        Ok(debug_dirs
+            .iter()
+            .any(|debug_dir| debug_dir.typ.get(LE) == pe::IMAGE_DEBUG_TYPE_REPRO))
+    }
+
+    /// Return the entries of the attribute certificate table.
+    ///
+    /// For Authenticode signatures, these entries contain a PKCS#7 `SignedData`
+    /// structure.
+    ///
+    /// Returns an empty vector if the file does not have a security directory.
+    pub fn certificates(&self) -> Result<Vec<Certificate<'data>>> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_SECURITY) {
+            Some(data_dir) => data_dir,
+            None => return Ok(Vec::new()),
+        };
+        // The certificate table is not loaded into memory, so the address is a file offset.
+        let mut data = self
+            .data
+            .read_bytes_at(
+                data_dir.virtual_address.get(LE).into(),
+                data_dir.size.get(LE).into(),
+            )
+            .read_error("Invalid PE certificate table size or offset")
+            .map(Bytes)?;
+        let mut certificates = Vec::new();
+        while !data.is_empty() {
+            let header = data
+                .read_at::<pe::WinCertificate>(0)
+                .read_error("Invalid PE certificate size")?;
+            let length = header.length.get(LE) as usize;
+            let certificate = data
+                .read_bytes_at(
+                    mem::size_of::<pe::WinCertificate>(),
+                    length
+                        .checked_sub(mem::size_of::<pe::WinCertificate>())
+                        .read_error("Invalid PE certificate length")?,
+                )
+                .read_error("Invalid PE certificate length")?;
+            certificates.push(Certificate {
+                revision: header.revision.get(LE),
+                certificate_type: header.certificate_type.get(LE),
+                data: certificate.0,
+            });
+            // Entries are aligned to 8 bytes, but the last entry may not be padded.
+            let next = read::util::align(length, 8).min(data.len());
+            data.skip(next)
+                .read_error("Invalid PE certificate length")?;
+        }
+        Ok(certificates)
+    }
+
+    /// Return the entries of the exception directory, which is usually the `.pdata` section.
+    ///
+    /// Each entry gives the address range of a function and the address of its unwind
+    /// information, which is usually in the `.xdata` section. This is supported for
+    /// x86-64 and AArch64 images.
+    ///
+    /// Returns an empty vector if the file does not have an exception directory.
+    pub fn exception_functions(&self) -> Result<Vec<RuntimeFunction>> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_EXCEPTION) {
+            Some(data_dir) => data_dir,
+            None => return Ok(Vec::new()),
+        };
+        let data = data_dir.data(self.data, &self.common.sections).map(Bytes)?;
+        match self.nt_headers.file_header().machine.get(LE) {
+            pe::IMAGE_FILE_MACHINE_AMD64 => {
+                let entries = data
+                    .read_slice_at::<pe::ImageRuntimeFunctionEntry>(
+                        0,
+                        data.len() / mem::size_of::<pe::ImageRuntimeFunctionEntry>(),
+                    )
+                    .read_error("Invalid PE exception directory size")?;
+                Ok(entries
+                    .iter()
+                    .map(|entry| RuntimeFunction {
+                        begin_address: entry.begin_address.get(LE),
+                        end_address: entry.end_address.get(LE),
+                        unwind_info_address: Some(entry.unwind_info_address_or_data.get(LE)),
+                    })
+                    .collect())
+            }
+            pe::IMAGE_FILE_MACHINE_ARM64 => {
+                let entries = data
+                    .read_slice_at::<pe::ImageArm64RuntimeFunctionEntry>(
+                        0,
+                        data.len() / mem::size_of::<pe::ImageArm64RuntimeFunctionEntry>(),
+                    )
+                    .read_error("Invalid PE exception directory size")?;
+                let mut functions = Vec::with_capacity(entries.len());
+                for entry in entries {
+                    let begin_address = entry.begin_address.get(LE);
+                    let unwind_data = entry.unwind_data.get(LE);
+                    // The low 2 bits are a flag that is 0 if the unwind data is an
+                    // `.xdata` record, or the format of the packed unwind data.
+                    // Both store the function length in units of 4 bytes.
+                    let (length, unwind_info_address) = if unwind_data & 3 == 0 {
+                        let header = self
+                            .data_at(unwind_data)
+                            .and_then(|data| data.read_at::<U32Bytes<LE>>(0).ok())
+                            .read_error("Invalid PE ARM64 unwind info address")?
+                            .get(LE);
+                        (header & 0x3ffff, Some(unwind_data))
+                    } else {
+                        ((unwind_data >> 2) & 0x7ff, None)
+                    };
+                    functions.push(RuntimeFunction {
+                        begin_address,
+                        end_address: begin_address.wrapping_add(length * 4),
+                        unwind_info_address,
+                    });
+                }
+                Ok(functions)
+            }
+            _ => Err(Error("Unsupported PE machine for exception directory")),
+        }
+    }
+
+    /// Return the application manifest from the resource directory.
+    ///
+    /// This is the data of the first `RT_MANIFEST` resource, which is normally
+    /// UTF-8 encoded XML. If there are multiple names or languages for the manifest,
+    /// then the first of each is used.
+    ///
+    /// Returns `Ok(None)` if the file does not have a manifest.
+    pub fn manifest(&self) -> Result<Option<&'data [u8]>> {
+        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_RESOURCE) {
+            Some(data_dir) => data_dir,
+            None => return Ok(None),
+        };
+        let data = data_dir.data(self.data, &self.common.sections).map(Bytes)?;
+        // The levels of the resource tree are the type, name, and language.
+        let mut offset = 0;
+        for level in 0..2 {
+            let entries = resource_directory_entries(data, offset)?;
+            let entry = if level == 0 {
+                entries
+                    .iter()
+                    .find(|entry| entry.name_or_id.get(LE) == u32::from(pe::RT_MANIFEST))
+            } else {
+                entries.first()
+            };
+            let entry = match entry {
+                Some(entry) => entry.offset_to_data_or_directory.get(LE),
+                None => return Ok(None),
+            };
+            if entry & pe::IMAGE_RESOURCE_DATA_IS_DIRECTORY == 0 {
+                return Err(Error("Invalid PE resource directory depth"));
+            }
+            offset = entry & !pe::IMAGE_RESOURCE_DATA_IS_DIRECTORY;
+        }
+        let entry = resource_directory_entries(data, offset)?
+            .first()
+            .map(|entry| entry.offset_to_data_or_directory.get(LE));
+        let entry = match entry {
+            Some(entry) if entry & pe::IMAGE_RESOURCE_DATA_IS_DIRECTORY != 0 => {
+                return Err(Error("Invalid PE resource directory depth"));
+            }
+            Some(entry) => data
+                .read_at::<pe::ImageResourceDataEntry>(entry as usize)
+                .read_error("Invalid PE resource data entry offset")?,
+            None => return Ok(None),
+        };
+        // The data offset is a virtual address, not an offset in the resource directory.
+        let manifest = self
+            .data_at(entry.offset_to_data.get(LE))
+            .and_then(|data| data.read_bytes_at(0, entry.size.get(LE) as usize).ok())
+            .read_error("Invalid PE resource data address or size")?;
+        Ok(Some(manifest.0))
+    }
+
     fn data_directory(&self, id: usize) -> Option<&'data pe::ImageDataDirectory> {
         self.data_directories
             .get(id)
@@ -86,6 +528,198 @@ where
     }
 }
 
+/// A DLL recorded in the PE bound import directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundImport<'data> {
+    name: ByteString<'data>,
+    time_date_stamp: u32,
+}
+
+impl<'data> BoundImport<'data> {
+    /// The name of the DLL.
+    #[inline]
+    pub fn name(&self) -> &'data [u8] {
+        self.name.0
+    }
+
+    /// The time/date stamp of the DLL that the imports were bound to.
+    #[inline]
+    pub fn time_date_stamp(&self) -> u32 {
+        self.time_date_stamp
+    }
+}
+
+/// An entry in the PE attribute certificate table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Certificate<'data> {
+    revision: u16,
+    certificate_type: u16,
+    data: &'data [u8],
+}
+
+impl<'data> Certificate<'data> {
+    /// The revision of the certificate structure.
+    ///
+    /// This is one of the `WIN_CERT_REVISION_*` values.
+    #[inline]
+    pub fn revision(&self) -> u16 {
+        self.revision
+    }
+
+    /// The type of the certificate data.
+    ///
+    /// This is one of the `WIN_CERT_TYPE_*` values.
+    #[inline]
+    pub fn certificate_type(&self) -> u16 {
+        self.certificate_type
+    }
+
+    /// The certificate data.
+    ///
+    /// For `WIN_CERT_TYPE_PKCS_SIGNED_DATA`, this is a DER encoded PKCS#7 `SignedData`
+    /// structure.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+}
+
+/// An entry in the PE exception directory.
+///
+/// Addresses are relative virtual addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeFunction {
+    begin_address: u32,
+    end_address: u32,
+    unwind_info_address: Option<u32>,
+}
+
+impl RuntimeFunction {
+    /// The address of the start of the function.
+    #[inline]
+    pub fn begin_address(&self) -> u32 {
+        self.begin_address
+    }
+
+    /// The address of the end of the function.
+    ///
+    /// This is the address of the first byte after the function.
+    #[inline]
+    pub fn end_address(&self) -> u32 {
+        self.end_address
+    }
+
+    /// The address of the unwind information for the function.
+    ///
+    /// Returns `None` for AArch64 entries that store packed unwind data instead.
+    #[inline]
+    pub fn unwind_info_address(&self) -> Option<u32> {
+        self.unwind_info_address
+    }
+}
+
+/// Read a structure that may be truncated, setting any missing fields to zero.
+fn read_versioned<T: Pod>(data: &[u8]) -> Result<T> {
+    // Use a `u64` buffer to ensure correct alignment.
+    let mut buf = vec![0u64; mem::size_of::<T>() / 8 + 1];
+    let len = data.len().min(mem::size_of::<T>());
+    pod::bytes_of_slice_mut(&mut buf)[..len].copy_from_slice(&data[..len]);
+    pod::from_bytes::<T>(pod::bytes_of_slice(&buf))
+        .map(|(val, _)| *val)
+        .read_error("Invalid structure alignment")
+}
+
+/// Read the entries of the resource directory at the given offset.
+fn resource_directory_entries(
+    data: Bytes<'_>,
+    offset: u32,
+) -> Result<&[pe::ImageResourceDirectoryEntry]> {
+    let directory = data
+        .read_at::<pe::ImageResourceDirectory>(offset as usize)
+        .read_error("Invalid PE resource directory offset")?;
+    let count = usize::from(directory.number_of_named_entries.get(LE))
+        + usize::from(directory.number_of_id_entries.get(LE));
+    data.read_slice_at(
+        offset as usize + mem::size_of::<pe::ImageResourceDirectory>(),
+        count,
+    )
+    .read_error("Invalid PE resource directory size")
+}
+
+/// The load configuration directory of a PE file.
+///
+/// Addresses are virtual addresses, not relative virtual addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadConfig {
+    size: u32,
+    security_cookie: u64,
+    se_handler_table: u64,
+    se_handler_count: u64,
+    guard_cf_check_function_pointer: u64,
+    guard_cf_dispatch_function_pointer: u64,
+    guard_cf_function_table: u64,
+    guard_cf_function_count: u64,
+    guard_flags: u32,
+}
+
+impl LoadConfig {
+    /// The size of the directory, as recorded in the directory.
+    #[inline]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// The address of the security cookie.
+    #[inline]
+    pub fn security_cookie(&self) -> u64 {
+        self.security_cookie
+    }
+
+    /// The address of the SafeSEH handler table.
+    #[inline]
+    pub fn se_handler_table(&self) -> u64 {
+        self.se_handler_table
+    }
+
+    /// The number of entries in the SafeSEH handler table.
+    #[inline]
+    pub fn se_handler_count(&self) -> u64 {
+        self.se_handler_count
+    }
+
+    /// The address of the control flow guard check function pointer.
+    #[inline]
+    pub fn guard_cf_check_function_pointer(&self) -> u64 {
+        self.guard_cf_check_function_pointer
+    }
+
+    /// The address of the control flow guard dispatch function pointer.
+    #[inline]
+    pub fn guard_cf_dispatch_function_pointer(&self) -> u64 {
+        self.guard_cf_dispatch_function_pointer
+    }
+
+    /// The address of the control flow guard function table.
+    #[inline]
+    pub fn guard_cf_function_table(&self) -> u64 {
+        self.guard_cf_function_table
+    }
+
+    /// The number of entries in the control flow guard function table.
+    #[inline]
+    pub fn guard_cf_function_count(&self) -> u64 {
+        self.guard_cf_function_count
+    }
+
+    /// The control flow guard flags.
+    ///
+    /// This is a combination of the `IMAGE_GUARD_*` flags.
+    #[inline]
+    pub fn guard_flags(&self) -> u32 {
+        self.guard_flags
+    }
+}
+
 impl<'data, Pe, R> read::private::Sealed for PeFile<'data, Pe, R>
 where
     Pe: ImageNtHeaders,
@@ -115,6 +749,8 @@ where
             // TODO: Arm/Arm64
             pe::IMAGE_FILE_MACHINE_I386 => Architecture::I386,
             pe::IMAGE_FILE_MACHINE_AMD64 => Architecture::X86_64,
+            pe::IMAGE_FILE_MACHINE_RISCV32 => Architecture::Riscv32,
+            pe::IMAGE_FILE_MACHINE_RISCV64 => Architecture::Riscv64,
             _ => Architecture::Unknown,
         }
     }
@@ -130,6 +766,16 @@ where
         self.nt_headers.is_type_64()
     }
 
+    #[inline]
+    fn is_relocatable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn default_page_size(&self) -> u64 {
+        0x1000
+    }
+
     fn segments(&'file self) -> PeSegmentIterator<'data, 'file, Pe, R> {
         PeSegmentIterator {
             file: self,
@@ -203,75 +849,30 @@ where
         None
     }
 
+    fn contains_symbol(&'file self, name: &str) -> bool {
+        let name_bytes = name.as_bytes();
+        if let Ok(true) = self.export_name_exists(name_bytes) {
+            return true;
+        }
+        if let Ok(true) = self.visit_imports(|_, import| import == name_bytes) {
+            return true;
+        }
+        read::symbol_tables_contain(self, name)
+    }
+
     fn dynamic_relocations(&'file self) -> Option<NoDynamicRelocationIterator> {
         None
     }
 
     fn imports(&self) -> Result<Vec<Import<'data>>> {
-        let data_dir = match self.data_directory(pe::IMAGE_DIRECTORY_ENTRY_IMPORT) {
-            Some(data_dir) => data_dir,
-            None => return Ok(Vec::new()),
-        };
-        let mut import_descriptors = data_dir.data(self.data, &self.common.sections).map(Bytes)?;
         let mut imports = Vec::new();
-        loop {
-            let import_desc = import_descriptors
-                .read::<pe::ImageImportDescriptor>()
-                .read_error("Missing PE null import descriptor")?;
-            if import_desc.original_first_thunk.get(LE) == 0 {
-                break;
-            }
-
-            let library = self
-                .data_at(import_desc.name.get(LE))
-                .read_error("Invalid PE import descriptor name")?
-                .read_string()
-                .read_error("Invalid PE import descriptor name")?;
-
-            let thunk_va = import_desc.original_first_thunk.get(LE);
-            let mut thunk_data = self
-                .data_at(thunk_va)
-                .read_error("Invalid PE import thunk address")?;
-            loop {
-                let hint_name = if self.is_64() {
-                    let thunk = thunk_data
-                        .read::<U64<_>>()
-                        .read_error("Missing PE null import thunk")?
-                        .get(LE);
-                    if thunk == 0 {
-                        break;
-                    }
-                    if thunk & pe::IMAGE_ORDINAL_FLAG64 != 0 {
-                        // TODO: handle import by ordinal
-                        continue;
-                    }
-                    thunk as u32
-                } else {
-                    let thunk = thunk_data
-                        .read::<U32<_>>()
-                        .read_error("Missing PE null import thunk")?
-                        .get(LE);
-                    if thunk == 0 {
-                        break;
-                    }
-                    if thunk & pe::IMAGE_ORDINAL_FLAG32 != 0 {
-                        // TODO: handle import by ordinal
-                        continue;
-                    }
-                    thunk
-                };
-                let name = self
-                    .data_at(hint_name)
-                    .read_error("Invalid PE import thunk name")?
-                    .read_string_at(2)
-                    .read_error("Invalid PE import thunk name")?;
-
-                imports.push(Import {
-                    name: ByteString(name),
-                    library: ByteString(library),
-                });
-            }
-        }
+        self.visit_imports(|library, name| {
+            imports.push(Import {
+                name: ByteString(name),
+                library: ByteString(library),
+            });
+            false
+        })?;
         Ok(imports)
     }
 
@@ -326,6 +927,16 @@ where
                 exports.push(Export {
                     name: ByteString(name),
                     address: self.common.image_base.wrapping_add(address.into()),
+                    forward: None,
+                })
+            } else {
+                let forward = export_data
+                    .read_string_at((address - export_va) as usize)
+                    .read_error("Invalid PE export forwarder")?;
+                exports.push(Export {
+                    name: ByteString(name),
+                    address: 0,
+                    forward: Some(ByteString(forward)),
                 })
             }
         }
@@ -387,10 +998,48 @@ where
         self.section_by_name(".debug_info").is_some()
     }
 
+    fn toolchain(&'file self) -> Toolchain {
+        let mut toolchain = toolchain::toolchain(self);
+        // The Microsoft linker adds a Rich header to the DOS stub.
+        if let Ok(stub) = self.dos_stub() {
+            if stub.chunks(4).any(|chunk| chunk == b"Rich") {
+                let optional_header = self.nt_headers.optional_header();
+                toolchain.add_linker(format!(
+                    "link {}.{}",
+                    optional_header.major_linker_version(),
+                    optional_header.minor_linker_version()
+                ));
+            }
+        }
+        toolchain
+    }
+
+    fn timestamp(&self) -> Option<u64> {
+        match self.nt_headers.file_header().time_date_stamp.get(LE) {
+            0 => None,
+            timestamp => Some(timestamp.into()),
+        }
+    }
+
     fn entry(&self) -> u64 {
         u64::from(self.nt_headers.optional_header().address_of_entry_point())
     }
 
+    fn entry_symbol(&'file self) -> Option<CoffSymbol<'data, 'file>> {
+        // The entry point is relative to the image base, but symbol addresses are not.
+        match self.entry() {
+            0 => None,
+            entry => read::traits::definition_at_address(
+                self,
+                self.common.image_base.wrapping_add(entry),
+            ),
+        }
+    }
+
+    fn preferred_base(&'file self) -> u64 {
+        self.common.image_base
+    }
+
     fn flags(&self) -> FileFlags {
         FileFlags::Coff {
             characteristics: self.nt_headers.file_header().characteristics.get(LE),
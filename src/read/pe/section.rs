@@ -1,11 +1,13 @@
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use core::{cmp, iter, result, slice, str};
 
 use crate::endian::LittleEndian as LE;
 use crate::pe;
+use crate::read::coff::{parse_line_numbers, LineNumber};
 use crate::read::{
-    self, CompressedData, CompressedFileRange, ObjectSection, ObjectSegment, ReadError, ReadRef,
-    Relocation, Result, SectionFlags, SectionIndex, SectionKind,
+    self, CompressedData, CompressedFileRange, ObjectSection, ObjectSegment, Permissions,
+    RawRelocation, ReadError, ReadRef, Relocation, Result, SectionFlags, SectionIndex, SectionKind,
 };
 
 use super::{ImageNtHeaders, PeFile, SectionTable};
@@ -71,6 +73,16 @@ where
             .pe_data(self.file.data)
             .read_error("Invalid PE section offset or size")
     }
+
+    /// Return the line number entries of the section.
+    ///
+    /// These are deprecated in PE images, and are usually not present.
+    ///
+    /// Returns an empty `Vec` if the section has no line numbers.
+    pub fn line_numbers(&self) -> Result<Vec<LineNumber>> {
+        let entries = self.section.coff_line_numbers(self.file.data)?;
+        Ok(parse_line_numbers(entries))
+    }
 }
 
 impl<'data, 'file, Pe, R> read::private::Sealed for PeSegment<'data, 'file, Pe, R>
@@ -128,6 +140,11 @@ where
                 .read_error("Non UTF-8 PE section name")?,
         ))
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        self.section.permissions()
+    }
 }
 
 /// An iterator over the sections of a `PeFile32`.
@@ -195,6 +212,16 @@ where
             .pe_data(self.file.data)
             .read_error("Invalid PE section offset or size")
     }
+
+    /// Return the line number entries of the section.
+    ///
+    /// These are deprecated in PE images, and are usually not present.
+    ///
+    /// Returns an empty `Vec` if the section has no line numbers.
+    pub fn line_numbers(&self) -> Result<Vec<LineNumber>> {
+        let entries = self.section.coff_line_numbers(self.file.data)?;
+        Ok(parse_line_numbers(entries))
+    }
 }
 
 impl<'data, 'file, Pe, R> read::private::Sealed for PeSection<'data, 'file, Pe, R>
@@ -286,11 +313,21 @@ where
         PeRelocationIterator(PhantomData)
     }
 
+    #[inline]
+    fn raw_relocations(&self) -> Result<Vec<RawRelocation>> {
+        Ok(Vec::new())
+    }
+
     fn flags(&self) -> SectionFlags {
         SectionFlags::Coff {
             characteristics: self.section.characteristics.get(LE),
         }
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        self.section.permissions()
+    }
 }
 
 impl<'data> SectionTable<'data> {
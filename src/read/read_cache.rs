@@ -15,6 +15,22 @@ use crate::read::ReadRef;
 /// them to be returned. Entries in the cache are never removed.
 /// Entries are keyed on the offset and size of the read.
 /// Currently overlapping reads are considered separate reads.
+///
+/// This can be used to parse a file without reading all of it into memory,
+/// and without the safety concerns of memory mapping the file, which is
+/// undefined behaviour if the file is modified or truncated while it is mapped.
+///
+/// ```no_run
+/// use object::read::{File, Object, ReadCache};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = std::fs::File::open("path/to/binary")?;
+/// let cache = ReadCache::new(file);
+/// let object = File::parse(&cache)?;
+/// println!("{:?}", object.architecture());
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct ReadCache<R: Read + Seek> {
     cache: RefCell<ReadCacheInternal<R>>,
@@ -0,0 +1,240 @@
+//! Identification of the toolchain that produced a file.
+//!
+//! This combines the producer information that is recorded in the various file
+//! formats. See `Object::toolchain` for the sources that are used.
+
+use alloc::format;
+use alloc::string::String;
+use core::str;
+
+use crate::endian::{Endianness, U32Bytes};
+use crate::pod::Bytes;
+use crate::read::dwarf::read_uleb128;
+use crate::read::{Object, ObjectSection};
+
+/// The toolchain that produced a file.
+///
+/// Returned by `Object::toolchain`.
+///
+/// Tool names are normalized to a lowercase name followed by the version,
+/// such as `gcc 11.2.0`, `clang 14.0.0`, `rustc 1.60.0` or `lld 14.0.0`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Toolchain {
+    compiler: Option<String>,
+    linker: Option<String>,
+    language_hint: Option<String>,
+}
+
+impl Toolchain {
+    /// The compiler that produced the file, if known.
+    #[inline]
+    pub fn compiler(&self) -> Option<&str> {
+        self.compiler.as_deref()
+    }
+
+    /// The linker that produced the file, if known.
+    #[inline]
+    pub fn linker(&self) -> Option<&str> {
+        self.linker.as_deref()
+    }
+
+    /// The source language of the file, if it can be determined from the compiler.
+    ///
+    /// This is only set for compilers that are specific to a language, such as
+    /// `Rust`, `Go` or `Swift`, or if the file records the language explicitly.
+    #[inline]
+    pub fn language_hint(&self) -> Option<&str> {
+        self.language_hint.as_deref()
+    }
+
+    /// Set the compiler if it has a higher priority than the current compiler.
+    ///
+    /// Compilers with a language take precedence over C compilers, and clang takes
+    /// precedence over gcc. Otherwise the first compiler that is found is used.
+    pub(crate) fn add_compiler(&mut self, compiler: String, language: Option<&str>) {
+        fn priority(compiler: &str, language: bool) -> u8 {
+            if language {
+                2
+            } else if compiler.starts_with("clang") {
+                1
+            } else {
+                0
+            }
+        }
+        if let Some(current) = &self.compiler {
+            if priority(current, self.language_hint.is_some())
+                >= priority(&compiler, language.is_some())
+            {
+                return;
+            }
+        }
+        self.compiler = Some(compiler);
+        self.language_hint = language.map(String::from);
+    }
+
+    /// Set the linker if it is not already known.
+    pub(crate) fn add_linker(&mut self, linker: String) {
+        if self.linker.is_none() {
+            self.linker = Some(linker);
+        }
+    }
+}
+
+/// Determine the toolchain from the sections that record producer information.
+pub(crate) fn toolchain<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    object: &'file O,
+) -> Toolchain {
+    let mut toolchain = Toolchain::default();
+    for section in object.sections() {
+        let (name, data) = match (section.name(), section.data()) {
+            (Ok(name), Ok(data)) => (name, data),
+            _ => continue,
+        };
+        match name {
+            ".comment" => parse_comment(&mut toolchain, data),
+            ".note.gnu.gold-version" => {
+                parse_gold_version(&mut toolchain, data, object.endianness());
+            }
+            ".go.buildinfo" => {
+                parse_go_buildinfo(&mut toolchain, data);
+            }
+            "producers" => {
+                parse_wasm_producers(&mut toolchain, data);
+            }
+            _ => {}
+        }
+    }
+    toolchain
+}
+
+/// Return the first word of a version string, omitting any build details.
+fn version(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+/// Parse the null terminated producer strings in an ELF `.comment` section.
+fn parse_comment(toolchain: &mut Toolchain, data: &[u8]) {
+    for s in data.split(|&byte| byte == 0) {
+        let s = match str::from_utf8(s) {
+            Ok(s) => s.trim(),
+            Err(_) => continue,
+        };
+        if let Some(s) = strip_prefix(s, "Linker: ") {
+            // Example: "Linker: LLD 14.0.0"
+            let mut words = s.split_whitespace();
+            if let Some(name) = words.next() {
+                let linker = match words.next() {
+                    Some(version) => format!("{} {}", name, version),
+                    None => String::from(name),
+                };
+                toolchain.add_linker(linker.to_ascii_lowercase());
+            }
+        } else if let Some(s) = strip_prefix(s, "rustc version ") {
+            // Example: "rustc version 1.60.0 (7737e0b5c 2022-04-04)"
+            let version = version(s);
+            toolchain.add_compiler(format!("rustc {}", version), Some("Rust"));
+        } else if let Some(index) = s.find("clang version ") {
+            // Example: "Ubuntu clang version 14.0.0-1ubuntu1"
+            let version = version(&s[index + "clang version ".len()..]);
+            toolchain.add_compiler(format!("clang {}", version), None);
+        } else if s.starts_with("GCC: ") {
+            // Example: "GCC: (GNU) 11.2.0"
+            let version = s.split_whitespace().last().unwrap_or("");
+            toolchain.add_compiler(format!("gcc {}", version), None);
+        }
+    }
+}
+
+/// Parse the ELF note that the gold linker stores in `.note.gnu.gold-version`.
+fn parse_gold_version(toolchain: &mut Toolchain, data: &[u8], endian: Endianness) -> Option<()> {
+    // `elf::NT_GNU_GOLD_VERSION` is only available with the `elf` feature.
+    const NT_GNU_GOLD_VERSION: u32 = 4;
+
+    let mut data = Bytes(data);
+    let namesz = data.read::<U32Bytes<Endianness>>().ok()?.get(endian);
+    let descsz = data.read::<U32Bytes<Endianness>>().ok()?.get(endian);
+    let n_type = data.read::<U32Bytes<Endianness>>().ok()?.get(endian);
+    let name = data.read_bytes((namesz as usize + 3) & !3).ok()?;
+    // The name is "GNU" followed by a null terminator.
+    if n_type != NT_GNU_GOLD_VERSION || namesz != 4 || !name.0.starts_with(b"GNU") {
+        return None;
+    }
+    let desc = data.read_bytes(descsz as usize).ok()?;
+    let desc = desc.0.split(|&byte| byte == 0).next()?;
+    // Example: "gold 1.16"
+    let linker = str::from_utf8(desc).ok()?.trim();
+    if !linker.is_empty() {
+        toolchain.add_linker(String::from(linker));
+    }
+    Some(())
+}
+
+/// Parse the build information that the Go linker stores in `.go.buildinfo`.
+///
+/// The version string is only stored inline since Go 1.18. Earlier versions
+/// store a pointer to it instead, and are ignored.
+fn parse_go_buildinfo(toolchain: &mut Toolchain, data: &[u8]) -> Option<()> {
+    const MAGIC: &[u8] = b"\xff Go buildinf:";
+    const FLAGS_VERSION_INLINE: u8 = 0x2;
+
+    if !data.starts_with(MAGIC) {
+        return None;
+    }
+    let flags = *data.get(MAGIC.len() + 1)?;
+    if flags & FLAGS_VERSION_INLINE == 0 {
+        return None;
+    }
+    let mut data = Bytes(data);
+    data.skip(32).ok()?;
+    // Example: "go1.18.1"
+    let version = read_string(&mut data)?;
+    let version = strip_prefix(version, "go")?;
+    toolchain.add_compiler(format!("go {}", version), Some("Go"));
+    Some(())
+}
+
+/// Parse a Wasm `producers` custom section.
+///
+/// The first `processed-by` entry is used for the compiler, and the first
+/// `language` entry is used for the language hint. Nothing is recorded if there
+/// is no `processed-by` entry.
+fn parse_wasm_producers(toolchain: &mut Toolchain, data: &[u8]) -> Option<()> {
+    let mut data = Bytes(data);
+    let mut compiler = None;
+    let mut language = None;
+    let field_count = read_uleb128(&mut data).ok()?;
+    for _ in 0..field_count {
+        let field = read_string(&mut data)?;
+        let value_count = read_uleb128(&mut data).ok()?;
+        for i in 0..value_count {
+            let name = read_string(&mut data)?;
+            let version = read_string(&mut data)?;
+            if i != 0 {
+                continue;
+            }
+            match field {
+                "language" => language = Some(name),
+                "processed-by" => compiler = Some(format!("{} {}", name, self::version(version))),
+                _ => {}
+            }
+        }
+    }
+    toolchain.add_compiler(compiler?, language);
+    Some(())
+}
+
+// TODO: use `str::strip_prefix` when the MSRV is at least 1.45.
+fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if s.starts_with(prefix) {
+        s.get(prefix.len()..)
+    } else {
+        None
+    }
+}
+
+/// Read a string that is prefixed by its ULEB128 length.
+fn read_string<'data>(data: &mut Bytes<'data>) -> Option<&'data str> {
+    let len = read_uleb128(data).ok()?;
+    let s = data.read_bytes(len as usize).ok()?;
+    str::from_utf8(s.0).ok()
+}
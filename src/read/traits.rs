@@ -1,10 +1,16 @@
 use alloc::borrow::Cow;
+use alloc::string::String;
 use alloc::vec::Vec;
 
+use crate::endian::{U32Bytes, U64Bytes};
+use crate::pod::Bytes;
+use crate::read::ReadError;
 use crate::read::{
-    self, Architecture, CodeView, ComdatKind, CompressedData, CompressedFileRange, Export,
-    FileFlags, Import, ObjectMap, Relocation, Result, SectionFlags, SectionIndex, SectionKind,
-    SymbolFlags, SymbolIndex, SymbolKind, SymbolMap, SymbolMapName, SymbolScope, SymbolSection,
+    self, dwarf, toolchain, validate, Architecture, CodeView, ComdatKind, CompressedData,
+    CompressedFileRange, CompressionFormat, Export, FileFlags, Import, ObjectMap, Permissions,
+    RawRelocation, Relocation, Result, SectionFlags, SectionIndex, SectionKind, SectionSizes,
+    SymbolFlags, SymbolIndex, SymbolKind, SymbolLanguage, SymbolMap, SymbolMapName, SymbolScope,
+    SymbolSection, Toolchain, ValidationIssue,
 };
 use crate::Endianness;
 
@@ -66,12 +72,127 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// Return true if the file can contain 64-bit addresses.
     fn is_64(&self) -> bool;
 
+    /// Return the size in bytes of a pointer in the file's data.
+    ///
+    /// This is 8 for 64-bit files and 4 otherwise. COFF files use the size for the
+    /// architecture, since `is_64` is always false for COFF.
+    #[inline]
+    fn pointer_size(&self) -> u8 {
+        if self.is_64() {
+            8
+        } else {
+            4
+        }
+    }
+
+    /// Read a pointer sized value at the given address.
+    ///
+    /// The size of the value is given by `pointer_size`, and it is read using
+    /// the file's endianness from the data of the section that contains the address.
+    /// Only sections that are readable when loaded are considered, so this never
+    /// reads from sections such as string tables or debug information.
+    ///
+    /// This is intended for linked images, where each address is in at most one
+    /// section. In relocatable files, the sections may all have an address of 0,
+    /// and so the address does not identify a section. The value is also read
+    /// without applying any relocations.
+    ///
+    /// Returns `Ok(None)` if no section contains data for the full value.
+    /// Returns `Err` if more than one section contains data for the address.
+    fn read_pointer(&'file self, address: u64) -> Result<Option<u64>> {
+        let size = self.pointer_size();
+        let endian = self.endianness();
+        let mut result = None;
+        for section in self.sections() {
+            if !section.permissions().read() {
+                continue;
+            }
+            if let Some(data) = section.data_range(address, size.into())? {
+                if result.is_some() {
+                    return Err(read::Error("Pointer address is in multiple sections"));
+                }
+                let mut data = Bytes(data);
+                let value = if size == 8 {
+                    data.read::<U64Bytes<_>>().map(|value| value.get(endian))
+                } else {
+                    data.read::<U32Bytes<_>>()
+                        .map(|value| value.get(endian).into())
+                };
+                result = Some(value.read_error("Invalid pointer data")?);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Return true if the file is a relocatable object file.
+    ///
+    /// This is true for ELF `ET_REL` files, Mach-O `MH_OBJECT` files, COFF object files,
+    /// and Wasm files containing a `linking` custom section. It is false for linked
+    /// images such as executables and shared libraries.
+    fn is_relocatable(&self) -> bool;
+
+    /// Get the conventional page size for the file format and architecture.
+    ///
+    /// This is a heuristic for use when mapping the file. It is 4096 for most targets,
+    /// 16384 for Mach-O on AArch64, and 65536 (the linear memory page size) for Wasm.
+    ///
+    /// For ELF, `max_segment_alignment` may give a larger value, which should be used
+    /// in preference to this.
+    fn default_page_size(&self) -> u64;
+
     /// Get an iterator over the segments in the file.
     fn segments(&'file self) -> Self::SegmentIterator;
 
     /// Get the entry point address of the binary
     fn entry(&'file self) -> u64;
 
+    /// Get the symbol for the entry point of the binary.
+    ///
+    /// This is the first symbol definition with an address equal to the entry point,
+    /// such as `_start`, `main` or `mainCRTStartup`. The debugging symbols are searched
+    /// first, followed by the dynamic symbols.
+    ///
+    /// For Mach-O files, the entry point file offset is converted to an address using
+    /// the segments. For PE files, the image base is added to the entry point.
+    ///
+    /// Returns `None` if the file does not have an entry point, or if there is no
+    /// symbol at the entry point, such as for stripped files.
+    fn entry_symbol(&'file self) -> Option<Self::Symbol> {
+        match self.entry() {
+            0 => None,
+            entry => definition_at_address(self, entry),
+        }
+    }
+
+    /// The address that the file prefers to be loaded at.
+    ///
+    /// This is the lowest address of the segments that contain file data, which is
+    /// the address of the file header when it is loaded. For PE files, this is the
+    /// image base. This is 0 for position independent ELF executables and shared
+    /// objects, and for files without segments.
+    fn preferred_base(&'file self) -> u64 {
+        self.segments()
+            .filter(|segment| segment.file_range().1 != 0)
+            .map(|segment| segment.address())
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Convert an address in a running process to an address in this file.
+    ///
+    /// `load_base` is the address that the file was loaded at in the process, such
+    /// as the start of its first mapping or the module handle of a PE file. The
+    /// difference between `load_base` and `preferred_base` is the load bias, which
+    /// is subtracted from `runtime_address`.
+    ///
+    /// The returned address can be compared with the addresses of symbols and
+    /// sections. For PE files, this includes the image base.
+    fn file_address(&'file self, runtime_address: u64, load_base: u64) -> u64 {
+        runtime_address
+            .wrapping_sub(load_base)
+            .wrapping_add(self.preferred_base())
+    }
+
     /// Get the section named `section_name`, if such a section exists.
     ///
     /// If `section_name` starts with a '.' then it is treated as a system section name,
@@ -100,6 +221,125 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// Get an iterator over the sections in the file.
     fn sections(&'file self) -> Self::SectionIterator;
 
+    /// Get the section whose file range contains the given file offset.
+    ///
+    /// Sections that do not have any data in the file are ignored.
+    ///
+    /// This requires iterating through all sections.
+    fn section_at_file_offset(&'file self, offset: u64) -> Option<Self::Section> {
+        self.sections().find(|section| match section.file_range() {
+            Some((start, size)) => offset.wrapping_sub(start) < size,
+            None => false,
+        })
+    }
+
+    /// Get the section whose address range contains the given address.
+    ///
+    /// Sections that are not readable when loaded, such as debugging sections,
+    /// are ignored. For COFF object files, discardable sections are also ignored.
+    /// Sections that have no data in the file, such as `.bss`, are
+    /// included.
+    ///
+    /// If more than one section contains the address, then the first is returned.
+    /// This requires iterating through all sections.
+    fn section_by_address(&'file self, address: u64) -> Option<Self::Section> {
+        self.sections().find(|section| {
+            section.permissions().read() && address.wrapping_sub(section.address()) < section.size()
+        })
+    }
+
+    /// Get the address ranges that are both writable and executable.
+    ///
+    /// Each item is the address and size of a region.
+    ///
+    /// This uses the segment permissions. If the file has no segments (such as for
+    /// ELF relocatable files), then the section flags are used instead.
+    fn wx_regions(&'file self) -> Vec<(u64, u64)> {
+        let mut regions = Vec::new();
+        let mut has_segments = false;
+        for segment in self.segments() {
+            has_segments = true;
+            let permissions = segment.permissions();
+            if permissions.write() && permissions.execute() {
+                regions.push((segment.address(), segment.size()));
+            }
+        }
+        if !has_segments {
+            for section in self.sections() {
+                if section_flags_wx(section.flags()) {
+                    regions.push((section.address(), section.size()));
+                }
+            }
+        }
+        regions
+    }
+
+    /// Return true if the file contains any regions that are both writable and executable.
+    ///
+    /// See `wx_regions` for details.
+    fn has_wx_regions(&'file self) -> bool {
+        !self.wx_regions().is_empty()
+    }
+
+    /// Get the total size of the virtual memory of the segments.
+    ///
+    /// This is the sum of the segment sizes (`p_memsz` for ELF), and so includes
+    /// any zero-initialized data that is not in the file. It does not include gaps
+    /// between segments. Use `memory_span` for the size of the address range that
+    /// must be reserved to load the file.
+    ///
+    /// Returns 0 if the file has no segments.
+    fn memory_size(&'file self) -> u64 {
+        self.segments()
+            .map(|segment| segment.size())
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Get the size of the address range that is needed to load the file.
+    ///
+    /// This is the span from the lowest segment address to the highest segment end
+    /// address, and so includes any zero-initialized data that is not in the file,
+    /// as well as any gaps between segments.
+    ///
+    /// Returns 0 if the file has no segments.
+    fn memory_span(&'file self) -> u64 {
+        let mut range: Option<(u64, u64)> = None;
+        for segment in self.segments() {
+            if segment.size() == 0 {
+                continue;
+            }
+            let start = segment.address();
+            let end = start.saturating_add(segment.size());
+            range = Some(match range {
+                Some((min, max)) => (min.min(start), max.max(end)),
+                None => (start, end),
+            });
+        }
+        range.map(|(start, end)| end - start).unwrap_or(0)
+    }
+
+    /// Get the total size of the file data that is loaded by the segments.
+    ///
+    /// This is the sum of the sizes of the segment file ranges.
+    fn file_size_loaded(&'file self) -> u64 {
+        self.segments()
+            .map(|segment| segment.file_range().1)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Get the maximum alignment of the loadable segments.
+    ///
+    /// This is the alignment that is needed to map the file. For ELF, this is the
+    /// largest `p_align` of the `PT_LOAD` segments, which may be larger than the page
+    /// size (such as for huge pages). For PE, this is the section alignment. Mach-O
+    /// does not record segment alignment, so this is always the page size.
+    ///
+    /// Returns `None` if the file has no segments, such as for ELF relocatable
+    /// files. In this case, `default_page_size` can be used as the alignment instead.
+    fn max_segment_alignment(&'file self) -> Option<u64> {
+        self.segments().map(|segment| segment.align()).max()
+    }
+
     /// Get an iterator over the COMDAT section groups in the file.
     fn comdats(&'file self) -> Self::ComdatIterator;
 
@@ -120,6 +360,140 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// For Mach-O files, this does not include STAB entries.
     fn symbols(&'file self) -> Self::SymbolIterator;
 
+    /// Return all of the debugging symbols in the file, sorted by address.
+    ///
+    /// Unlike `symbol_map`, no symbols are filtered out, so this includes undefined
+    /// symbols and symbols that are not text or data. The sort is stable, so symbols
+    /// with the same address are in the same order as `symbols`, which is the
+    /// order of their indices.
+    fn symbols_by_address(&'file self) -> Vec<Self::Symbol> {
+        let mut symbols: Vec<_> = self.symbols().collect();
+        symbols.sort_by_key(|symbol| symbol.address());
+        symbols
+    }
+
+    /// Get the data for the given symbol.
+    ///
+    /// This is the `size` bytes at the symbol's address in the symbol's section.
+    ///
+    /// Returns `Ok(None)` if the symbol has a size of zero, if it is not defined in
+    /// a section, or if its section has no data in the file (such as for
+    /// zero-initialized data). Note that COFF and Mach-O do not record symbol sizes,
+    /// so this always returns `Ok(None)` for those formats.
+    fn symbol_data(&'file self, symbol: &Self::Symbol) -> Result<Option<&'data [u8]>> {
+        if symbol.size() == 0 {
+            return Ok(None);
+        }
+        let index = match symbol.section_index() {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let section = self.section_by_index(index)?;
+        section.data_range(symbol.address(), symbol.size())
+    }
+
+    /// Return the address ranges in a section that are not covered by any symbol.
+    ///
+    /// Each range is returned as an `(address, size)` pair, in order of address.
+    ///
+    /// Symbols with a size of zero are assumed to extend to the next symbol with a
+    /// greater address, or to the end of the section. This is always the case for
+    /// COFF and Mach-O, which do not record symbol sizes.
+    /// Overlapping symbols are merged before the gaps are computed.
+    fn unsymbolized_ranges(&'file self, index: SectionIndex) -> Result<Vec<(u64, u64)>> {
+        let section = self.section_by_index(index)?;
+        let start = section.address();
+        let end = start.saturating_add(section.size());
+
+        let mut symbols: Vec<(u64, u64)> = self
+            .symbols()
+            .filter(|symbol| {
+                symbol.section_index() == Some(index)
+                    && symbol.kind() != SymbolKind::Section
+                    && symbol.kind() != SymbolKind::File
+            })
+            .map(|symbol| (symbol.address(), symbol.size()))
+            .collect();
+        symbols.sort_unstable();
+
+        let mut ranges = Vec::new();
+        let mut covered = start;
+        for (i, &(address, size)) in symbols.iter().enumerate() {
+            let symbol_end = if size != 0 {
+                address.saturating_add(size)
+            } else {
+                symbols[i + 1..]
+                    .iter()
+                    .map(|&(next, _)| next)
+                    .find(|&next| next > address)
+                    .unwrap_or(end)
+            };
+            if address > covered {
+                if address >= end {
+                    break;
+                }
+                ranges.push((covered, address - covered));
+            }
+            covered = covered.max(symbol_end);
+            if covered >= end {
+                break;
+            }
+        }
+        if covered < end {
+            ranges.push((covered, end - covered));
+        }
+        Ok(ranges)
+    }
+
+    /// Get the thread-local storage symbols, paired with their offsets within the
+    /// TLS block.
+    ///
+    /// Only symbols with kind `SymbolKind::Tls` that are defined in a section are
+    /// returned.
+    ///
+    /// For ELF, the offset is the symbol value. In executables and shared objects,
+    /// this is the offset within the TLS template given by the `PT_TLS` segment.
+    /// In relocatable files, it is the offset within the symbol's section.
+    ///
+    /// For Mach-O, the offset is relative to the address of the first TLS section.
+    /// Only symbols for the initial values in the TLS sections (such as
+    /// `_foo$tlv$init`) are returned. The thread variable descriptors in the
+    /// `__thread_vars` section are not returned.
+    fn tls_symbols(&'file self) -> Vec<(Self::Symbol, u64)> {
+        let is_tls_section = |index| match self.section_by_index(index) {
+            Ok(section) => matches!(
+                section.kind(),
+                SectionKind::Tls | SectionKind::UninitializedTls
+            ),
+            Err(_) => false,
+        };
+        let base = if let FileFlags::MachO { .. } = self.flags() {
+            match self
+                .sections()
+                .filter(|section| is_tls_section(section.index()))
+                .map(|section| section.address())
+                .min()
+            {
+                Some(base) => Some(base),
+                None => return Vec::new(),
+            }
+        } else {
+            None
+        };
+        self.symbols()
+            .filter(|symbol| symbol.kind() == SymbolKind::Tls)
+            .filter_map(|symbol| {
+                let index = symbol.section_index()?;
+                let offset = match base {
+                    Some(base) if is_tls_section(index) => symbol.address().wrapping_sub(base),
+                    Some(_) => return None,
+                    None => symbol.address(),
+                };
+                Some((symbol, offset))
+            })
+            .collect()
+    }
+
     /// Get the dynamic linking symbol table, if any.
     ///
     /// Only ELF has a separate dynamic linking symbol table.
@@ -140,23 +514,73 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     /// Only ELF has dynamic relocations.
     fn dynamic_relocations(&'file self) -> Option<Self::DynamicRelocationIterator>;
 
+    /// Return true if the file defines or references a symbol with the given name.
+    ///
+    /// This stops searching as soon as a match is found, and does not allocate.
+    /// Exported symbols are found without a linear search if the file has a
+    /// suitable lookup table: the ELF `.gnu.hash` section, the Mach-O exports trie,
+    /// or the PE export name table. Otherwise, this searches the symbol table and
+    /// the dynamic symbol table, and for PE files, the imports.
+    ///
+    /// The name must match the symbol name exactly, including any leading underscore
+    /// that is added for Mach-O symbols.
+    fn contains_symbol(&'file self, name: &str) -> bool {
+        symbol_tables_contain(self, name)
+    }
+
+    /// Find a symbol definition with the given name.
+    ///
+    /// The debugging symbols are searched first, followed by the dynamic symbols.
+    /// Undefined symbols are ignored. If there is a global symbol with the name,
+    /// then the first global symbol is returned. Otherwise, the first local symbol
+    /// with the name is returned.
+    ///
+    /// The name must match the symbol name exactly, including any leading underscore
+    /// that is added for Mach-O symbols.
+    ///
+    /// This requires iterating through all symbols.
+    fn symbol_by_name(&'file self, name: &str) -> Option<Self::Symbol> {
+        let mut local = None;
+        for symbol in self.symbols().chain(self.dynamic_symbols()) {
+            if symbol.is_undefined() || symbol.name() != Ok(name) {
+                continue;
+            }
+            if symbol.is_global() {
+                return Some(symbol);
+            }
+            if local.is_none() {
+                local = Some(symbol);
+            }
+        }
+        local
+    }
+
     /// Construct a map from addresses to symbol names.
     ///
     /// The map will only contain defined text and data symbols.
     /// The dynamic symbol table will only be used if there are no debugging symbols.
+    ///
+    /// Use `symbol_map_with_filter` to select a different set of symbols.
+    ///
+    /// The map is constructed and sorted on each call. It only borrows the file
+    /// data, not the file, so callers that perform many lookups should construct
+    /// the map once and keep it.
     fn symbol_map(&'file self) -> SymbolMap<SymbolMapName<'data>> {
-        let mut symbols = Vec::new();
-        if let Some(table) = self.symbol_table().or_else(|| self.dynamic_symbol_table()) {
-            for symbol in table.symbols() {
-                if !symbol.is_definition() {
-                    continue;
-                }
-                if let Ok(name) = symbol.name() {
-                    symbols.push(SymbolMapName::new(symbol.address(), name));
-                }
-            }
+        self.symbol_map_with_filter(|symbol| symbol.is_definition())
+    }
+
+    /// Construct a map from addresses to symbol names, including only the symbols
+    /// for which `filter` returns true.
+    ///
+    /// The dynamic symbol table will only be used if there are no debugging symbols.
+    fn symbol_map_with_filter<F>(&'file self, filter: F) -> SymbolMap<SymbolMapName<'data>>
+    where
+        F: Fn(&Self::Symbol) -> bool,
+    {
+        match self.symbol_table().or_else(|| self.dynamic_symbol_table()) {
+            Some(table) => SymbolMap::with_filter(table.symbols(), filter),
+            None => SymbolMap::new(Vec::new()),
         }
-        SymbolMap::new(symbols)
     }
 
     /// Construct a map from addresses to symbol names and object file names.
@@ -170,6 +594,9 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
     fn imports(&self) -> Result<Vec<Import<'data>>>;
 
     /// Get the exported symbols.
+    ///
+    /// For PE files, this includes forwarded exports. These have an address of 0,
+    /// and their target is given by `Export::forward` and `Export::forward_target`.
     fn exports(&self) -> Result<Vec<Export<'data>>>;
 
     /// Return true if the file contains debug information sections, false if not.
@@ -205,10 +632,275 @@ pub trait Object<'data: 'file, 'file>: read::private::Sealed {
         Ok(None)
     }
 
+    /// Get the name of the split DWARF object referenced by the skeleton unit.
+    ///
+    /// This is the value of the `DW_AT_dwo_name` or `DW_AT_GNU_dwo_name` attribute
+    /// of the first unit in the `.debug_info` section.
+    ///
+    /// Returns `Ok(None)` if there is no `.debug_info` section, or the first unit
+    /// does not have this attribute. Returns an error if the DWARF data is invalid,
+    /// the sections are compressed, or the first DIE uses an attribute form that is not
+    /// normally used by skeleton units. Use the `gimli` crate for full DWARF parsing.
+    fn dwo_name(&'file self) -> Result<Option<&'data [u8]>> {
+        let sections = dwarf::DwarfSections {
+            debug_info: uncompressed_section_data(self.section_by_name(".debug_info"))?,
+            debug_abbrev: uncompressed_section_data(self.section_by_name(".debug_abbrev"))?,
+            debug_str: uncompressed_section_data(self.section_by_name(".debug_str"))?,
+            debug_str_offsets: uncompressed_section_data(
+                self.section_by_name(".debug_str_offsets"),
+            )?,
+        };
+        dwarf::dwo_name(self.endianness(), &sections)
+    }
+
+    /// Return the version of the first compilation unit in the `.debug_info` section.
+    ///
+    /// This only reads the unit header, and does not validate the rest of the DWARF data.
+    ///
+    /// Returns `None` if there is no `.debug_info` section, if the unit header is
+    /// invalid, or if the section is compressed and decompression is not supported.
+    fn dwarf_version(&'file self) -> Option<u16> {
+        let section = self.section_by_name(".debug_info")?;
+        let data = section.uncompressed_data().ok()?;
+        dwarf::unit_version(self.endianness(), &data)
+    }
+
+    /// Return true if the file uses split DWARF.
+    ///
+    /// This is true for DWARF package files (which have a `.debug_cu_index` or
+    /// `.debug_tu_index` section), for split DWARF objects (which have sections with
+    /// a `.dwo` suffix), and for files with a skeleton unit that references a split
+    /// DWARF object.
+    fn has_split_dwarf(&'file self) -> bool {
+        self.section_by_name(".debug_cu_index").is_some()
+            || self.section_by_name(".debug_tu_index").is_some()
+            || self
+                .sections()
+                .any(|section| matches!(section.name(), Ok(name) if name.ends_with(".dwo")))
+            || self.dwo_name().ok().flatten().is_some()
+    }
+
+    /// Return the addresses of the static constructors that are run when the file is loaded.
+    ///
+    /// These are read from the function pointer arrays in the ELF `.preinit_array` and
+    /// `.init_array` sections, the Mach-O `__mod_init_func` sections, and the COFF
+    /// `.CRT$XC*` sections. The addresses are returned in the order that they are
+    /// stored in the file, and null pointers are skipped.
+    ///
+    /// These are the raw values in the section data, and relocations are not applied.
+    /// This means that some pointers are missing:
+    /// - In relocatable files, the pointers are usually 0, and relocations fill them in.
+    /// - In ELF position independent executables and shared objects, the pointers are
+    ///   filled in by dynamic relocations. For architectures that use `SHT_RELA`
+    ///   relocations, such as x86-64 and AArch64, the raw values are 0. For `SHT_REL`
+    ///   relocations, the raw values are the addresses before relocation.
+    /// - PE linkers merge the `.CRT$XC*` sections into the `.CRT` section, and the
+    ///   bounds of the array in that section are only given by symbols in the CRT.
+    ///   So this always returns an empty `Vec` for PE images. Only COFF object files
+    ///   are supported.
+    fn initializers(&'file self) -> Result<Vec<u64>> {
+        function_pointers(self, |name| {
+            name == ".preinit_array"
+                || name == ".init_array"
+                || name.starts_with(".init_array.")
+                || name == "__mod_init_func"
+                || name.starts_with(".CRT$XC")
+        })
+    }
+
+    /// Return the addresses of the static destructors that are run when the file is unloaded.
+    ///
+    /// These are read from the function pointer arrays in the ELF `.fini_array` sections,
+    /// the Mach-O `__mod_term_func` sections, and the COFF `.CRT$XT*` sections.
+    ///
+    /// See `initializers` for the limitations of this method.
+    fn finalizers(&'file self) -> Result<Vec<u64>> {
+        function_pointers(self, |name| {
+            name == ".fini_array"
+                || name.starts_with(".fini_array.")
+                || name == "__mod_term_func"
+                || name.starts_with(".CRT$XT")
+        })
+    }
+
+    /// Return the name and a hash of the data for each section, in section index order.
+    ///
+    /// This is intended for comparing two builds to find which sections differ.
+    /// The hash is the 64-bit FNV-1a hash of the section's file data, so it is
+    /// stable across runs and platforms, but it is not a cryptographic hash.
+    ///
+    /// Sections without file data, such as `.bss`, have the hash of an empty slice.
+    fn section_hashes(&'file self) -> Result<Vec<(String, u64)>> {
+        let mut hashes = Vec::new();
+        for section in self.sections() {
+            let name = section.name()?;
+            let data = section.data()?;
+            hashes.push((name.into(), fnv1a_64(data)));
+        }
+        Ok(hashes)
+    }
+
+    /// Return the compiler, linker and language that produced the file.
+    ///
+    /// The information is combined from the following sources:
+    /// - the `GCC:`, `clang version`, `rustc version` and `Linker:` strings in the
+    ///   ELF `.comment` section
+    /// - the `NT_GNU_GOLD_VERSION` note in the ELF `.note.gnu.gold-version` section
+    /// - the Go version in the ELF `.go.buildinfo` section, for Go 1.18 and later
+    /// - the Wasm `producers` custom section
+    /// - the tools in the Mach-O `LC_BUILD_VERSION` load command
+    /// - for PE files that contain a Rich header, the linker version in the optional
+    ///   header
+    ///
+    /// When more than one compiler is found, compilers for a specific language
+    /// (such as rustc, go or swift) take precedence over C compilers, and clang takes
+    /// precedence over gcc. This is because programs often contain C runtime objects
+    /// that were compiled with a different compiler. Otherwise, the first compiler
+    /// or linker that is found is used.
+    ///
+    /// Errors while reading these sources are ignored.
+    fn toolchain(&'file self) -> Toolchain {
+        toolchain::toolchain(self)
+    }
+
+    /// Check the cross-references within the file for consistency.
+    ///
+    /// This can find files that are parsed successfully but are subtly corrupt.
+    /// The following checks are performed:
+    /// - the ELF section header string table index is a valid section index
+    /// - the section indices of symbols are valid
+    /// - the symbol and section indices of relocations are valid
+    /// - the file ranges of segments are within the file
+    /// - the entry point is within an executable segment, or an executable
+    ///   section if the file has no segments
+    ///
+    /// Returns an empty `Vec` if no issues are found.
+    fn validate(&'file self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let entry = match self.entry() {
+            0 => None,
+            entry => Some(entry),
+        };
+        validate::validate(self, entry, &mut issues);
+        issues
+    }
+
+    /// The time that the file was linked, as a Unix timestamp.
+    ///
+    /// This is the `TimeDateStamp` field in the file header for COFF and PE files.
+    /// Returns `None` for other file formats, or if the timestamp is zero.
+    ///
+    /// Note that for reproducible builds the linker may set this to a hash of the
+    /// file contents instead of a time. For PE files, this is indicated by an
+    /// `IMAGE_DEBUG_TYPE_REPRO` debug directory entry, which can be checked with
+    /// `PeFile::is_reproducible`.
+    #[inline]
+    fn timestamp(&self) -> Option<u64> {
+        None
+    }
+
     /// File flags that are specific to each file format.
     fn flags(&self) -> FileFlags;
 }
 
+/// Return the data for an optional section.
+///
+/// Returns an empty slice if the section is missing, and an error if it is compressed.
+fn uncompressed_section_data<'data, S: ObjectSection<'data>>(
+    section: Option<S>,
+) -> Result<&'data [u8]> {
+    let section = match section {
+        Some(section) => section,
+        None => return Ok(&[]),
+    };
+    let data = section.compressed_data()?;
+    if data.format != CompressionFormat::None {
+        return Err(read::Error("Unsupported compressed DWARF section"));
+    }
+    Ok(data.data)
+}
+
+/// Return the first symbol definition with the given address.
+///
+/// Searches the debugging symbols first, followed by the dynamic symbols.
+pub(crate) fn definition_at_address<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    object: &'file O,
+    address: u64,
+) -> Option<O::Symbol> {
+    let is_match = |symbol: &O::Symbol| symbol.is_definition() && symbol.address() == address;
+    object
+        .symbols()
+        .find(is_match)
+        .or_else(|| object.dynamic_symbols().find(is_match))
+}
+
+/// Return true if the symbol table or dynamic symbol table contains a symbol with the given name.
+pub(crate) fn symbol_tables_contain<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    object: &'file O,
+    name: &str,
+) -> bool {
+    object
+        .symbols()
+        .chain(object.dynamic_symbols())
+        .any(|symbol| matches!(symbol.name(), Ok(symbol_name) if symbol_name == name))
+}
+
+/// Return the non-null function pointers in the sections with matching names.
+fn function_pointers<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    object: &'file O,
+    is_array: fn(&str) -> bool,
+) -> Result<Vec<u64>> {
+    let endian = object.endianness();
+    let mut pointers = Vec::new();
+    for section in object.sections() {
+        if !matches!(section.name(), Ok(name) if is_array(name)) {
+            continue;
+        }
+        let mut data = Bytes(section.data()?);
+        while !data.is_empty() {
+            let pointer = if object.pointer_size() == 8 {
+                data.read::<U64Bytes<Endianness>>()
+                    .map(|pointer| pointer.get(endian))
+            } else {
+                data.read::<U32Bytes<Endianness>>()
+                    .map(|pointer| pointer.get(endian).into())
+            }
+            .read_error("Invalid function pointer array size")?;
+            if pointer != 0 {
+                pointers.push(pointer);
+            }
+        }
+    }
+    Ok(pointers)
+}
+
+/// Return the 64-bit FNV-1a hash of the data.
+fn fnv1a_64(data: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in data {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Return true if the section flags specify that the section is writable and executable.
+fn section_flags_wx(flags: SectionFlags) -> bool {
+    match flags {
+        #[cfg(feature = "elf")]
+        SectionFlags::Elf { sh_flags } => {
+            let wx = u64::from(crate::elf::SHF_WRITE | crate::elf::SHF_EXECINSTR);
+            sh_flags & wx == wx
+        }
+        #[cfg(feature = "coff")]
+        SectionFlags::Coff { characteristics } => {
+            let wx = crate::pe::IMAGE_SCN_MEM_WRITE | crate::pe::IMAGE_SCN_MEM_EXECUTE;
+            characteristics & wx == wx
+        }
+        _ => false,
+    }
+}
+
 /// A loadable segment defined in an object file.
 ///
 /// For ELF, this is a program header with type `PT_LOAD`.
@@ -224,6 +916,10 @@ pub trait ObjectSegment<'data>: read::private::Sealed {
     fn align(&self) -> u64;
 
     /// Returns the offset and size of the segment in the file.
+    ///
+    /// The file size may be smaller than `ObjectSegment::size`, such as for segments
+    /// that contain zero-initialized data. The remaining memory is filled with zeros
+    /// when the segment is loaded.
     fn file_range(&self) -> (u64, u64);
 
     /// Returns a reference to the file contents of the segment.
@@ -239,6 +935,11 @@ pub trait ObjectSegment<'data>: read::private::Sealed {
 
     /// Returns the name of the segment.
     fn name(&self) -> Result<Option<&str>>;
+
+    /// Returns the memory access permissions of the segment.
+    ///
+    /// For Mach-O, this is the initial protection of the segment.
+    fn permissions(&self) -> Permissions;
 }
 
 /// A section defined in an object file.
@@ -259,9 +960,16 @@ pub trait ObjectSection<'data>: read::private::Sealed {
     fn size(&self) -> u64;
 
     /// Returns the alignment of the section in memory.
+    ///
+    /// This is the alignment in bytes for all file formats. For Mach-O, the section
+    /// header stores the log2 of the alignment, and this returns `1 << align`.
+    /// Returns 0 if the Mach-O alignment is too large to be represented.
     fn align(&self) -> u64;
 
-    /// Returns offset and size of on-disk segment (if any).
+    /// Returns the offset and size of the section in the file.
+    ///
+    /// Returns `None` if the section has no data in the file, such as for
+    /// zero-initialized sections like `.bss`.
     fn file_range(&self) -> Option<(u64, u64)>;
 
     /// Returns the raw contents of the section.
@@ -287,6 +995,19 @@ pub trait ObjectSection<'data>: read::private::Sealed {
     /// along with information about the compression.
     fn compressed_data(&self) -> Result<CompressedData<'data>>;
 
+    /// Returns the file size, memory size, and uncompressed size of the section.
+    ///
+    /// The uncompressed size is read from the compression header, if any.
+    /// This does not do any decompression.
+    fn sizes(&self) -> Result<SectionSizes> {
+        let range = self.compressed_file_range()?;
+        Ok(SectionSizes {
+            file_size: self.file_range().map_or(0, |(_, size)| size),
+            memory_size: self.size(),
+            uncompressed_size: range.uncompressed_size,
+        })
+    }
+
     /// Returns the uncompressed contents of the section.
     ///
     /// The length of this data may be different from the size of the
@@ -298,6 +1019,23 @@ pub trait ObjectSection<'data>: read::private::Sealed {
         self.compressed_data()?.decompress()
     }
 
+    /// Return true if the contents of the section are entirely zero.
+    ///
+    /// This is true without reading any data for sections that have no file data,
+    /// such as ELF `SHT_NOBITS` sections and Mach-O zerofill sections. Otherwise,
+    /// the uncompressed data is checked, stopping at the first non-zero byte.
+    ///
+    /// Returns false if the data cannot be read or decompressed.
+    fn is_zero_filled(&self) -> bool {
+        if self.file_range().is_none() {
+            return true;
+        }
+        match self.uncompressed_data() {
+            Ok(data) => data.iter().all(|&byte| byte == 0),
+            Err(_) => false,
+        }
+    }
+
     /// Returns the name of the section.
     fn name(&self) -> Result<&str>;
 
@@ -310,8 +1048,40 @@ pub trait ObjectSection<'data>: read::private::Sealed {
     /// Get the relocations for this section.
     fn relocations(&self) -> Self::RelocationIterator;
 
+    /// Get the relocations for this section as they are stored in the file.
+    ///
+    /// Unlike `relocations`, this does not normalize the relocation kind or target,
+    /// and returns an error for invalid relocation data.
+    ///
+    /// For ELF, this includes the entries of all relocation sections that apply to
+    /// this section, in the order of the relocation sections.
+    ///
+    /// PE and Wasm files have no section relocations.
+    fn raw_relocations(&self) -> Result<Vec<RawRelocation>>;
+
     /// Section flags that are specific to each file format.
+    ///
+    /// For properties that are common to all file formats, use `ObjectSection::permissions`
+    /// to determine whether the section is loaded, writable or executable, and
+    /// `ObjectSection::kind` to determine whether it contains TLS data or strings.
     fn flags(&self) -> SectionFlags;
+
+    /// Returns the memory access permissions of the section.
+    ///
+    /// This is useful for relocatable object files, which have no segments.
+    ///
+    /// For ELF, sections without `SHF_ALLOC`, such as debugging information, have
+    /// no permissions. Otherwise, this is derived from `SHF_WRITE` and `SHF_EXECINSTR`.
+    ///
+    /// For Mach-O, debugging sections have no permissions. Otherwise, this is the
+    /// initial protection of the segment containing the section. Relocatable files
+    /// have no such segment, so this is derived from the section kind and attributes.
+    ///
+    /// For COFF and PE, this is derived from the `IMAGE_SCN_MEM_*` characteristics,
+    /// except that sections containing linker information have no permissions.
+    ///
+    /// Wasm sections are not loaded into memory, and have no permissions.
+    fn permissions(&self) -> Permissions;
 }
 
 /// A COMDAT section group defined in an object file.
@@ -408,6 +1178,55 @@ pub trait ObjectSymbol<'data>: read::private::Sealed {
 
     /// Symbol flags that are specific to each file format.
     fn flags(&self) -> SymbolFlags<SectionIndex>;
+
+    /// Return the source language of the symbol, as determined from its mangled name.
+    ///
+    /// Returns `SymbolLanguage::Unknown` if the name is not mangled, or if the name
+    /// is invalid.
+    fn language(&self) -> SymbolLanguage {
+        match self.name() {
+            Ok(name) => SymbolLanguage::from_name(name.as_bytes()),
+            Err(_) => SymbolLanguage::Unknown,
+        }
+    }
+
+    /// Return the demangled name of the symbol.
+    ///
+    /// Rust legacy and v0 mangled names are demangled without the hash suffix.
+    /// C++ names are demangled if they use the Itanium mangling scheme.
+    ///
+    /// Returns `None` if the name is not mangled using one of these schemes, or if
+    /// the name is invalid.
+    #[cfg(feature = "demangle")]
+    fn demangle(&self) -> Option<Cow<'data, str>> {
+        let name = self.name().ok()?;
+        match self.language() {
+            SymbolLanguage::Rust => {
+                let demangled = rustc_demangle::try_demangle(name).ok()?;
+                Some(Cow::Owned(format!("{:#}", demangled)))
+            }
+            SymbolLanguage::Cpp => {
+                let symbol = cpp_demangle::Symbol::new(name).ok()?;
+                let options = cpp_demangle::DemangleOptions::default();
+                symbol.demangle(&options).ok().map(Cow::Owned)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return true if the symbol is for ARM Thumb code.
+    ///
+    /// For ELF files, this is determined from the low bit of the value of function
+    /// symbols, or else from the preceding `$t` or `$a` mapping symbol. The low bit is
+    /// not included in `ObjectSymbol::address`. The mapping symbols are collected
+    /// when the file is parsed, so this does not search the symbol table. For Mach-O
+    /// files, this is determined from the `N_ARM_THUMB_DEF` flag.
+    ///
+    /// Always returns false for other architectures and file formats.
+    #[inline]
+    fn is_thumb(&self) -> bool {
+        false
+    }
 }
 
 /// An iterator for files that don't have dynamic relocations.
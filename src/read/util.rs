@@ -37,4 +37,10 @@ impl<'data> StringTable<'data> {
     pub fn get(&self, offset: u32) -> Result<&'data [u8], ()> {
         self.data.read_string_at(offset as usize)
     }
+
+    /// Return the raw data of the string table.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data.0
+    }
 }
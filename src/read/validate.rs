@@ -0,0 +1,166 @@
+//! Consistency checks for the cross-references within a file.
+//!
+//! See `Object::validate` for the checks that are performed.
+
+use alloc::vec::Vec;
+
+use crate::read::{
+    Object, ObjectSection, ObjectSegment, ObjectSymbol, ObjectSymbolTable, RelocationTarget,
+    SectionIndex, SectionKind, SymbolIndex, SymbolSection,
+};
+
+/// An inconsistency that was found in a file.
+///
+/// Returned by `Object::validate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationIssue {
+    /// The section header string table index is not a valid section index.
+    SectionStringTableIndex(u32),
+    /// A symbol in the symbol table refers to a section that does not exist.
+    SymbolSectionIndex {
+        /// The index of the symbol.
+        symbol: SymbolIndex,
+        /// The invalid section index.
+        section: SectionIndex,
+    },
+    /// A symbol in the dynamic symbol table refers to a section that does not exist.
+    DynamicSymbolSectionIndex {
+        /// The index of the symbol in the dynamic symbol table.
+        symbol: SymbolIndex,
+        /// The invalid section index.
+        section: SectionIndex,
+    },
+    /// A relocation refers to a symbol that does not exist.
+    RelocationSymbolIndex {
+        /// The section that the relocation applies to.
+        section: SectionIndex,
+        /// The offset of the relocation within the section.
+        offset: u64,
+        /// The invalid symbol index.
+        symbol: SymbolIndex,
+    },
+    /// A relocation refers to a section that does not exist.
+    RelocationSectionIndex {
+        /// The section that the relocation applies to.
+        section: SectionIndex,
+        /// The offset of the relocation within the section.
+        offset: u64,
+        /// The invalid section index.
+        target: SectionIndex,
+    },
+    /// The file range of a segment is not within the file.
+    SegmentFileRange {
+        /// The index of the segment in the segment iterator.
+        segment: usize,
+        /// The file offset of the segment.
+        offset: u64,
+        /// The file size of the segment.
+        size: u64,
+    },
+    /// The entry point is not within an executable segment or section.
+    ///
+    /// The value is the entry point as returned by `Object::entry`.
+    EntryPoint(u64),
+}
+
+/// Perform the checks that are common to all file formats.
+///
+/// `entry` is the address of the entry point, or `None` if there is no entry point.
+pub(crate) fn validate<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    object: &'file O,
+    entry: Option<u64>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for symbol in object.symbols() {
+        if let SymbolSection::Section(section) = symbol.section() {
+            if object.section_by_index(section).is_err() {
+                issues.push(ValidationIssue::SymbolSectionIndex {
+                    symbol: symbol.index(),
+                    section,
+                });
+            }
+        }
+    }
+    for symbol in object.dynamic_symbols() {
+        if let SymbolSection::Section(section) = symbol.section() {
+            if object.section_by_index(section).is_err() {
+                issues.push(ValidationIssue::DynamicSymbolSectionIndex {
+                    symbol: symbol.index(),
+                    section,
+                });
+            }
+        }
+    }
+
+    for section in object.sections() {
+        for (offset, relocation) in section.relocations() {
+            match relocation.target() {
+                RelocationTarget::Symbol(symbol) if object.symbol_by_index(symbol).is_err() => {
+                    issues.push(ValidationIssue::RelocationSymbolIndex {
+                        section: section.index(),
+                        offset,
+                        symbol,
+                    });
+                }
+                RelocationTarget::DynamicSymbol(symbol)
+                    if object
+                        .dynamic_symbol_table()
+                        .and_then(|table| table.symbol_by_index(symbol).ok())
+                        .is_none() =>
+                {
+                    issues.push(ValidationIssue::RelocationSymbolIndex {
+                        section: section.index(),
+                        offset,
+                        symbol,
+                    });
+                }
+                RelocationTarget::Section(target) if object.section_by_index(target).is_err() => {
+                    issues.push(ValidationIssue::RelocationSectionIndex {
+                        section: section.index(),
+                        offset,
+                        target,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (index, segment) in object.segments().enumerate() {
+        if segment.data().is_err() {
+            let (offset, size) = segment.file_range();
+            issues.push(ValidationIssue::SegmentFileRange {
+                segment: index,
+                offset,
+                size,
+            });
+        }
+    }
+
+    if let Some(address) = entry {
+        if !is_executable(object, address) {
+            issues.push(ValidationIssue::EntryPoint(object.entry()));
+        }
+    }
+}
+
+/// Return true if the address is within an executable segment.
+///
+/// If the file has no segments, then the sections are checked instead.
+fn is_executable<'data: 'file, 'file, O: Object<'data, 'file> + ?Sized>(
+    object: &'file O,
+    address: u64,
+) -> bool {
+    let contains = |start: u64, size: u64| address.wrapping_sub(start) < size;
+    let mut segments = object.segments().peekable();
+    if segments.peek().is_some() {
+        segments.any(|segment| {
+            segment.permissions().execute() && contains(segment.address(), segment.size())
+        })
+    } else {
+        object.sections().any(|section| {
+            section.kind() == SectionKind::Text && contains(section.address(), section.size())
+        })
+    }
+}
@@ -12,8 +12,9 @@ use wasmparser as wp;
 use crate::read::{
     self, Architecture, ComdatKind, CompressedData, CompressedFileRange, Error, Export, FileFlags,
     Import, NoDynamicRelocationIterator, Object, ObjectComdat, ObjectSection, ObjectSegment,
-    ObjectSymbol, ObjectSymbolTable, ReadError, ReadRef, Relocation, Result, SectionFlags,
-    SectionIndex, SectionKind, SymbolFlags, SymbolIndex, SymbolKind, SymbolScope, SymbolSection,
+    ObjectSymbol, ObjectSymbolTable, Permissions, RawRelocation, ReadError, ReadRef, Relocation,
+    Result, SectionFlags, SectionIndex, SectionKind, SymbolFlags, SymbolIndex, SymbolKind,
+    SymbolScope, SymbolSection,
 };
 
 const SECTION_CUSTOM: usize = 0;
@@ -137,13 +138,15 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                     }
                 }
                 wp::SectionCode::Function => {
-                    local_func_kinds = vec![
-                        LocalFunctionKind::Unknown;
-                        section
-                            .get_function_section_reader()
-                            .read_error("Couldn't read header of the function section")?
-                            .get_count() as usize
-                    ];
+                    let count = section
+                        .get_function_section_reader()
+                        .read_error("Couldn't read header of the function section")?
+                        .get_count() as usize;
+                    // Each function uses at least one byte, so this bounds the allocation.
+                    if count > section.range().end - section.range().start {
+                        return Err(Error("Invalid Wasm function count"));
+                    }
+                    local_func_kinds = vec![LocalFunctionKind::Unknown; count];
                 }
                 wp::SectionCode::Export => {
                     if let Some(main_file_symbol) = main_file_symbol.take() {
@@ -161,8 +164,9 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                                 if let Some(local_func_id) =
                                     export.index.checked_sub(imported_funcs_count)
                                 {
-                                    let local_func_kind =
-                                        &mut local_func_kinds[local_func_id as usize];
+                                    let local_func_kind = local_func_kinds
+                                        .get_mut(local_func_id as usize)
+                                        .read_error("Invalid Wasm exported function index")?;
                                     if let LocalFunctionKind::Unknown = local_func_kind {
                                         *local_func_kind = LocalFunctionKind::Exported {
                                             symbol_ids: Vec::new(),
@@ -268,10 +272,10 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
                             if let Some(local_index) =
                                 naming.index.checked_sub(imported_funcs_count)
                             {
-                                if let LocalFunctionKind::Local { symbol_id } =
-                                    local_func_kinds[local_index as usize]
+                                if let Some(LocalFunctionKind::Local { symbol_id }) =
+                                    local_func_kinds.get(local_index as usize)
                                 {
-                                    file.symbols[symbol_id as usize].name = naming.name;
+                                    file.symbols[*symbol_id as usize].name = naming.name;
                                 }
                             }
                         }
@@ -293,6 +297,315 @@ impl<'data, R: ReadRef<'data>> WasmFile<'data, R> {
     }
 }
 
+impl<'data, R> WasmFile<'data, R> {
+    fn id_section(&self, id: usize) -> Option<&wp::Section<'data>> {
+        self.id_sections[id].map(|index| &self.sections[index])
+    }
+
+    /// Get the memories of the module.
+    ///
+    /// This includes imported memories, which are listed first
+    /// so that the position in the vector is the memory index.
+    pub fn memories(&self) -> Result<Vec<MemoryType>> {
+        let mut memories = Vec::new();
+        if let Some(section) = self.id_section(SECTION_IMPORT) {
+            for import in section
+                .get_import_section_reader()
+                .read_error("Couldn't read header of the import section")?
+            {
+                let import = import.read_error("Couldn't read an import item")?;
+                if let wp::ImportSectionEntryType::Memory(memory) = import.ty {
+                    memories.push(MemoryType::new(memory));
+                }
+            }
+        }
+        if let Some(section) = self.id_section(SECTION_MEMORY) {
+            for memory in section
+                .get_memory_section_reader()
+                .read_error("Couldn't read header of the memory section")?
+            {
+                let memory = memory.read_error("Couldn't read a memory type")?;
+                memories.push(MemoryType::new(memory));
+            }
+        }
+        Ok(memories)
+    }
+
+    /// Get the tables of the module.
+    ///
+    /// This includes imported tables, which are listed first
+    /// so that the position in the vector is the table index.
+    pub fn tables(&self) -> Result<Vec<TableType>> {
+        let mut tables = Vec::new();
+        if let Some(section) = self.id_section(SECTION_IMPORT) {
+            for import in section
+                .get_import_section_reader()
+                .read_error("Couldn't read header of the import section")?
+            {
+                let import = import.read_error("Couldn't read an import item")?;
+                if let wp::ImportSectionEntryType::Table(table) = import.ty {
+                    tables.push(TableType::new(table));
+                }
+            }
+        }
+        if let Some(section) = self.id_section(SECTION_TABLE) {
+            for table in section
+                .get_table_section_reader()
+                .read_error("Couldn't read header of the table section")?
+            {
+                let table = table.read_error("Couldn't read a table type")?;
+                tables.push(TableType::new(table));
+            }
+        }
+        Ok(tables)
+    }
+
+    /// Get the data segments of the module.
+    pub fn data_segments(&self) -> Result<Vec<DataSegment<'data>>> {
+        let mut segments = Vec::new();
+        if let Some(section) = self.id_section(SECTION_DATA) {
+            for data in section
+                .get_data_section_reader()
+                .read_error("Couldn't read header of the data section")?
+            {
+                let data = data.read_error("Couldn't read a data segment")?;
+                let (memory_index, offset) = match data.kind {
+                    wp::DataKind::Passive => (None, None),
+                    wp::DataKind::Active {
+                        memory_index,
+                        init_expr,
+                    } => (Some(memory_index), const_expr_value(&init_expr)),
+                };
+                segments.push(DataSegment {
+                    memory_index,
+                    offset,
+                    data: data.data,
+                });
+            }
+        }
+        Ok(segments)
+    }
+
+    /// Get the element segments of the module.
+    pub fn element_segments(&self) -> Result<Vec<ElementSegment>> {
+        let mut segments = Vec::new();
+        if let Some(section) = self.id_section(SECTION_ELEMENT) {
+            for element in section
+                .get_element_section_reader()
+                .read_error("Couldn't read header of the element section")?
+            {
+                let element = element.read_error("Couldn't read an element segment")?;
+                let (table_index, offset) = match element.kind {
+                    wp::ElementKind::Passive | wp::ElementKind::Declared => (None, None),
+                    wp::ElementKind::Active {
+                        table_index,
+                        init_expr,
+                    } => (Some(table_index), const_expr_value(&init_expr)),
+                };
+                let mut items = element
+                    .items
+                    .get_items_reader()
+                    .read_error("Couldn't read header of the element items")?;
+                // The count is not trusted to preallocate, since each item may be truncated.
+                let mut functions = Vec::new();
+                for _ in 0..items.get_count() {
+                    match items.read().read_error("Couldn't read an element item")? {
+                        wp::ElementItem::Func(index) => functions.push(Some(index)),
+                        wp::ElementItem::Null(_) => functions.push(None),
+                    }
+                }
+                segments.push(ElementSegment {
+                    table_index,
+                    offset,
+                    functions,
+                });
+            }
+        }
+        Ok(segments)
+    }
+}
+
+/// Evaluate a constant expression that only contains an integer constant.
+fn const_expr_value(expr: &wp::InitExpr) -> Option<u64> {
+    match expr.get_operators_reader().read().ok()? {
+        wp::Operator::I32Const { value } => Some(u64::from(value as u32)),
+        wp::Operator::I64Const { value } => Some(value as u64),
+        _ => None,
+    }
+}
+
+/// A Wasm data segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataSegment<'data> {
+    memory_index: Option<u32>,
+    offset: Option<u64>,
+    data: &'data [u8],
+}
+
+impl<'data> DataSegment<'data> {
+    /// The index of the memory that the segment is copied into.
+    ///
+    /// Returns `None` for passive segments.
+    #[inline]
+    pub fn memory_index(&self) -> Option<u32> {
+        self.memory_index
+    }
+
+    /// The offset in memory that the segment is copied to.
+    ///
+    /// Returns `None` for passive segments, or if the offset is not an integer constant.
+    #[inline]
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// The contents of the segment.
+    #[inline]
+    pub fn data(&self) -> &'data [u8] {
+        self.data
+    }
+}
+
+/// A Wasm element segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementSegment {
+    table_index: Option<u32>,
+    offset: Option<u64>,
+    functions: Vec<Option<u32>>,
+}
+
+impl ElementSegment {
+    /// The index of the table that the segment is copied into.
+    ///
+    /// Returns `None` for passive and declared segments.
+    #[inline]
+    pub fn table_index(&self) -> Option<u32> {
+        self.table_index
+    }
+
+    /// The offset in the table that the segment is copied to.
+    ///
+    /// Returns `None` for passive and declared segments, or if the offset
+    /// is not an integer constant.
+    #[inline]
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// The function indices of the elements in the segment.
+    ///
+    /// Null references are `None`.
+    #[inline]
+    pub fn functions(&self) -> &[Option<u32>] {
+        &self.functions
+    }
+}
+
+/// The limits of a Wasm memory or table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    initial: u32,
+    maximum: Option<u32>,
+}
+
+impl Limits {
+    fn new(limits: wp::ResizableLimits) -> Self {
+        Limits {
+            initial: limits.initial,
+            maximum: limits.maximum,
+        }
+    }
+
+    /// The initial size.
+    ///
+    /// This is in units of pages for memories, and elements for tables.
+    #[inline]
+    pub fn initial(&self) -> u32 {
+        self.initial
+    }
+
+    /// The maximum size, if any.
+    ///
+    /// This is in units of pages for memories, and elements for tables.
+    #[inline]
+    pub fn maximum(&self) -> Option<u32> {
+        self.maximum
+    }
+}
+
+/// A Wasm memory type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryType {
+    limits: Limits,
+    shared: bool,
+}
+
+impl MemoryType {
+    fn new(memory: wp::MemoryType) -> Self {
+        MemoryType {
+            limits: Limits::new(memory.limits),
+            shared: memory.shared,
+        }
+    }
+
+    /// The limits of the memory, in units of 64 KiB pages.
+    #[inline]
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Return true if the memory is shared.
+    #[inline]
+    pub fn is_shared(&self) -> bool {
+        self.shared
+    }
+}
+
+/// The type of the elements in a Wasm table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TableElementType {
+    /// Function references.
+    FuncRef,
+    /// External references.
+    ExternRef,
+    /// An unknown element type.
+    Unknown,
+}
+
+/// A Wasm table type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableType {
+    element_type: TableElementType,
+    limits: Limits,
+}
+
+impl TableType {
+    fn new(table: wp::TableType) -> Self {
+        let element_type = match table.element_type {
+            wp::Type::FuncRef => TableElementType::FuncRef,
+            wp::Type::ExternRef => TableElementType::ExternRef,
+            _ => TableElementType::Unknown,
+        };
+        TableType {
+            element_type,
+            limits: Limits::new(table.limits),
+        }
+    }
+
+    /// The type of the elements in the table.
+    #[inline]
+    pub fn element_type(&self) -> TableElementType {
+        self.element_type
+    }
+
+    /// The limits of the table, in units of elements.
+    #[inline]
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+}
+
 impl<'data, R> read::private::Sealed for WasmFile<'data, R> {}
 
 impl<'data, 'file, R> Object<'data, 'file> for WasmFile<'data, R>
@@ -326,6 +639,23 @@ where
         false
     }
 
+    fn is_relocatable(&self) -> bool {
+        self.sections.iter().any(|section| {
+            matches!(
+                section.code,
+                wp::SectionCode::Custom {
+                    kind: wp::CustomSectionKind::Linking,
+                    ..
+                }
+            )
+        })
+    }
+
+    #[inline]
+    fn default_page_size(&self) -> u64 {
+        0x10000
+    }
+
     fn segments(&'file self) -> Self::SegmentIterator {
         WasmSegmentIterator { file: self }
     }
@@ -478,6 +808,11 @@ impl<'data, 'file, R> ObjectSegment<'data> for WasmSegment<'data, 'file, R> {
     fn name(&self) -> Result<Option<&str>> {
         unreachable!()
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        unreachable!()
+    }
 }
 
 /// An iterator over the sections of a `WasmFile`.
@@ -615,10 +950,20 @@ impl<'data, 'file, R> ObjectSection<'data> for WasmSection<'data, 'file, R> {
         WasmRelocationIterator(PhantomData)
     }
 
+    #[inline]
+    fn raw_relocations(&self) -> Result<Vec<RawRelocation>> {
+        Ok(Vec::new())
+    }
+
     #[inline]
     fn flags(&self) -> SectionFlags {
         SectionFlags::None
     }
+
+    #[inline]
+    fn permissions(&self) -> Permissions {
+        Permissions::default()
+    }
 }
 
 /// An iterator over the COMDAT section groups of a `WasmFile`.
@@ -96,6 +96,7 @@ impl Object {
                 }
                 _ => relocation.addend,
             },
+            Architecture::Aarch64 => relocation.addend,
             _ => unimplemented!(),
         };
         relocation.addend -= constant;
@@ -270,6 +271,7 @@ impl Object {
                 match self.architecture {
                     Architecture::I386 => coff::IMAGE_FILE_MACHINE_I386,
                     Architecture::X86_64 => coff::IMAGE_FILE_MACHINE_AMD64,
+                    Architecture::Aarch64 => coff::IMAGE_FILE_MACHINE_ARM64,
                     _ => {
                         return Err(Error(format!(
                             "unimplemented architecture {:?}",
@@ -317,7 +319,7 @@ impl Object {
                         | coff::IMAGE_SCN_MEM_READ
                         | coff::IMAGE_SCN_MEM_WRITE
                 }
-                SectionKind::ReadOnlyData | SectionKind::ReadOnlyString => {
+                SectionKind::ReadOnlyData | SectionKind::ReadOnlyString | SectionKind::Unwind => {
                     coff::IMAGE_SCN_CNT_INITIALIZED_DATA | coff::IMAGE_SCN_MEM_READ
                 }
                 SectionKind::Debug | SectionKind::Other | SectionKind::OtherString => {
@@ -331,6 +333,7 @@ impl Object {
                 | SectionKind::UninitializedTls
                 | SectionKind::TlsVariables
                 | SectionKind::Note
+                | SectionKind::DynamicLinkInfo
                 | SectionKind::Unknown
                 | SectionKind::Metadata
                 | SectionKind::Elf(_) => {
@@ -465,6 +468,17 @@ impl Object {
                                 return Err(Error(format!("unimplemented relocation {:?}", reloc)));
                             }
                         },
+                        Architecture::Aarch64 => match (reloc.kind, reloc.size, reloc.addend) {
+                            (RelocationKind::Absolute, 32, 0) => coff::IMAGE_REL_ARM64_ADDR32,
+                            (RelocationKind::ImageOffset, 32, 0) => coff::IMAGE_REL_ARM64_ADDR32NB,
+                            (RelocationKind::SectionIndex, 16, 0) => coff::IMAGE_REL_ARM64_SECTION,
+                            (RelocationKind::SectionOffset, 32, 0) => coff::IMAGE_REL_ARM64_SECREL,
+                            (RelocationKind::Absolute, 64, 0) => coff::IMAGE_REL_ARM64_ADDR64,
+                            (RelocationKind::Coff(x), _, _) => x,
+                            _ => {
+                                return Err(Error(format!("unimplemented relocation {:?}", reloc)));
+                            }
+                        },
                         _ => {
                             return Err(Error(format!(
                                 "unimplemented architecture {:?}",
@@ -77,6 +77,8 @@ impl Object {
             Architecture::X86_64 => true,
             Architecture::S390x => true,
             Architecture::Mips => false,
+            Architecture::PowerPc | Architecture::PowerPc64 => true,
+            Architecture::Riscv32 | Architecture::Riscv64 => true,
             _ => {
                 return Err(Error(format!(
                     "unimplemented architecture {:?}",
@@ -349,6 +351,9 @@ impl Object {
             Architecture::X86_64 => elf::EM_X86_64,
             Architecture::S390x => elf::EM_S390,
             Architecture::Mips => elf::EM_MIPS,
+            Architecture::PowerPc => elf::EM_PPC,
+            Architecture::PowerPc64 => elf::EM_PPC64,
+            Architecture::Riscv32 | Architecture::Riscv64 => elf::EM_RISCV,
             _ => {
                 return Err(Error(format!(
                     "unimplemented architecture {:?}",
@@ -678,6 +683,37 @@ impl Object {
                                 return Err(Error(format!("unimplemented relocation {:?}", reloc)));
                             }
                         },
+                        Architecture::PowerPc => match (reloc.kind, reloc.encoding, reloc.size) {
+                            (RelocationKind::Absolute, _, 32) => elf::R_PPC_ADDR32,
+                            (RelocationKind::Elf(x), _, _) => x,
+                            _ => {
+                                return Err(Error(format!("unimplemented relocation {:?}", reloc)));
+                            }
+                        },
+                        Architecture::PowerPc64 => match (reloc.kind, reloc.encoding, reloc.size) {
+                            (RelocationKind::Absolute, _, 32) => elf::R_PPC64_ADDR32,
+                            (RelocationKind::Absolute, _, 64) => elf::R_PPC64_ADDR64,
+                            (RelocationKind::Elf(x), _, _) => x,
+                            _ => {
+                                return Err(Error(format!("unimplemented relocation {:?}", reloc)));
+                            }
+                        },
+                        Architecture::Riscv32 | Architecture::Riscv64 => {
+                            match (reloc.kind, reloc.encoding, reloc.size) {
+                                (RelocationKind::Absolute, _, 8) => elf::R_RISCV_SET8,
+                                (RelocationKind::Absolute, _, 16) => elf::R_RISCV_SET16,
+                                (RelocationKind::Absolute, _, 32) => elf::R_RISCV_32,
+                                (RelocationKind::Absolute, _, 64) => elf::R_RISCV_64,
+                                (RelocationKind::Relative, _, 32) => elf::R_RISCV_32_PCREL,
+                                (RelocationKind::Elf(x), _, _) => x,
+                                _ => {
+                                    return Err(Error(format!(
+                                        "unimplemented relocation {:?}",
+                                        reloc
+                                    )));
+                                }
+                            }
+                        }
                         _ => {
                             return Err(Error(format!(
                                 "unimplemented architecture {:?}",
@@ -768,7 +804,9 @@ impl Object {
                     SectionKind::Tls => elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS,
                     SectionKind::UninitializedData => elf::SHF_ALLOC | elf::SHF_WRITE,
                     SectionKind::UninitializedTls => elf::SHF_ALLOC | elf::SHF_WRITE | elf::SHF_TLS,
-                    SectionKind::ReadOnlyData => elf::SHF_ALLOC,
+                    SectionKind::ReadOnlyData
+                    | SectionKind::Unwind
+                    | SectionKind::DynamicLinkInfo => elf::SHF_ALLOC,
                     SectionKind::ReadOnlyString => {
                         elf::SHF_ALLOC | elf::SHF_STRINGS | elf::SHF_MERGE
                     }
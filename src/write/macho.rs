@@ -408,8 +408,14 @@ impl Object {
                     SectionKind::TlsVariables => macho::S_THREAD_LOCAL_VARIABLES,
                     SectionKind::Debug => macho::S_ATTR_DEBUG,
                     SectionKind::OtherString => macho::S_CSTRING_LITERALS,
-                    SectionKind::Other | SectionKind::Linker | SectionKind::Metadata => 0,
-                    SectionKind::Note | SectionKind::Unknown | SectionKind::Elf(_) => {
+                    SectionKind::Other
+                    | SectionKind::Linker
+                    | SectionKind::Metadata
+                    | SectionKind::Unwind => 0,
+                    SectionKind::Note
+                    | SectionKind::DynamicLinkInfo
+                    | SectionKind::Unknown
+                    | SectionKind::Elf(_) => {
                         return Err(Error(format!(
                             "unimplemented section `{}` kind {:?}",
                             section.name().unwrap_or(""),
@@ -586,6 +592,15 @@ impl Object {
                                 return Err(Error(format!("unimplemented relocation {:?}", reloc)));
                             }
                         },
+                        Architecture::Aarch64 => match (reloc.kind, reloc.encoding, reloc.addend) {
+                            (RelocationKind::Absolute, RelocationEncoding::Generic, 0) => {
+                                (false, macho::ARM64_RELOC_UNSIGNED)
+                            }
+                            (RelocationKind::MachO { value, relative }, _, 0) => (relative, value),
+                            _ => {
+                                return Err(Error(format!("unimplemented relocation {:?}", reloc)));
+                            }
+                        },
                         _ => {
                             return Err(Error(format!(
                                 "unimplemented architecture {:?}",
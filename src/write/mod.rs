@@ -24,6 +24,10 @@ mod coff;
 mod elf;
 #[cfg(feature = "macho")]
 mod macho;
+#[cfg(all(feature = "elf", feature = "read_core"))]
+mod patch;
+#[cfg(all(feature = "elf", feature = "read_core"))]
+pub use patch::ElfPatch;
 mod string;
 mod util;
 
@@ -0,0 +1,205 @@
+use core::convert::TryFrom;
+use std::marker::PhantomData;
+use std::mem;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use crate::elf;
+use crate::endian::{U32Bytes, U64Bytes};
+use crate::pod::bytes_of;
+use crate::read::elf::{FileHeader, SectionHeader, SectionTable, Sym, SymbolTable};
+use crate::read::{self, SymbolIndex};
+use crate::write::{Error, Result};
+
+/// An ELF file that can be patched in place.
+///
+/// This modifies the bytes of an existing file without rebuilding it, so all
+/// bytes other than the patched fields are preserved.
+///
+/// Only the symbol table (`SHT_SYMTAB`) can be patched. Symbol indices are the
+/// same as those used by `ElfFile::symbols`.
+#[derive(Debug)]
+pub struct ElfPatch<Elf: FileHeader> {
+    data: Vec<u8>,
+    marker: PhantomData<Elf>,
+}
+
+impl<Elf: FileHeader> ElfPatch<Elf> {
+    /// Create a patch for the given file data.
+    ///
+    /// Returns an error if the data is not a valid ELF file of the given type.
+    pub fn new(data: Vec<u8>) -> Result<Self> {
+        let patch = ElfPatch {
+            data,
+            marker: PhantomData,
+        };
+        patch.tables().map_err(read_error)?;
+        Ok(patch)
+    }
+
+    /// Return the file data, including any patches.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Return the file data, including any patches.
+    #[inline]
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Set the `st_value` field of a symbol.
+    ///
+    /// For 32-bit files, returns an error if the address does not fit in 32 bits.
+    pub fn set_symbol_address(&mut self, index: SymbolIndex, address: u64) -> Result<()> {
+        let (is_64, endian, offset) = {
+            let (header, sections, symbols) = self.tables().map_err(read_error)?;
+            let endian = header.endian().map_err(read_error)?;
+            let offset = symbol_offset(endian, &sections, &symbols, index)?;
+            (header.is_type_64(), endian, offset)
+        };
+        if is_64 {
+            // Skip `st_name`, `st_info`, `st_other` and `st_shndx`.
+            self.write_bytes(offset + 8, bytes_of(&U64Bytes::new(endian, address)))
+        } else {
+            let address = u32::try_from(address).map_err(|_| {
+                Error(format!(
+                    "Symbol address 0x{:x} is too large for a 32-bit ELF file",
+                    address
+                ))
+            })?;
+            // Skip `st_name`.
+            self.write_bytes(offset + 4, bytes_of(&U32Bytes::new(endian, address)))
+        }
+    }
+
+    /// Set the name of a symbol.
+    ///
+    /// The string table is not resized, so this only succeeds in the following cases:
+    /// - the name is already in the string table, in which case the symbol is
+    ///   changed to refer to the existing string
+    /// - the name is no longer than the current name of the symbol, and the
+    ///   current name is not shared with any other symbol or section, in which
+    ///   case the current name is overwritten
+    pub fn set_symbol_name(&mut self, index: SymbolIndex, name: &[u8]) -> Result<()> {
+        if name.contains(&0) {
+            return Err(Error(String::from("Symbol name contains a null byte")));
+        }
+        let (offset, bytes) = self.symbol_name_patch(index, name)?;
+        self.write_bytes(offset, &bytes)
+    }
+
+    /// Return the file offset and bytes to write to set the name of a symbol.
+    fn symbol_name_patch(&self, index: SymbolIndex, name: &[u8]) -> Result<(usize, Vec<u8>)> {
+        let (header, sections, symbols) = self.tables().map_err(read_error)?;
+        let endian = header.endian().map_err(read_error)?;
+        let offset = symbol_offset(endian, &sections, &symbols, index)?;
+        let strtab_index = sections
+            .section(symbols.section())
+            .map_err(read_error)?
+            .sh_link(endian) as usize;
+        let (strtab_offset, strtab_size) = sections
+            .section(strtab_index)
+            .ok()
+            .and_then(|section| section.file_range(endian))
+            .ok_or_else(|| Error(String::from("Invalid ELF symbol string table")))?;
+        let strings = self
+            .data
+            .get(strtab_offset as usize..)
+            .and_then(|data| data.get(..strtab_size as usize))
+            .ok_or_else(|| Error(String::from("Invalid ELF symbol string table")))?;
+
+        // Refer to an existing string if possible.
+        if let Some(st_name) = strings
+            .windows(name.len() + 1)
+            .position(|s| s[..name.len()] == *name && s[name.len()] == 0)
+        {
+            let st_name = U32Bytes::new(endian, st_name as u32);
+            return Ok((offset, bytes_of(&st_name).to_vec()));
+        }
+
+        // Otherwise overwrite the current name.
+        let symbol = symbols.symbol(index.0).map_err(read_error)?;
+        let start = symbol.st_name(endian) as usize;
+        let len = strings
+            .get(start..)
+            .and_then(|s| s.iter().position(|&c| c == 0))
+            .ok_or_else(|| Error(String::from("Invalid ELF symbol name offset")))?;
+        if name.len() > len {
+            return Err(Error(format!(
+                "Symbol name of length {} does not fit in the {} bytes of the current name",
+                name.len(),
+                len
+            )));
+        }
+        let end = start + len;
+        let is_shared = |st_name: u32| {
+            let st_name = st_name as usize;
+            if st_name >= start {
+                st_name <= end
+            } else {
+                // A tail merged string ends with the same null terminator.
+                strings.get(st_name..end).map(|s| !s.contains(&0)) == Some(true)
+            }
+        };
+        let shared_symbol = symbols
+            .iter()
+            .enumerate()
+            .any(|(i, symbol)| i != index.0 && is_shared(symbol.st_name(endian)));
+        let shared_section = header.shstrndx(endian, &*self.data).ok() == Some(strtab_index as u32)
+            && sections
+                .iter()
+                .any(|section| is_shared(section.sh_name(endian)));
+        if shared_symbol || shared_section {
+            return Err(Error(String::from(
+                "Symbol name is shared and cannot be overwritten",
+            )));
+        }
+        let mut bytes = name.to_vec();
+        bytes.push(0);
+        Ok((strtab_offset as usize + start, bytes))
+    }
+
+    /// Parse the file header, section table, and symbol table.
+    #[allow(clippy::type_complexity)]
+    fn tables(&self) -> read::Result<(&Elf, SectionTable<'_, Elf>, SymbolTable<'_, Elf>)> {
+        let data = &*self.data;
+        let header = Elf::parse(data)?;
+        let endian = header.endian()?;
+        let sections = header.sections(endian, data)?;
+        let symbols = sections.symbols(endian, data, elf::SHT_SYMTAB)?;
+        Ok((header, sections, symbols))
+    }
+
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> Result<()> {
+        self.data
+            .get_mut(offset..)
+            .and_then(|data| data.get_mut(..bytes.len()))
+            .ok_or_else(|| Error(String::from("Invalid ELF patch offset")))?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Return the file offset of a symbol in the symbol table.
+fn symbol_offset<Elf: FileHeader>(
+    endian: Elf::Endian,
+    sections: &SectionTable<'_, Elf>,
+    symbols: &SymbolTable<'_, Elf>,
+    index: SymbolIndex,
+) -> Result<usize> {
+    if index.0 >= symbols.len() {
+        return Err(Error(format!("Invalid ELF symbol index {}", index.0)));
+    }
+    let (offset, _) = sections
+        .section(symbols.section())
+        .ok()
+        .and_then(|section| section.file_range(endian))
+        .ok_or_else(|| Error(String::from("Invalid ELF symbol table")))?;
+    Ok(offset as usize + index.0 * mem::size_of::<Elf::Sym>())
+}
+
+fn read_error(error: read::Error) -> Error {
+    Error(error.to_string())
+}
@@ -1,5 +1,5 @@
 #![cfg(feature = "read")]
-use object::{File, Object};
+use object::{File, Object, ObjectSection, ObjectSegment};
 use std::{env, fs};
 
 #[test]
@@ -11,6 +11,24 @@ fn parse_self() {
     assert!(object.sections().count() != 0);
 }
 
+#[test]
+fn parse_self_section_at_file_offset() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    for section in object.sections() {
+        if let Some((offset, size)) = section.file_range() {
+            if size == 0 {
+                continue;
+            }
+            let found = object.section_at_file_offset(offset + size - 1).unwrap();
+            let (found_offset, found_size) = found.file_range().unwrap();
+            assert!(found_offset <= offset + size - 1);
+            assert!(offset + size - 1 < found_offset + found_size);
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn parse_self_cache() {
@@ -23,3 +41,221 @@ fn parse_self_cache() {
     assert!(object.entry() != 0);
     assert!(object.sections().count() != 0);
 }
+
+#[test]
+fn parse_self_symbol_map_with_filter() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let all = object.symbol_map_with_filter(|_| true);
+    let definitions = object.symbol_map();
+    assert!(all.symbols().len() >= definitions.symbols().len());
+    let none = object.symbol_map_with_filter(|_| false);
+    assert!(none.symbols().is_empty());
+}
+
+#[test]
+fn parse_self_permissions() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let entry = object.entry();
+    let segment = object
+        .segments()
+        .find(|segment| entry.wrapping_sub(segment.address()) < segment.size())
+        .unwrap();
+    let permissions = segment.permissions();
+    assert!(permissions.read() && !permissions.write() && permissions.execute());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn parse_self_data_permissions() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let data = object.section_by_name(".data").unwrap();
+    let segment = object
+        .segments()
+        .find(|segment| data.address().wrapping_sub(segment.address()) < segment.size())
+        .unwrap();
+    let permissions = segment.permissions();
+    assert!(permissions.read() && permissions.write() && !permissions.execute());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn parse_self_segment_file_range() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let bss = object.section_by_name(".bss").unwrap();
+    assert!(bss.size() != 0);
+    let segment = object
+        .segments()
+        .find(|segment| bss.address().wrapping_sub(segment.address()) < segment.size())
+        .unwrap();
+    // The zero-initialized data is not stored in the file.
+    let (offset, size) = segment.file_range();
+    assert!(size <= segment.size() - bss.size());
+    assert_eq!(
+        segment.data().unwrap(),
+        &data[offset as usize..][..size as usize]
+    );
+}
+
+#[test]
+fn parse_self_wx_regions() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    assert!(!object.has_wx_regions());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn parse_self_lenient() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let (object, errors) = File::parse_lenient(&*data);
+    assert!(object.is_some());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn parse_self_checked() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    File::parse_checked(&*data).unwrap();
+
+    // Truncated files must not panic.
+    for len in (0..data.len()).step_by(4093) {
+        let _ = File::parse_checked(&data[..len]);
+    }
+}
+
+#[test]
+fn parse_self_memory_size() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let memory_size = object.memory_size();
+    let file_size = object.file_size_loaded();
+    assert!(file_size != 0);
+    assert!(file_size <= data.len() as u64);
+    assert!(memory_size >= file_size);
+    assert!(object.memory_span() >= memory_size);
+}
+
+#[test]
+fn parse_self_max_segment_alignment() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let align = object.max_segment_alignment().unwrap();
+    assert!(align.is_power_of_two());
+    for segment in object.segments() {
+        assert!(segment.align() <= align);
+    }
+}
+
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+#[test]
+fn parse_self_eh_frame_hdr() {
+    use object::read::elf::ElfFile64;
+    use object::Endianness;
+
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = ElfFile64::<Endianness>::parse(&*data).unwrap();
+    let hdr = object.eh_frame_hdr().unwrap().unwrap();
+    let eh_frame = object.section_by_name(".eh_frame").unwrap();
+    assert_eq!(hdr.eh_frame_address(), eh_frame.address());
+    let table = hdr.table();
+    assert!(!table.is_empty());
+    assert!(table.windows(2).all(|w| w[0].0 <= w[1].0));
+    for &(_, fde) in table {
+        assert!(fde.wrapping_sub(eh_frame.address()) < eh_frame.size());
+    }
+}
+
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+#[test]
+fn parse_self_section_segment_map() {
+    use object::elf;
+    use object::read::elf::{ElfFile64, ProgramHeader};
+    use object::{Endianness, SectionFlags};
+
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = ElfFile64::<Endianness>::parse(&*data).unwrap();
+    let endian = object.endian();
+    let map = object.section_segment_map();
+    assert!(!map.is_empty());
+    for (index, segments) in map {
+        let section = object.section_by_index(index).unwrap();
+        match section.flags() {
+            SectionFlags::Elf { sh_flags } => assert!(sh_flags & u64::from(elf::SHF_ALLOC) != 0),
+            _ => unreachable!(),
+        }
+        if section.size() == 0 {
+            continue;
+        }
+        // Every allocated section is loaded by exactly one `PT_LOAD` segment.
+        let loads = segments
+            .iter()
+            .filter(|&&segment| object.raw_segments()[segment].p_type(endian) == elf::PT_LOAD)
+            .count();
+        let tbss = section.name() == Ok(".tbss");
+        assert_eq!(loads, if tbss { 0 } else { 1 }, "{:?}", section.name());
+    }
+}
+
+#[test]
+fn parse_self_section_by_address() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    let text = object.section_by_name(".text").unwrap();
+    for &address in &[text.address(), text.address() + text.size() - 1] {
+        let section = object.section_by_address(address).unwrap();
+        assert_eq!(section.index(), text.index());
+    }
+}
+
+#[cfg(all(target_os = "windows", target_pointer_width = "64"))]
+#[test]
+fn parse_self_dos_stub() {
+    use object::read::pe::PeFile64;
+
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = PeFile64::parse(&*data).unwrap();
+    let stub = object.dos_stub().unwrap();
+    let offset = object.dos_header().nt_headers_offset() as usize;
+    assert_eq!(stub, &data[64..offset]);
+}
+
+#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+#[test]
+fn parse_self_exception_functions() {
+    use object::read::pe::PeFile64;
+
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = PeFile64::parse(&*data).unwrap();
+    let functions = object.exception_functions().unwrap();
+    assert!(!functions.is_empty());
+    for function in &functions {
+        assert!(function.begin_address() < function.end_address());
+        assert!(function.unwind_info_address().is_some());
+    }
+}
+
+#[test]
+fn parse_self_validate() {
+    let exe = env::current_exe().unwrap();
+    let data = fs::read(exe).unwrap();
+    let object = File::parse(&*data).unwrap();
+    assert_eq!(object.validate(), Vec::new());
+}
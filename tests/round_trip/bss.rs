@@ -139,6 +139,14 @@ fn elf_x86_64_bss() {
     assert_eq!(bss.kind(), SectionKind::UninitializedData);
     assert_eq!(bss.size(), 58);
     assert_eq!(bss.data(), Ok(&[][..]));
+    assert_eq!(
+        bss.sizes(),
+        Ok(read::SectionSizes {
+            file_size: 0,
+            memory_size: 58,
+            uncompressed_size: 0,
+        })
+    );
 
     let mut symbols = object.symbols();
 
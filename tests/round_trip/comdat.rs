@@ -41,6 +41,15 @@ fn coff_x86_64_comdat() {
 
     //std::fs::write(&"comdat.o", &bytes).unwrap();
 
+    let coff = read::coff::CoffFile::<&[u8]>::parse(&*bytes).unwrap();
+    let mut symbols = coff.symbols();
+    let aux = symbols.next().unwrap().aux_section_definition().unwrap();
+    assert_eq!(aux.length.get(object::LittleEndian), 4);
+    assert_eq!(aux.selection, pe::IMAGE_COMDAT_SELECT_NODUPLICATES);
+    let aux = symbols.next().unwrap().aux_section_definition().unwrap();
+    assert_eq!(aux.selection, pe::IMAGE_COMDAT_SELECT_ASSOCIATIVE);
+    assert!(symbols.next().unwrap().aux_section_definition().is_none());
+
     let object = read::File::parse(&*bytes).unwrap();
     assert_eq!(object.format(), BinaryFormat::Coff);
     assert_eq!(object.architecture(), Architecture::X86_64);
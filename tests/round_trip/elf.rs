@@ -1,8 +1,9 @@
 use object::read::elf::{FileHeader, SectionHeader};
-use object::read::{Object, ObjectSymbol};
+use object::read::{Object, ObjectSection, ObjectSymbol};
 use object::{
-    elf, read, write, Architecture, BinaryFormat, Endianness, LittleEndian, SectionIndex,
-    SectionKind, SymbolFlags, SymbolKind, SymbolScope, SymbolSection, U32,
+    elf, read, write, Architecture, BinaryFormat, Endianness, LittleEndian, RelocationEncoding,
+    RelocationKind, SectionIndex, SectionKind, SymbolFlags, SymbolKind, SymbolScope, SymbolSection,
+    U32,
 };
 use std::io::Write;
 
@@ -61,6 +62,7 @@ fn compression_zlib() {
     let mut encoder = flate2::write::ZlibEncoder::new(buf, flate2::Compression::default());
     encoder.write_all(data).unwrap();
     let compressed = encoder.finish().unwrap();
+    let compressed_len = compressed.len() as u64;
 
     let mut object =
         write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
@@ -84,6 +86,11 @@ fn compression_zlib() {
     let section = object.section_by_name(".debug_info").unwrap();
     let uncompressed = section.uncompressed_data().unwrap();
     assert_eq!(data, &*uncompressed);
+
+    let sizes = section.sizes().unwrap();
+    assert_eq!(sizes.file_size, compressed_len);
+    assert_eq!(sizes.memory_size, compressed_len);
+    assert_eq!(sizes.uncompressed_size, len);
 }
 
 #[cfg(feature = "compression")]
@@ -123,6 +130,54 @@ fn compression_gnu() {
     assert_eq!(data, &*uncompressed);
 }
 
+#[test]
+fn compression_zstd() {
+    use object::read::ObjectSection;
+    use object::LittleEndian as LE;
+
+    let data = b"test data data data data data data data data data";
+    let len = data.len() as u64;
+    // A zstd frame with a compressed block, created with `zstd -19 --no-check`.
+    #[rustfmt::skip]
+    let compressed_data = [
+        0x28, 0xb5, 0x2f, 0xfd, 0x20, 0x31, 0x85, 0x00, 0x00, 0x50, 0x74, 0x65,
+        0x73, 0x74, 0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x01, 0x00, 0x50, 0x16,
+        0x24,
+    ];
+
+    let mut ch = object::elf::CompressionHeader64::<LE>::default();
+    ch.ch_type.set(LE, object::elf::ELFCOMPRESS_ZSTD);
+    ch.ch_size.set(LE, len);
+    ch.ch_addralign.set(LE, 1);
+    let mut compressed = object::bytes_of(&ch).to_vec();
+    compressed.extend_from_slice(&compressed_data);
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(
+        Vec::new(),
+        b".debug_info".to_vec(),
+        object::SectionKind::Other,
+    );
+    object.section_mut(section).set_data(compressed, 1);
+    object.section_mut(section).flags = object::SectionFlags::Elf {
+        sh_flags: object::elf::SHF_COMPRESSED.into(),
+    };
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name(".debug_info").unwrap();
+    let compressed = section.compressed_data().unwrap();
+    assert_eq!(compressed.format, read::CompressionFormat::Zstandard);
+    assert_eq!(compressed.uncompressed_size, len);
+    assert_eq!(compressed.data, &compressed_data[..]);
+    if cfg!(feature = "compression-zstd") {
+        assert_eq!(&*section.uncompressed_data().unwrap(), &data[..]);
+    } else {
+        assert!(section.uncompressed_data().is_err());
+    }
+}
+
 #[test]
 fn note() {
     let endian = Endianness::Little;
@@ -216,3 +271,1405 @@ fn note() {
     assert_eq!(note.n_type(endian), 2);
     assert!(notes.next().unwrap().is_none());
 }
+
+#[test]
+fn gdb_scripts() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(
+        Vec::new(),
+        b".debug_gdb_scripts".to_vec(),
+        object::SectionKind::Other,
+    );
+    object.section_mut(section).set_data(
+        b"\x01foo-gdb.py\0\x04gdb.inlined-script\nimport gdb\n\0".to_vec(),
+        1,
+    );
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let scripts = object.gdb_scripts().unwrap();
+    assert_eq!(scripts.len(), 2);
+    assert_eq!(scripts[0].kind(), read::elf::GdbScriptKind::PythonFile);
+    assert_eq!(scripts[0].name_or_source(), b"foo-gdb.py");
+    assert!(!scripts[0].kind().is_inline());
+    assert_eq!(scripts[1].kind(), read::elf::GdbScriptKind::PythonText);
+    assert_eq!(
+        scripts[1].name_or_source(),
+        &b"gdb.inlined-script\nimport gdb\n"[..]
+    );
+    assert!(scripts[1].kind().is_inline());
+}
+
+#[test]
+fn gnu_debugaltlink() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let file = read::File::parse(&*bytes).unwrap();
+    assert_eq!(file.gnu_debugaltlink(), Ok(None));
+
+    let section = object.add_section(
+        Vec::new(),
+        b".gnu_debugaltlink".to_vec(),
+        SectionKind::Other,
+    );
+    object
+        .section_mut(section)
+        .set_data(b"../alt/foo.debug\0\x12\x34\x56\x78".to_vec(), 1);
+    let bytes = object.write().unwrap();
+    let file = read::File::parse(&*bytes).unwrap();
+    assert_eq!(
+        file.gnu_debugaltlink(),
+        Ok(Some((&b"../alt/foo.debug"[..], &b"\x12\x34\x56\x78"[..])))
+    );
+}
+
+#[test]
+fn dwo_name() {
+    fn build(sections: &[(&[u8], &[u8])]) -> Vec<u8> {
+        let mut object =
+            write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        for (name, data) in sections {
+            let section = object.add_section(Vec::new(), name.to_vec(), SectionKind::Debug);
+            object.section_mut(section).set_data(data.to_vec(), 1);
+        }
+        object.write().unwrap()
+    }
+
+    // DWARF 4 with the GNU extension.
+    #[rustfmt::skip]
+    let bytes = build(&[
+        (b".debug_abbrev", &[
+            0x01, 0x11, 0x00,
+            0x1b, 0x08, // DW_AT_comp_dir, DW_FORM_string
+            0xb0, 0x42, 0x0e, // DW_AT_GNU_dwo_name, DW_FORM_strp
+            0x00, 0x00, 0x00,
+        ]),
+        (b".debug_info", &[
+            0x11, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+            0x01, b'/', b't', b'm', b'p', 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]),
+        (b".debug_str", b"foo.dwo\0"),
+    ]);
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.dwo_name(), Ok(Some(&b"foo.dwo"[..])));
+    assert!(object.has_split_dwarf());
+    assert_eq!(object.dwarf_version(), Some(4));
+
+    // DWARF 5 skeleton unit.
+    #[rustfmt::skip]
+    let bytes = build(&[
+        (b".debug_abbrev", &[
+            0x01, 0x4a, 0x00,
+            0x72, 0x17, // DW_AT_str_offsets_base, DW_FORM_sec_offset
+            0x76, 0x25, // DW_AT_dwo_name, DW_FORM_strx1
+            0x00, 0x00, 0x00,
+        ]),
+        (b".debug_info", &[
+            0x16, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+            0x01, 0x08, 0x00, 0x00, 0x00, 0x01,
+        ]),
+        (b".debug_str_offsets", &[
+            0x0c, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        ]),
+        (b".debug_str", b"abc\0bar.dwo\0"),
+    ]);
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.dwo_name(), Ok(Some(&b"bar.dwo"[..])));
+    assert_eq!(object.dwarf_version(), Some(5));
+
+    // No split DWARF.
+    let bytes = build(&[]);
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.dwo_name(), Ok(None));
+    assert!(!object.has_split_dwarf());
+    assert_eq!(object.dwarf_version(), None);
+}
+
+#[test]
+fn max_segment_alignment() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3], 16);
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.segments().count(), 0);
+    assert_eq!(object.max_segment_alignment(), None);
+}
+
+#[test]
+fn version_definitions() {
+    use object::read::ObjectSection;
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let strings = object.add_section(Vec::new(), b".dynstr".to_vec(), SectionKind::Other);
+    object
+        .section_mut(strings)
+        .set_data(b"\0libfoo.so.1\0FOO_2.0\0FOO_1.0\0FOO_3.0\0".to_vec(), 1);
+    #[rustfmt::skip]
+    let verdef = vec![
+        // Base version.
+        1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0,
+        // FOO_2.0, with parent FOO_1.0.
+        1, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 20, 0, 0, 0, 36, 0, 0, 0,
+        13, 0, 0, 0, 8, 0, 0, 0,
+        21, 0, 0, 0, 0, 0, 0, 0,
+        // FOO_3.0, with parents FOO_2.0 and FOO_1.0.
+        1, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
+        29, 0, 0, 0, 8, 0, 0, 0,
+        13, 0, 0, 0, 8, 0, 0, 0,
+        21, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let section = object.add_section(Vec::new(), b".gnu.version_d".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(verdef, 4);
+    let mut bytes = object.write().unwrap();
+
+    // Set the section type and links, which can't be specified when writing.
+    let (strings_index, section_index, shoff) = {
+        let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+        (
+            object.section_by_name(".dynstr").unwrap().index().0,
+            object.section_by_name(".gnu.version_d").unwrap().index().0,
+            object.raw_header().e_shoff(LittleEndian) as usize,
+        )
+    };
+    let offset = shoff + section_index * std::mem::size_of::<elf::SectionHeader64<LittleEndian>>();
+    let (header, _) =
+        object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..]).unwrap();
+    header.sh_type.set(LittleEndian, elf::SHT_GNU_VERDEF);
+    header.sh_link.set(LittleEndian, strings_index as u32);
+    header.sh_info.set(LittleEndian, 3);
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let definitions = object.version_definitions().unwrap();
+    assert_eq!(definitions.len(), 3);
+    assert!(definitions[0].is_base());
+    assert_eq!(definitions[0].index(), 1);
+    assert_eq!(definitions[0].version_name(), b"libfoo.so.1");
+    assert_eq!(definitions[0].parent(), None);
+    assert!(!definitions[1].is_base());
+    assert_eq!(definitions[1].index(), 2);
+    assert_eq!(definitions[1].version_name(), b"FOO_2.0");
+    assert_eq!(definitions[1].parent(), Some(&b"FOO_1.0"[..]));
+    assert_eq!(definitions[2].index(), 3);
+    assert_eq!(definitions[2].version_name(), b"FOO_3.0");
+    assert_eq!(definitions[2].parent(), Some(&b"FOO_2.0"[..]));
+    assert_eq!(
+        definitions[2].parents().collect::<Vec<_>>(),
+        vec![&b"FOO_2.0"[..], &b"FOO_1.0"[..]]
+    );
+}
+
+#[test]
+fn gnu_hash_exports() {
+    use object::read::ObjectSection;
+    use object::SymbolIndex;
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    #[rustfmt::skip]
+    let hash = vec![
+        // Header: 2 buckets, symbol base 1, 1 bloom word, bloom shift 6.
+        2, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0,
+        // Bloom filter.
+        0, 0, 0, 0, 0, 0, 0, 0,
+        // Buckets.
+        1, 0, 0, 0, 3, 0, 0, 0,
+        // Chains for symbols 1 to 3.
+        0x10, 0, 0, 0, 0x11, 0, 0, 0, 0x21, 0, 0, 0,
+    ];
+    let section = object.add_section(Vec::new(), b".gnu.hash".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(hash, 8);
+    let mut bytes = object.write().unwrap();
+
+    // Set the section type, which can't be specified when writing.
+    let (section_index, shoff) = {
+        let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+        assert_eq!(object.gnu_hash_exports(), Ok(None));
+        (
+            object.section_by_name(".gnu.hash").unwrap().index().0,
+            object.raw_header().e_shoff(LittleEndian) as usize,
+        )
+    };
+    let offset = shoff + section_index * std::mem::size_of::<elf::SectionHeader64<LittleEndian>>();
+    let (header, _) =
+        object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..]).unwrap();
+    header.sh_type.set(LittleEndian, elf::SHT_GNU_HASH);
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(
+        object.gnu_hash_exports(),
+        Ok(Some(vec![SymbolIndex(1), SymbolIndex(2), SymbolIndex(3)]))
+    );
+}
+
+#[test]
+fn contains_symbol_gnu_hash() {
+    use object::SymbolIndex;
+
+    let gnu_hash = |name: &[u8]| {
+        name.iter().fold(5381u32, |hash, &c| {
+            hash.wrapping_mul(33).wrapping_add(c.into())
+        })
+    };
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let dynstr = object.add_section(Vec::new(), b".dynstr".to_vec(), SectionKind::Other);
+    object
+        .section_mut(dynstr)
+        .set_data(b"\0foo\0bar\0undef\0".to_vec(), 1);
+    // Symbol 1 is an import, which is not in the hash table.
+    let mut dynsym = vec![0; 24];
+    for &(name, shndx) in &[(9u32, 0u16), (1, 1), (5, 1)] {
+        dynsym.extend_from_slice(&name.to_le_bytes());
+        dynsym.extend_from_slice(&[elf::STT_FUNC | (elf::STB_GLOBAL << 4), 0]);
+        dynsym.extend_from_slice(&shndx.to_le_bytes());
+        dynsym.extend_from_slice(&[0; 16]);
+    }
+    let dynsym_id = object.add_section(Vec::new(), b".dynsym".to_vec(), SectionKind::Other);
+    object.section_mut(dynsym_id).set_data(dynsym, 8);
+    let mut hash = Vec::new();
+    // Header: 1 bucket, symbol base 2, 1 bloom word, bloom shift 6.
+    for &word in &[1u32, 2, 1, 6] {
+        hash.extend_from_slice(&word.to_le_bytes());
+    }
+    hash.extend_from_slice(&[0xff; 8]);
+    hash.extend_from_slice(&2u32.to_le_bytes());
+    hash.extend_from_slice(&(gnu_hash(b"foo") & !1).to_le_bytes());
+    hash.extend_from_slice(&(gnu_hash(b"bar") | 1).to_le_bytes());
+    let section = object.add_section(Vec::new(), b".gnu.hash".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(hash, 8);
+    let mut bytes = object.write().unwrap();
+
+    set_section_types(
+        &mut bytes,
+        &[
+            (".dynsym", elf::SHT_DYNSYM),
+            (".gnu.hash", elf::SHT_GNU_HASH),
+        ],
+    );
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(
+        object.gnu_hash_exports(),
+        Ok(Some(vec![SymbolIndex(2), SymbolIndex(3)]))
+    );
+    assert!(object.contains_symbol("foo"));
+    assert!(object.contains_symbol("bar"));
+    assert!(object.contains_symbol("undef"));
+    assert!(!object.contains_symbol("baz"));
+    assert!(!object.contains_symbol("fo"));
+}
+
+/// Set the types of sections, which can't be specified when writing.
+///
+/// `SHT_DYNSYM` sections are linked to the `.dynstr` section.
+fn set_section_types(bytes: &mut [u8], types: &[(&str, u32)]) {
+    let (indices, dynstr, shoff) = {
+        let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+        let index = |name| object.section_by_name(name).unwrap().index().0;
+        let indices = types
+            .iter()
+            .map(|&(name, sh_type)| (index(name), sh_type))
+            .collect::<Vec<_>>();
+        let dynstr = object.section_by_name(".dynstr").map(|s| s.index().0);
+        (
+            indices,
+            dynstr,
+            object.raw_header().e_shoff(LittleEndian) as usize,
+        )
+    };
+    let size = std::mem::size_of::<elf::SectionHeader64<LittleEndian>>();
+    for (index, sh_type) in indices {
+        let offset = shoff + index * size;
+        let (header, _) =
+            object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..])
+                .unwrap();
+        header.sh_type.set(LittleEndian, sh_type);
+        if sh_type == elf::SHT_DYNSYM {
+            header.sh_link.set(LittleEndian, dynstr.unwrap() as u32);
+            header.sh_entsize.set(LittleEndian, 24);
+        }
+    }
+}
+
+#[test]
+fn is_symbol_exported() {
+    use object::SymbolIndex;
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let dynstr = object.add_section(Vec::new(), b".dynstr".to_vec(), SectionKind::Other);
+    object.section_mut(dynstr).set_data(b"\0sym\0".to_vec(), 1);
+    let global = elf::STT_FUNC | (elf::STB_GLOBAL << 4);
+    let local = elf::STT_FUNC | (elf::STB_LOCAL << 4);
+    // (st_info, st_other, st_shndx, versym)
+    let symbols = [
+        (global, elf::STV_DEFAULT, 1u16, 2u16),
+        (global, elf::STV_DEFAULT, 1, elf::VER_NDX_LOCAL),
+        (global, elf::STV_HIDDEN, 1, 2),
+        (global, elf::STV_DEFAULT, 0, 2),
+        (global, elf::STV_PROTECTED, 1, elf::VERSYM_HIDDEN | 2),
+        (local, elf::STV_DEFAULT, 1, elf::VER_NDX_GLOBAL),
+    ];
+    let mut dynsym = vec![0; 24];
+    let mut versym = vec![0; 2];
+    for &(st_info, st_other, st_shndx, version) in &symbols {
+        dynsym.extend_from_slice(&1u32.to_le_bytes());
+        dynsym.extend_from_slice(&[st_info, st_other]);
+        dynsym.extend_from_slice(&st_shndx.to_le_bytes());
+        dynsym.extend_from_slice(&[0; 16]);
+        versym.extend_from_slice(&version.to_le_bytes());
+    }
+    let section = object.add_section(Vec::new(), b".dynsym".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(dynsym, 8);
+    let section = object.add_section(Vec::new(), b".gnu.version".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(versym, 2);
+    let mut bytes = object.write().unwrap();
+    set_section_types(
+        &mut bytes,
+        &[
+            (".dynsym", elf::SHT_DYNSYM),
+            (".gnu.version", elf::SHT_GNU_VERSYM),
+        ],
+    );
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let exported = (0..=symbols.len() + 1)
+        .map(|index| object.is_symbol_exported(SymbolIndex(index)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        exported,
+        [false, true, false, false, false, true, false, false]
+    );
+}
+
+#[test]
+fn read_implicit_addend() {
+    use object::read::ObjectSection;
+    use object::{RelocationEncoding, RelocationKind};
+
+    let mut object = write::Object::new(BinaryFormat::Elf, Architecture::I386, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 16], 4);
+    let symbol = object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+    let relocations = [
+        (0, RelocationKind::Absolute, 32, 0x1234),
+        (4, RelocationKind::Relative, 32, -4),
+        (8, RelocationKind::PltRelative, 32, -0x1000),
+    ];
+    for &(offset, kind, size, addend) in &relocations {
+        object
+            .add_relocation(
+                text,
+                write::Relocation {
+                    offset,
+                    size,
+                    kind,
+                    encoding: RelocationEncoding::Generic,
+                    symbol,
+                    addend,
+                },
+            )
+            .unwrap();
+    }
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name(".text").unwrap();
+    let data = section.data().unwrap();
+    let addends = section
+        .relocations()
+        .map(|(offset, relocation)| {
+            assert!(relocation.has_implicit_addend());
+            assert_eq!(relocation.addend(), 0);
+            relocation
+                .read_implicit_addend(data, offset, Endianness::Little)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(addends, [0x1234, -4, -0x1000]);
+
+    let relocation = section.relocations().next().unwrap().1;
+    assert!(relocation
+        .read_implicit_addend(data, 14, Endianness::Little)
+        .is_err());
+}
+
+#[test]
+fn df_flags() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let mut dynamic = Vec::new();
+    for &(tag, val) in &[
+        (elf::DT_FLAGS, elf::DF_BIND_NOW | elf::DF_TEXTREL),
+        (elf::DT_FLAGS_1, elf::DF_1_NOW | elf::DF_1_PIE),
+        (elf::DT_NULL, 0),
+        (elf::DT_FLAGS, elf::DF_ORIGIN),
+    ] {
+        dynamic.extend_from_slice(&u64::from(tag).to_le_bytes());
+        dynamic.extend_from_slice(&u64::from(val).to_le_bytes());
+    }
+    let section = object.add_section(Vec::new(), b".dynamic".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(dynamic, 8);
+    let mut bytes = object.write().unwrap();
+    set_section_types(&mut bytes, &[(".dynamic", elf::SHT_DYNAMIC)]);
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let flags = object.df_flags().unwrap();
+    assert_eq!(flags.bits(), elf::DF_BIND_NOW | elf::DF_TEXTREL);
+    assert!(flags.bind_now());
+    assert!(flags.textrel());
+    assert!(!flags.origin());
+    let flags_1 = object.df_flags_1().unwrap();
+    assert!(flags_1.now());
+    assert!(flags_1.pie());
+    assert!(!flags_1.nodelete());
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.df_flags().unwrap().bits(), 0);
+    assert_eq!(object.df_flags_1().unwrap().bits(), 0);
+}
+
+#[test]
+fn dynamic_section_kinds() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    for name in &[".dynstr", ".dynsym", ".dynamic", ".hash", ".gnu.version"] {
+        let section = object.add_section(Vec::new(), name.as_bytes().to_vec(), SectionKind::Other);
+        object.section_mut(section).set_data(vec![0; 24], 8);
+    }
+    let mut bytes = object.write().unwrap();
+    set_section_types(
+        &mut bytes,
+        &[
+            (".dynstr", elf::SHT_STRTAB),
+            (".dynsym", elf::SHT_DYNSYM),
+            (".dynamic", elf::SHT_DYNAMIC),
+            (".hash", elf::SHT_HASH),
+            (".gnu.version", elf::SHT_GNU_VERSYM),
+        ],
+    );
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    for &(name, kind) in &[
+        (".dynstr", SectionKind::Metadata),
+        (".dynsym", SectionKind::Metadata),
+        (".dynamic", SectionKind::DynamicLinkInfo),
+        (".hash", SectionKind::DynamicLinkInfo),
+        (".gnu.version", SectionKind::DynamicLinkInfo),
+    ] {
+        assert_eq!(
+            object.section_by_name(name).unwrap().kind(),
+            kind,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn init_array_section_kinds() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    for &(name, kind) in &[
+        (".init_array", SectionKind::Data),
+        (".fini_array", SectionKind::Data),
+        (".preinit_array", SectionKind::ReadOnlyData),
+    ] {
+        let section = object.add_section(Vec::new(), name.as_bytes().to_vec(), kind);
+        object.section_mut(section).set_data(vec![0; 8], 8);
+    }
+    let mut bytes = object.write().unwrap();
+    set_section_types(
+        &mut bytes,
+        &[
+            (".init_array", elf::SHT_INIT_ARRAY),
+            (".fini_array", elf::SHT_FINI_ARRAY),
+            (".preinit_array", elf::SHT_PREINIT_ARRAY),
+        ],
+    );
+
+    // These are classified using their flags, in the same way as `SHT_PROGBITS`.
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    for &(name, kind) in &[
+        (".init_array", SectionKind::Data),
+        (".fini_array", SectionKind::Data),
+        (".preinit_array", SectionKind::ReadOnlyData),
+    ] {
+        assert_eq!(
+            object.section_by_name(name).unwrap().kind(),
+            kind,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn dynamic_relocation_count() {
+    let build = |jmprel: u64| {
+        let mut object =
+            write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        let mut dynamic = Vec::new();
+        for &(tag, val) in &[
+            (elf::DT_RELA, 0x1000),
+            (elf::DT_RELASZ, 5 * 24),
+            (elf::DT_RELAENT, 24),
+            (elf::DT_JMPREL, jmprel),
+            (elf::DT_PLTRELSZ, 2 * 24),
+            (elf::DT_PLTREL, u64::from(elf::DT_RELA)),
+            (elf::DT_RELR, 0),
+            (elf::DT_RELRSZ, 24),
+            (elf::DT_RELRENT, 8),
+            (elf::DT_NULL, 0),
+        ] {
+            dynamic.extend_from_slice(&u64::from(tag).to_le_bytes());
+            dynamic.extend_from_slice(&val.to_le_bytes());
+        }
+        let section = object.add_section(Vec::new(), b".dynamic".to_vec(), SectionKind::Other);
+        object.section_mut(section).set_data(dynamic, 8);
+        // An address, a bitmap with 2 relocations, and another address.
+        let mut relr = Vec::new();
+        for &word in &[0x10000u64, 0b1011, 0x20000] {
+            relr.extend_from_slice(&word.to_le_bytes());
+        }
+        let section = object.add_section(Vec::new(), b".relr.dyn".to_vec(), SectionKind::Other);
+        object.section_mut(section).set_data(relr, 8);
+        let mut bytes = object.write().unwrap();
+        set_section_types(
+            &mut bytes,
+            &[(".dynamic", elf::SHT_DYNAMIC), (".relr.dyn", elf::SHT_RELR)],
+        );
+        bytes
+    };
+
+    let bytes = build(0x2000);
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.dynamic_relocation_count().unwrap(), 5 + 2 + 4);
+
+    // The PLT relocations are included in the `DT_RELA` table.
+    let bytes = build(0x1000 + 3 * 24);
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.dynamic_relocation_count().unwrap(), 5 + 4);
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.dynamic_relocation_count().unwrap(), 0);
+}
+
+#[test]
+fn relr_relocations() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let mut dynamic = Vec::new();
+    for &(tag, val) in &[
+        (elf::DT_RELR, 0u64),
+        (elf::DT_RELRSZ, 48),
+        (elf::DT_RELRENT, 8),
+        (elf::DT_NULL, 0),
+    ] {
+        dynamic.extend_from_slice(&u64::from(tag).to_le_bytes());
+        dynamic.extend_from_slice(&val.to_le_bytes());
+    }
+    let section = object.add_section(Vec::new(), b".dynamic".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(dynamic, 8);
+    let mut relr = Vec::new();
+    for &word in &[0x10000u64, 0b1011, 0x20000, 0x30000, 1 | (1 << 63), 0b101] {
+        relr.extend_from_slice(&word.to_le_bytes());
+    }
+    let section = object.add_section(Vec::new(), b".relr.dyn".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(relr, 8);
+    let mut bytes = object.write().unwrap();
+    set_section_types(
+        &mut bytes,
+        &[(".dynamic", elf::SHT_DYNAMIC), (".relr.dyn", elf::SHT_RELR)],
+    );
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let relocations = object
+        .relr_relocations()
+        .unwrap()
+        .unwrap()
+        .collect::<Vec<_>>();
+    let offsets = relocations
+        .iter()
+        .map(|(offset, _)| *offset)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        offsets,
+        [0x10000, 0x10008, 0x10018, 0x20000, 0x30000, 0x301f8, 0x30208]
+    );
+    for (_, relocation) in &relocations {
+        assert_eq!(
+            relocation.kind(),
+            RelocationKind::Elf(elf::R_X86_64_RELATIVE)
+        );
+        assert_eq!(relocation.size(), 64);
+        assert_eq!(relocation.target(), read::RelocationTarget::Absolute);
+        assert!(relocation.has_implicit_addend());
+    }
+    assert_eq!(object.dynamic_relocation_count().unwrap(), 7);
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert!(object.relr_relocations().unwrap().is_none());
+}
+
+#[test]
+fn ctf_header() {
+    // A big endian CTFv3 dictionary with a parent.
+    let mut dict = vec![0xdf, 0xf2, 3, 0x1];
+    dict.extend_from_slice(&0u32.to_be_bytes());
+    dict.extend_from_slice(&1u32.to_be_bytes());
+    dict.extend_from_slice(&[0; 24]);
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(Vec::new(), b".ctf".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(dict.clone(), 4);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.ctf_data().unwrap(), Some(&dict[..]));
+    let header = object.ctf_header().unwrap().unwrap();
+    assert!(!header.is_archive());
+    assert_eq!(header.version(), 3);
+    assert!(header.is_compressed());
+    assert!(header.has_parent());
+
+    // A little endian CTF archive containing a dictionary without a parent.
+    let mut archive = Vec::new();
+    archive.extend_from_slice(&0x8b47_f2a4_d762_3eebu64.to_le_bytes());
+    archive.extend_from_slice(&8u64.to_le_bytes());
+    archive.extend_from_slice(&1u64.to_le_bytes());
+    archive.extend_from_slice(&0u64.to_le_bytes());
+    archive.extend_from_slice(&40u64.to_le_bytes());
+    archive.extend_from_slice(&36u64.to_le_bytes());
+    archive.extend_from_slice(&[0xf2, 0xdf, 2, 0]);
+    archive.extend_from_slice(&[0; 32]);
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(Vec::new(), b".SUNW_ctf".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(archive, 8);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let header = object.ctf_header().unwrap().unwrap();
+    assert!(header.is_archive());
+    assert_eq!(header.version(), 2);
+    assert!(!header.is_compressed());
+    assert!(!header.has_parent());
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.ctf_data().unwrap(), None);
+    assert_eq!(object.ctf_header().unwrap(), None);
+}
+
+#[test]
+fn validate() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    let symbol = object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    object
+        .add_relocation(
+            text,
+            write::Relocation {
+                offset: 8,
+                size: 32,
+                kind: RelocationKind::Absolute,
+                encoding: RelocationEncoding::Generic,
+                symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+    let bytes = object.write().unwrap();
+    let file = read::File::parse(&*bytes).unwrap();
+    assert_eq!(file.validate(), Vec::new());
+
+    // Point the symbol at a section that does not exist.
+    let (symtab_offset, _) = file
+        .section_by_name(".symtab")
+        .unwrap()
+        .file_range()
+        .unwrap();
+    let index = file
+        .symbols()
+        .find(|s| s.name() == Ok("func"))
+        .unwrap()
+        .index();
+    let offset = symtab_offset as usize + index.0 * 24 + 6;
+    let mut corrupt = bytes.clone();
+    corrupt[offset..offset + 2].copy_from_slice(&100u16.to_le_bytes());
+    let file = read::File::parse(&*corrupt).unwrap();
+    assert_eq!(
+        file.validate(),
+        vec![read::ValidationIssue::SymbolSectionIndex {
+            symbol: index,
+            section: read::SectionIndex(100),
+        }]
+    );
+
+    // Set `e_shstrndx` to a section that does not exist.
+    let mut corrupt = bytes.clone();
+    corrupt[62..64].copy_from_slice(&200u16.to_le_bytes());
+    let mut errors = Vec::new();
+    let file = read::elf::ElfFile64::<LittleEndian>::parse_lenient(&*corrupt, &mut errors).unwrap();
+    assert!(!errors.is_empty());
+    assert_eq!(
+        file.validate(),
+        vec![read::ValidationIssue::SectionStringTableIndex(200)]
+    );
+}
+
+#[test]
+fn entry_symbol() {
+    for &(architecture, value) in &[
+        (Architecture::X86_64, 8),
+        // The entry point and function symbol have the Thumb bit set.
+        (Architecture::Arm, 9),
+    ] {
+        let mut object = write::Object::new(BinaryFormat::Elf, architecture, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0; 16], 4);
+        for &(name, value) in &[(&b"other"[..], 0), (&b"_start"[..], value)] {
+            object.add_symbol(write::Symbol {
+                name: name.to_vec(),
+                value,
+                size: 0,
+                kind: SymbolKind::Text,
+                scope: SymbolScope::Linkage,
+                weak: false,
+                section: write::SymbolSection::Section(text),
+                flags: SymbolFlags::None,
+            });
+        }
+        let mut bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        assert!(object.entry_symbol().is_none());
+
+        // Set `e_entry`.
+        for &(entry, expected) in &[(value, Some("_start")), (4, None)] {
+            if architecture == Architecture::Arm {
+                bytes[24..28].copy_from_slice(&(entry as u32).to_le_bytes());
+            } else {
+                bytes[24..32].copy_from_slice(&entry.to_le_bytes());
+            }
+            let object = read::File::parse(&*bytes).unwrap();
+            assert_eq!(object.entry(), entry);
+            let name = object.entry_symbol().map(|symbol| symbol.name().unwrap());
+            assert_eq!(name, expected, "{:?} 0x{:x}", architecture, entry);
+        }
+    }
+}
+
+#[test]
+fn parse_lenient() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    let bytes = object.write().unwrap();
+    let (object, errors) = read::File::parse_lenient(&*bytes);
+    assert!(object.unwrap().sections().count() != 0);
+    assert!(errors.is_empty());
+
+    // Truncate the file before the section headers.
+    let header = elf::FileHeader64::<LittleEndian>::parse(&*bytes).unwrap();
+    let shoff = header.e_shoff(LittleEndian) as usize;
+    let bytes = &bytes[..shoff];
+    assert!(read::File::parse(bytes).is_err());
+    let (object, errors) = read::File::parse_lenient(bytes);
+    assert_eq!(object.unwrap().sections().count(), 0);
+    assert!(!errors.is_empty());
+}
+
+#[test]
+fn relocation_symbol_table() {
+    use object::read::ObjectSymbolTable;
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 16], 4);
+    let symbol = object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+    object
+        .add_relocation(
+            text,
+            write::Relocation {
+                offset: 8,
+                size: 64,
+                kind: RelocationKind::Absolute,
+                encoding: RelocationEncoding::Generic,
+                symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+
+    // A relocation section for `.text` that uses the dynamic symbol table.
+    let dynstr = object.add_section(Vec::new(), b".dynstr".to_vec(), SectionKind::Other);
+    object.section_mut(dynstr).set_data(b"\0bar\0".to_vec(), 1);
+    let mut dynsym = vec![0; 24];
+    dynsym.extend_from_slice(&1u32.to_le_bytes());
+    dynsym.extend_from_slice(&[elf::STT_FUNC | (elf::STB_GLOBAL << 4), 0, 0, 0]);
+    dynsym.extend_from_slice(&[0; 16]);
+    let section = object.add_section(Vec::new(), b".dynsym".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(dynsym, 8);
+    let mut rela = Vec::new();
+    rela.extend_from_slice(&4u64.to_le_bytes());
+    rela.extend_from_slice(&((1u64 << 32) | u64::from(elf::R_X86_64_JUMP_SLOT)).to_le_bytes());
+    rela.extend_from_slice(&0u64.to_le_bytes());
+    let section = object.add_section(
+        Vec::new(),
+        b".rela.plt".to_vec(),
+        SectionKind::Elf(elf::SHT_RELA),
+    );
+    object.section_mut(section).set_data(rela, 8);
+    let mut bytes = object.write().unwrap();
+    set_section_types(&mut bytes, &[(".dynsym", elf::SHT_DYNSYM)]);
+    let (dynsym_index, rela_index, text_index, shoff) = {
+        let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+        let index = |name| object.section_by_name(name).unwrap().index().0;
+        (
+            index(".dynsym"),
+            index(".rela.plt"),
+            index(".text"),
+            object.raw_header().e_shoff(LittleEndian) as usize,
+        )
+    };
+    let size = std::mem::size_of::<elf::SectionHeader64<LittleEndian>>();
+    let offset = shoff + rela_index * size;
+    let (header, _) =
+        object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..]).unwrap();
+    header.sh_link.set(LittleEndian, dynsym_index as u32);
+    header.sh_info.set(LittleEndian, text_index as u32);
+    header.sh_entsize.set(LittleEndian, 24);
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let symtab = object.section_by_name(".symtab").unwrap();
+    let rela = object.section_by_name(".rela.text").unwrap();
+    assert_eq!(rela.relocation_symbol_table(), Some(symtab.index()));
+    let rela = object.section_by_name(".rela.plt").unwrap();
+    assert_eq!(
+        rela.relocation_symbol_table(),
+        Some(object::SectionIndex(dynsym_index))
+    );
+    let text = object.section_by_name(".text").unwrap();
+    assert_eq!(text.relocation_symbol_table(), None);
+
+    let mut relocations = text.relocations().collect::<Vec<_>>();
+    relocations.sort_by_key(|(offset, _)| *offset);
+    assert_eq!(relocations.len(), 2);
+    let (offset, ref relocation) = relocations[0];
+    assert_eq!(offset, 4);
+    let index = match relocation.target() {
+        read::RelocationTarget::DynamicSymbol(index) => index,
+        target => panic!("unexpected target {:?}", target),
+    };
+    let dynamic_symbols = object.dynamic_symbol_table().unwrap();
+    let symbol = dynamic_symbols.symbol_by_index(index).unwrap();
+    assert_eq!(symbol.name(), Ok("bar"));
+    let (offset, ref relocation) = relocations[1];
+    assert_eq!(offset, 8);
+    let index = match relocation.target() {
+        read::RelocationTarget::Symbol(index) => index,
+        target => panic!("unexpected target {:?}", target),
+    };
+    assert_eq!(object.symbol_by_index(index).unwrap().name(), Ok("func"));
+    assert_eq!(object.validate(), Vec::new());
+}
+
+#[test]
+fn symbol_string_table() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.dynamic_symbol_string_table(), None);
+    let strings = object.symbol_string_table().unwrap();
+    assert!(strings.windows(6).any(|s| s == b"\0func\0"));
+}
+
+#[test]
+fn usdt_probes() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let strings = b"prov\0probe\0-4@%edi\0";
+    let mut note = Vec::new();
+    note.extend_from_slice(&8u32.to_le_bytes());
+    note.extend_from_slice(&(24 + strings.len() as u32).to_le_bytes());
+    note.extend_from_slice(&elf::NT_STAPSDT.to_le_bytes());
+    note.extend_from_slice(b"stapsdt\0");
+    note.extend_from_slice(&0x1000u64.to_le_bytes());
+    note.extend_from_slice(&0x2000u64.to_le_bytes());
+    note.extend_from_slice(&0x3000u64.to_le_bytes());
+    note.extend_from_slice(strings);
+    while note.len() % 4 != 0 {
+        note.push(0);
+    }
+    let section = object.add_section(Vec::new(), b".note.stapsdt".to_vec(), SectionKind::Note);
+    object.section_mut(section).set_data(note, 4);
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let probes = object.usdt_probes().unwrap();
+    assert_eq!(probes.len(), 1);
+    assert_eq!(probes[0].provider(), b"prov");
+    assert_eq!(probes[0].name(), b"probe");
+    assert_eq!(probes[0].location(), 0x1000);
+    assert_eq!(probes[0].base(), 0x2000);
+    assert_eq!(probes[0].semaphore(), 0x3000);
+    assert_eq!(probes[0].arguments(), b"-4@%edi");
+}
+
+#[test]
+fn go_build_id() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let build_id = b"abc/def/ghi/jkl";
+    let mut note = Vec::new();
+    note.extend_from_slice(&4u32.to_le_bytes());
+    note.extend_from_slice(&(build_id.len() as u32).to_le_bytes());
+    note.extend_from_slice(&elf::NT_GO_BUILD_ID.to_le_bytes());
+    note.extend_from_slice(b"Go\0\0");
+    note.extend_from_slice(build_id);
+    while note.len() % 4 != 0 {
+        note.push(0);
+    }
+    let section = object.add_section(Vec::new(), b".note.go.buildid".to_vec(), SectionKind::Note);
+    object.section_mut(section).set_data(note, 4);
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.go_build_id().unwrap(), Some(&build_id[..]));
+    assert_eq!(object.build_id().unwrap(), None);
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.go_build_id().unwrap(), None);
+}
+
+#[test]
+fn abi_tag() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let mut note = Vec::new();
+    note.extend_from_slice(&4u32.to_le_bytes());
+    note.extend_from_slice(&16u32.to_le_bytes());
+    note.extend_from_slice(&elf::NT_GNU_ABI_TAG.to_le_bytes());
+    note.extend_from_slice(b"GNU\0");
+    for &word in &[elf::ELF_NOTE_OS_LINUX, 3, 2, 0] {
+        note.extend_from_slice(&word.to_le_bytes());
+    }
+    let section = object.add_section(Vec::new(), b".note.ABI-tag".to_vec(), SectionKind::Note);
+    object.section_mut(section).set_data(note, 4);
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let tag = object.abi_tag().unwrap().unwrap();
+    assert_eq!(tag.os(), elf::ELF_NOTE_OS_LINUX);
+    assert_eq!(tag.os_kind(), read::elf::AbiTagOs::Linux);
+    assert_eq!((tag.major(), tag.minor(), tag.patch()), (3, 2, 0));
+    assert!(tag.version() < (4, 0, 0));
+    assert_eq!(object.build_id().unwrap(), None);
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.abi_tag().unwrap(), None);
+}
+
+#[test]
+fn gnu_debugdata() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let data = b"\xfd7zXZ\0\0\x04\xe6\xd6\xb4\x46".to_vec();
+    let section = object.add_section(Vec::new(), b".gnu_debugdata".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(data.clone(), 1);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.gnu_debugdata().unwrap(), Some(&data[..]));
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(Vec::new(), b".gnu_debugdata".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(vec![0; 16], 1);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert!(object.gnu_debugdata().is_err());
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.gnu_debugdata().unwrap(), None);
+}
+
+#[cfg(feature = "xz")]
+#[test]
+fn mini_debug_info() {
+    let mut debug = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = debug.add_section(
+        Vec::new(),
+        b".text".to_vec(),
+        SectionKind::UninitializedData,
+    );
+    debug.section_mut(text).append_bss(0x20, 16);
+    debug.add_symbol(write::Symbol {
+        name: b"stripped".to_vec(),
+        value: 0x10,
+        size: 0x10,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Compilation,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let debug = debug.write().unwrap();
+    let mut compressed = Vec::new();
+    lzma_rs::xz_compress(&mut &*debug, &mut compressed).unwrap();
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(Vec::new(), b".gnu_debugdata".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(compressed, 1);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let data = object.mini_debug_info().unwrap().unwrap();
+    assert_eq!(data, debug);
+    let debug = read::File::parse(&*data).unwrap();
+    let symbol = debug.symbol_by_name("stripped").unwrap();
+    assert_eq!(symbol.address(), 0x10);
+    assert_eq!(symbol.size(), 0x10);
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let data = b"\xfd7zXZ\0\0\x04\xe6\xd6\xb4\x46".to_vec();
+    let section = object.add_section(Vec::new(), b".gnu_debugdata".to_vec(), SectionKind::Other);
+    object.section_mut(section).set_data(data, 1);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert!(object.mini_debug_info().is_err());
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    assert_eq!(object.mini_debug_info().unwrap(), None);
+}
+
+#[test]
+fn elf_patch() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    for &name in &[&b"func"[..], &b"other"[..]] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+    let index = |bytes: &[u8], name: &str| {
+        let object = read::File::parse(bytes).unwrap();
+        let symbol = object.symbols().find(|s| s.name() == Ok(name)).unwrap();
+        symbol.index()
+    };
+    let func = index(&bytes, "func");
+    let other = index(&bytes, "other");
+
+    let mut patch = write::ElfPatch::<elf::FileHeader64<LittleEndian>>::new(bytes.clone()).unwrap();
+    patch.set_symbol_address(func, 0x1234).unwrap();
+    // Longer than the current name.
+    assert!(patch.set_symbol_name(func, b"function").is_err());
+    // Overwrites the current name.
+    patch.set_symbol_name(other, b"oth").unwrap();
+    // Refers to the existing string.
+    patch.set_symbol_name(func, b"th").unwrap();
+    let patched = patch.into_data();
+    assert_eq!(patched.len(), bytes.len());
+
+    let object = read::File::parse(&*patched).unwrap();
+    let func = object.symbol_by_index(func).unwrap();
+    assert_eq!(func.name(), Ok("th"));
+    assert_eq!(func.address(), 0x1234);
+    let other = object.symbol_by_index(other).unwrap();
+    assert_eq!(other.name(), Ok("oth"));
+    assert_eq!(other.address(), 0);
+    assert_eq!(
+        object.section_by_name(".text").unwrap().data(),
+        Ok(&[0xc3; 16][..])
+    );
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::I386, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let mut patch = write::ElfPatch::<elf::FileHeader32<LittleEndian>>::new(bytes).unwrap();
+    assert!(patch
+        .set_symbol_address(read::SymbolIndex(0), 0x1_0000_0000)
+        .is_err());
+    assert!(patch.set_symbol_address(read::SymbolIndex(1), 0).is_err());
+    assert!(write::ElfPatch::<elf::FileHeader64<LittleEndian>>::new(patch.into_data()).is_err());
+}
+
+#[test]
+fn elf_patch_tail_merged() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    for &name in &[&b"foo_bar"[..], &b"bar"[..]] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let foo_bar = object.symbol_by_name("foo_bar").unwrap().index();
+    let bar = object.symbol_by_name("bar").unwrap().index();
+
+    // The string table stores "bar" as the tail of "foo_bar".
+    let mut patch = write::ElfPatch::<elf::FileHeader64<LittleEndian>>::new(bytes.clone()).unwrap();
+    assert!(patch.set_symbol_name(bar, b"baz").is_err());
+    assert!(patch.set_symbol_name(foo_bar, b"qux").is_err());
+    assert_eq!(patch.into_data(), bytes);
+}
+
+#[test]
+fn plt_entries() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let plt = object.add_section(Vec::new(), b".plt".to_vec(), SectionKind::Text);
+    object.section_mut(plt).set_data(vec![0; 64], 16);
+    let mut rela = Vec::new();
+    for &(offset, r_type, r_sym) in &[
+        (0x1018u64, elf::R_X86_64_JUMP_SLOT, 1u64),
+        (0x1020, elf::R_X86_64_IRELATIVE, 0),
+        (0x1028, elf::R_X86_64_JUMP_SLOT, 2),
+    ] {
+        rela.extend_from_slice(&offset.to_le_bytes());
+        rela.extend_from_slice(&((r_sym << 32) | u64::from(r_type)).to_le_bytes());
+        rela.extend_from_slice(&0u64.to_le_bytes());
+    }
+    let section = object.add_section(
+        Vec::new(),
+        b".rela.plt".to_vec(),
+        SectionKind::Elf(elf::SHT_RELA),
+    );
+    object.section_mut(section).set_data(rela, 8);
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let entries = object.plt_entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].address(), 16);
+    assert_eq!(entries[0].got_address(), 0x1018);
+    assert_eq!(entries[0].symbol(), object::SymbolIndex(1));
+    assert_eq!(entries[1].address(), 48);
+    assert_eq!(entries[1].got_address(), 0x1028);
+    assert_eq!(entries[1].symbol(), object::SymbolIndex(2));
+}
+
+#[test]
+fn got_entries() {
+    use object::read::ObjectSection;
+
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let got = object.add_section(Vec::new(), b".got".to_vec(), SectionKind::Data);
+    object.section_mut(got).set_data(vec![0; 24], 8);
+    let mut rela = Vec::new();
+    rela.extend_from_slice(&8u64.to_le_bytes());
+    rela.extend_from_slice(&((1u64 << 32) | u64::from(elf::R_X86_64_GLOB_DAT)).to_le_bytes());
+    rela.extend_from_slice(&0u64.to_le_bytes());
+    let section = object.add_section(
+        Vec::new(),
+        b".rela.dyn".to_vec(),
+        SectionKind::Elf(elf::SHT_RELA),
+    );
+    object.section_mut(section).set_data(rela, 8);
+    let mut bytes = object.write().unwrap();
+
+    // Make the symbol table a dynamic symbol table, and link the relocations to it.
+    let (symtab_index, rela_index, shoff) = {
+        let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+        (
+            object.section_by_name(".symtab").unwrap().index().0,
+            object.section_by_name(".rela.dyn").unwrap().index().0,
+            object.raw_header().e_shoff(LittleEndian) as usize,
+        )
+    };
+    let size = std::mem::size_of::<elf::SectionHeader64<LittleEndian>>();
+    let offset = shoff + symtab_index * size;
+    let (header, _) =
+        object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..]).unwrap();
+    header.sh_type.set(LittleEndian, elf::SHT_DYNSYM);
+    let offset = shoff + rela_index * size;
+    let (header, _) =
+        object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..]).unwrap();
+    header.sh_link.set(LittleEndian, symtab_index as u32);
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let entries = object.got_entries().unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].address(), 0);
+    assert_eq!(entries[0].symbol(), None);
+    assert_eq!(entries[1].address(), 8);
+    assert_eq!(entries[1].symbol(), Some(object::SymbolIndex(1)));
+    assert_eq!(entries[2].address(), 16);
+    assert_eq!(entries[2].symbol(), None);
+}
+
+#[test]
+fn eh_frame_hdr() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let mut data = vec![1, 0x1b, 0x03, 0x3b];
+    // eh_frame_ptr: pcrel sdata4.
+    data.extend_from_slice(&(0x1000i32 - 4).to_le_bytes());
+    // fde_count: udata4.
+    data.extend_from_slice(&2u32.to_le_bytes());
+    // table: datarel sdata4.
+    for &value in &[0x100i32, 0x200, 0x300, -0x10] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    let section = object.add_section(
+        Vec::new(),
+        b".eh_frame_hdr".to_vec(),
+        SectionKind::ReadOnlyData,
+    );
+    object.section_mut(section).set_data(data, 4);
+    let bytes = object.write().unwrap();
+
+    let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let hdr = object.eh_frame_hdr().unwrap().unwrap();
+    assert_eq!(hdr.eh_frame_address(), 0x1000);
+    assert_eq!(hdr.table(), &[(0x100, 0x200), (0x300, (-0x10i64) as u64)]);
+    assert_eq!(hdr.find_fde(0xff), None);
+    assert_eq!(hdr.find_fde(0x100), Some(0x200));
+    assert_eq!(hdr.find_fde(0x2ff), Some(0x200));
+    assert_eq!(hdr.find_fde(0x400), Some((-0x10i64) as u64));
+}
+
+#[test]
+fn header() {
+    use object::read::elf::ElfFile64;
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let mut bytes = object.write().unwrap();
+
+    let file = ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let header = file.header().unwrap();
+    assert_eq!(header.class(), elf::ELFCLASS64);
+    assert_eq!(header.data(), elf::ELFDATA2LSB);
+    assert_eq!(header.version(), u32::from(elf::EV_CURRENT));
+    assert_eq!(header.file_type(), elf::ET_REL);
+    assert_eq!(header.machine(), elf::EM_X86_64);
+    assert_eq!(header.entry(), 0);
+    assert_eq!(header.phoff(), 0);
+    assert_eq!(header.phnum(), 0);
+    assert_eq!(header.ehsize(), 64);
+    assert_eq!(header.shentsize(), 64);
+    let shnum = header.shnum();
+    let shstrndx = header.shstrndx();
+    assert!(shnum > 1);
+    assert!(shstrndx != 0);
+
+    // Move the section count and string table index into section 0.
+    let shoff = header.shoff() as usize;
+    {
+        let (raw, _) =
+            object::from_bytes_mut::<elf::FileHeader64<LittleEndian>>(&mut bytes).unwrap();
+        raw.e_shnum.set(LittleEndian, 0);
+        raw.e_shstrndx.set(LittleEndian, elf::SHN_XINDEX);
+        raw.e_phnum.set(LittleEndian, elf::PN_XNUM);
+    }
+    {
+        let (section_0, _) =
+            object::from_bytes_mut::<elf::SectionHeader64<LittleEndian>>(&mut bytes[shoff..])
+                .unwrap();
+        section_0.sh_size.set(LittleEndian, shnum as u64);
+        section_0.sh_link.set(LittleEndian, shstrndx);
+    }
+    let file = ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+    let header = file.header().unwrap();
+    assert_eq!(header.shnum(), shnum);
+    assert_eq!(header.shstrndx(), shstrndx);
+    assert_eq!(header.phnum(), 0);
+
+    // Section accessors must use the extended values too.
+    assert_eq!(file.sections().count(), shnum);
+    assert!(file.section_by_name(".symtab").is_some());
+    assert!(file.section_by_name(".shstrtab").is_some());
+    assert_eq!(file.segments().count(), 0);
+}
@@ -22,3 +22,650 @@ fn issue_286_segment_file_size() {
     assert_eq!(segment.vmsize.get(endian), 30);
     assert_eq!(segment.filesize.get(endian), 30);
 }
+
+#[test]
+fn data_in_code() {
+    use object::read::macho::{DataInCodeKind, MachOFile64};
+
+    let mut bytes = Vec::new();
+    for word in &[
+        // Header.
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_X86_64,
+        macho::CPU_SUBTYPE_X86_64_ALL,
+        macho::MH_OBJECT,
+        1,
+        16,
+        0,
+        0,
+        // Data-in-code load command.
+        macho::LC_DATA_IN_CODE,
+        16,
+        48,
+        16,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    for &(offset, length, kind) in &[
+        (0x100u32, 8u16, macho::DICE_KIND_JUMP_TABLE32 as u16),
+        (0x200, 4, 0x99),
+    ] {
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&length.to_le_bytes());
+        bytes.extend_from_slice(&kind.to_le_bytes());
+    }
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    let entries = file.data_in_code().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].offset(), 0x100);
+    assert_eq!(entries[0].length(), 8);
+    assert_eq!(entries[0].kind(), DataInCodeKind::JumpTable32);
+    assert_eq!(entries[1].offset(), 0x200);
+    assert_eq!(entries[1].kind(), DataInCodeKind::Unknown(0x99));
+}
+
+#[test]
+fn umbrella_info() {
+    use object::read::macho::MachOFile64;
+
+    let mut commands = Vec::new();
+    let mut ncmds = 0;
+    for &(cmd, name) in &[
+        (macho::LC_SUB_FRAMEWORK, &b"Umbrella\0\0\0\0"[..]),
+        (macho::LC_SUB_CLIENT, b"Client\0\0"),
+        (macho::LC_SUB_UMBRELLA, b"SubUmbrella\0"),
+        (macho::LC_SUB_LIBRARY, b"libsub\0\0"),
+    ] {
+        commands.extend_from_slice(&cmd.to_le_bytes());
+        commands.extend_from_slice(&(12 + name.len() as u32).to_le_bytes());
+        commands.extend_from_slice(&12u32.to_le_bytes());
+        commands.extend_from_slice(name);
+        ncmds += 1;
+    }
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_X86_64,
+        macho::CPU_SUBTYPE_X86_64_ALL,
+        macho::MH_DYLIB,
+        ncmds,
+        commands.len() as u32,
+        0,
+        0,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    bytes.extend_from_slice(&commands);
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    let info = file.umbrella_info().unwrap();
+    assert_eq!(info.umbrella(), Some(&b"Umbrella"[..]));
+    assert_eq!(info.clients(), [&b"Client"[..]]);
+    assert_eq!(info.sub_umbrellas(), [&b"SubUmbrella"[..]]);
+    assert_eq!(info.sub_libraries(), [&b"libsub"[..]]);
+}
+
+#[test]
+fn pagezero_size() {
+    use object::read::macho::MachOFile64;
+
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_X86_64,
+        macho::CPU_SUBTYPE_X86_64_ALL,
+        macho::MH_EXECUTE,
+        1,
+        72,
+        0,
+        0,
+        macho::LC_SEGMENT_64,
+        72,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    bytes.extend_from_slice(b"__PAGEZERO\0\0\0\0\0\0");
+    for value in &[0u64, 0x1_0000_0000, 0, 0] {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes.extend_from_slice(&[0; 16]);
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.pagezero_size(), Ok(0x1_0000_0000));
+
+    let object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let bytes = object.write().unwrap();
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.pagezero_size(), Ok(0));
+}
+
+#[test]
+fn memory_size() {
+    use object::read::macho::MachOFile64;
+    use object::read::Object;
+
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_X86_64,
+        macho::CPU_SUBTYPE_X86_64_ALL,
+        macho::MH_EXECUTE,
+        2,
+        144,
+        0,
+        0,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    for &(name, address, size) in &[
+        (b"__TEXT\0\0\0\0\0\0\0\0\0\0", 0x1000u64, 0x1000u64),
+        (b"__DATA\0\0\0\0\0\0\0\0\0\0", 0x4000, 0x2000),
+    ] {
+        for word in &[macho::LC_SEGMENT_64, 72] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(name);
+        for value in &[address, size, 0, 0] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&[0; 16]);
+    }
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.memory_size(), 0x3000);
+    assert_eq!(file.memory_span(), 0x5000);
+    assert_eq!(file.max_segment_alignment(), Some(0x1000));
+
+    let object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let bytes = object.write().unwrap();
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.memory_size(), 0);
+    assert_eq!(file.memory_span(), 0);
+}
+
+#[test]
+fn indirect_symbols() {
+    use object::read::macho::MachOFile64;
+    use object::SymbolIndex;
+
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_X86_64,
+        macho::CPU_SUBTYPE_X86_64_ALL,
+        macho::MH_EXECUTE,
+        1,
+        80,
+        0,
+        0,
+        macho::LC_DYSYMTAB,
+        80,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    // All fields are zero except for indirectsymoff and nindirectsyms.
+    for i in 0..18 {
+        let value: u32 = match i {
+            12 => 112,
+            13 => 3,
+            _ => 0,
+        };
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    for entry in &[5, macho::INDIRECT_SYMBOL_LOCAL, 2] {
+        bytes.extend_from_slice(&entry.to_le_bytes());
+    }
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(
+        file.indirect_symbols().unwrap(),
+        [Some(SymbolIndex(5)), None, Some(SymbolIndex(2))]
+    );
+}
+
+#[test]
+fn header() {
+    use object::read::macho::MachOFile64;
+    use object::read::Object;
+
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_ARM64,
+        macho::CPU_SUBTYPE_ARM64E | macho::CPU_SUBTYPE_PTRAUTH_ABI,
+        macho::MH_EXECUTE,
+        0,
+        0,
+        macho::MH_PIE,
+        0,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.architecture(), Architecture::Aarch64);
+    let header = file.header();
+    assert_eq!(header.magic(), macho::MH_CIGAM_64);
+    assert_eq!(header.cputype(), macho::CPU_TYPE_ARM64);
+    assert_eq!(
+        header.cpusubtype() & !macho::CPU_SUBTYPE_MASK,
+        macho::CPU_SUBTYPE_ARM64E
+    );
+    assert_ne!(header.cpusubtype() & macho::CPU_SUBTYPE_PTRAUTH_ABI, 0);
+    assert_eq!(header.filetype(), macho::MH_EXECUTE);
+    assert_eq!(header.ncmds(), 0);
+    assert_eq!(header.sizeofcmds(), 0);
+    assert_eq!(header.flags(), macho::MH_PIE);
+    assert!(file.is_arm64e());
+}
+
+#[test]
+fn is_arm64e() {
+    use object::read::macho::MachOFile64;
+
+    for &(cputype, cpusubtype, is_arm64e) in &[
+        (macho::CPU_TYPE_ARM64, macho::CPU_SUBTYPE_ARM64_ALL, false),
+        (macho::CPU_TYPE_ARM64, macho::CPU_SUBTYPE_ARM64E, true),
+        (
+            macho::CPU_TYPE_ARM64,
+            macho::CPU_SUBTYPE_ARM64E | macho::CPU_SUBTYPE_PTRAUTH_ABI,
+            true,
+        ),
+        (macho::CPU_TYPE_X86_64, macho::CPU_SUBTYPE_ARM64E, false),
+    ] {
+        let mut bytes = Vec::new();
+        for word in &[
+            macho::MH_MAGIC_64,
+            cputype,
+            cpusubtype,
+            macho::MH_EXECUTE,
+            0,
+            0,
+            0,
+            0,
+        ] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+        assert_eq!(file.is_arm64e(), is_arm64e);
+    }
+}
+
+#[test]
+fn toolchain() {
+    use object::read::macho::MachOFile64;
+    use object::read::Object;
+
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_ARM64,
+        macho::CPU_SUBTYPE_ARM64_ALL,
+        macho::MH_EXECUTE,
+        1,
+        48,
+        0,
+        0,
+        macho::LC_BUILD_VERSION,
+        48,
+        macho::PLATFORM_MACOS,
+        0x000c_0000,
+        0x000c_0300,
+        3,
+        macho::TOOL_CLANG,
+        0x000d_0100,
+        macho::TOOL_SWIFT,
+        0x0005_0500,
+        macho::TOOL_LD,
+        0x0261_0000,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    let toolchain = file.toolchain();
+    assert_eq!(toolchain.compiler(), Some("swift 5.5.0"));
+    assert_eq!(toolchain.language_hint(), Some("Swift"));
+    assert_eq!(toolchain.linker(), Some("ld 609.0.0"));
+}
+
+#[test]
+fn contains_symbol_exports_trie() {
+    use object::read::macho::MachOFile64;
+    use object::read::Object;
+
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_ARM64,
+        macho::CPU_SUBTYPE_ARM64_ALL,
+        macho::MH_EXECUTE,
+        1,
+        16,
+        0,
+        0,
+        macho::LC_DYLD_EXPORTS_TRIE,
+        16,
+        48,
+        24,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    // Root node: edge "_f" to offset 6.
+    bytes.extend_from_slice(&[0, 1, b'_', b'f', 0, 6]);
+    // Edges "oo" and "ee" to offsets 16 and 20.
+    bytes.extend_from_slice(&[0, 2, b'o', b'o', 0, 16, b'e', b'e', 0, 20]);
+    // Terminal nodes with flags and address.
+    bytes.extend_from_slice(&[2, 0, 0x10, 0]);
+    bytes.extend_from_slice(&[2, 0, 0x20, 0]);
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert!(file.contains_symbol("_foo"));
+    assert!(file.contains_symbol("_fee"));
+    assert!(!file.contains_symbol("_f"));
+    assert!(!file.contains_symbol("_fo"));
+    assert!(!file.contains_symbol("_food"));
+    assert!(!file.contains_symbol("_bar"));
+}
+
+#[test]
+fn section_align() {
+    use object::read::{Object, ObjectSection};
+    use std::mem::size_of;
+
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let section = object.section_id(write::StandardSection::Data);
+    object.append_section_data(section, &[1; 16], 16);
+    let mut bytes = object.write().unwrap();
+
+    let file = object::read::File::parse(&*bytes).unwrap();
+    let section = file.section_by_name("__data").unwrap();
+    assert_eq!(section.align(), 16);
+
+    // Set an alignment that is too large for the shift.
+    let offset = size_of::<macho::MachHeader64<Endianness>>()
+        + size_of::<macho::SegmentCommand64<Endianness>>()
+        + 52;
+    bytes[offset..offset + 4].copy_from_slice(&64u32.to_le_bytes());
+    let file = object::read::File::parse(&*bytes).unwrap();
+    let section = file.section_by_name("__data").unwrap();
+    assert_eq!(section.align(), 0);
+}
+
+#[test]
+fn symbol_library() {
+    use object::read::macho::{MachOFile64, SymbolLibrary};
+    use object::SymbolIndex;
+
+    let name = b"/usr/lib/libSystem.B.dylib\0\0\0\0\0\0";
+    let mut bytes = Vec::new();
+    for word in &[
+        macho::MH_MAGIC_64,
+        macho::CPU_TYPE_ARM64,
+        macho::CPU_SUBTYPE_ARM64_ALL,
+        macho::MH_EXECUTE,
+        2,
+        80,
+        macho::MH_TWOLEVEL,
+        0,
+        macho::LC_LOAD_DYLIB,
+        56,
+        24,
+        0,
+        0,
+        0,
+    ] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    bytes.extend_from_slice(name);
+    for word in &[macho::LC_SYMTAB, 24, 112, 6, 208, 4] {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    // (n_type, library ordinal, n_value)
+    for &(n_type, ordinal, n_value) in &[
+        (macho::N_UNDF | macho::N_EXT, 1u8, 0u64),
+        (
+            macho::N_UNDF | macho::N_EXT,
+            macho::DYNAMIC_LOOKUP_ORDINAL,
+            0,
+        ),
+        (macho::N_UNDF | macho::N_EXT, macho::EXECUTABLE_ORDINAL, 0),
+        (macho::N_UNDF | macho::N_EXT, macho::SELF_LIBRARY_ORDINAL, 0),
+        (macho::N_UNDF | macho::N_EXT, 2, 0),
+        // A common symbol, where the ordinal is the alignment.
+        (macho::N_UNDF | macho::N_EXT, 3, 16),
+    ] {
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&[n_type, 0]);
+        bytes.extend_from_slice(&(u16::from(ordinal) << 8).to_le_bytes());
+        bytes.extend_from_slice(&n_value.to_le_bytes());
+    }
+    bytes.extend_from_slice(b"\0_a\0");
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(
+        file.symbol_library(SymbolIndex(0)),
+        Ok(Some(SymbolLibrary::Dylib(b"/usr/lib/libSystem.B.dylib")))
+    );
+    assert_eq!(
+        file.symbol_library(SymbolIndex(1)),
+        Ok(Some(SymbolLibrary::DynamicLookup))
+    );
+    assert_eq!(
+        file.symbol_library(SymbolIndex(2)),
+        Ok(Some(SymbolLibrary::MainExecutable))
+    );
+    assert_eq!(
+        file.symbol_library(SymbolIndex(3)),
+        Ok(Some(SymbolLibrary::SelfImage))
+    );
+    assert!(file.symbol_library(SymbolIndex(4)).is_err());
+    assert_eq!(file.symbol_library(SymbolIndex(5)), Ok(None));
+    assert!(file.symbol_library(SymbolIndex(6)).is_err());
+}
+
+#[test]
+fn objc_class_names() {
+    use object::read::macho::MachOFile64;
+
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let section = object.add_section(
+        b"__TEXT".to_vec(),
+        b"__objc_classname".to_vec(),
+        object::SectionKind::ReadOnlyString,
+    );
+    object.append_section_data(section, b"AppDelegate\0ViewController\0", 1);
+    let bytes = object.write().unwrap();
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(
+        file.objc_class_names().unwrap(),
+        [&b"AppDelegate"[..], &b"ViewController"[..]]
+    );
+
+    let object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let bytes = object.write().unwrap();
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert!(file.objc_class_names().unwrap().is_empty());
+}
+
+#[test]
+fn swift_sections() {
+    use object::read::macho::MachOFile64;
+    use object::read::{Object, ObjectSection};
+
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    let mut types = Vec::new();
+    types.extend_from_slice(&0x10i32.to_le_bytes());
+    types.extend_from_slice(&(-4i32 | 1).to_le_bytes());
+    let section = object.add_section(
+        b"__TEXT".to_vec(),
+        b"__swift5_types".to_vec(),
+        object::SectionKind::ReadOnlyData,
+    );
+    object.append_section_data(section, &types, 4);
+    let section = object.add_section(
+        b"__TEXT".to_vec(),
+        b"__swift5_proto".to_vec(),
+        object::SectionKind::ReadOnlyData,
+    );
+    object.append_section_data(section, &[0; 4], 4);
+    let bytes = object.write().unwrap();
+
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    let sections = file.swift_sections().unwrap();
+    assert_eq!(
+        sections,
+        [
+            (&b"__swift5_types"[..], &types[..]),
+            (&b"__swift5_proto"[..], &[0; 4][..])
+        ]
+    );
+
+    let address = file.section_by_name("__swift5_types").unwrap().address();
+    let types = file.swift5_types().unwrap();
+    assert_eq!(types.len(), 2);
+    assert_eq!(types[0].address(), address + 0x10);
+    assert!(!types[0].is_indirect());
+    assert_eq!(types[1].address(), address);
+    assert_eq!(types[1].kind(), 1);
+    assert!(types[1].is_indirect());
+
+    let object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let bytes = object.write().unwrap();
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert!(file.swift_sections().unwrap().is_empty());
+    assert!(file.swift5_types().unwrap().is_empty());
+}
+
+#[test]
+fn preferred_base() {
+    use object::read::Object;
+    use std::mem::size_of;
+
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    let mut bytes = object.write().unwrap();
+    let file = object::read::File::parse(&*bytes).unwrap();
+    assert_eq!(file.preferred_base(), 0);
+
+    // Set the `vmaddr` of the segment.
+    let offset = size_of::<macho::MachHeader64<Endianness>>() + 24;
+    bytes[offset..offset + 8].copy_from_slice(&0x1_0000_0000u64.to_le_bytes());
+    let file = object::read::File::parse(&*bytes).unwrap();
+    assert_eq!(file.preferred_base(), 0x1_0000_0000);
+    assert_eq!(
+        file.file_address(0x1_0420_0010, 0x1_0420_0000),
+        0x1_0000_0010
+    );
+}
+
+#[test]
+fn entry_symbol() {
+    use object::read::macho::MachOFile64;
+    use object::read::{Object, ObjectSymbol, ValidationIssue};
+
+    let build = |entryoff: u64| {
+        let mut bytes = Vec::new();
+        for word in &[
+            macho::MH_MAGIC_64,
+            macho::CPU_TYPE_X86_64,
+            macho::CPU_SUBTYPE_X86_64_ALL,
+            macho::MH_EXECUTE,
+            3,
+            200,
+            0,
+            0,
+            macho::LC_SEGMENT_64,
+            152,
+        ] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(b"__TEXT\0\0\0\0\0\0\0\0\0\0");
+        for value in &[0x1_0000_0000u64, 0x1000, 0, 0x1000] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        let prot = macho::VM_PROT_READ | macho::VM_PROT_EXECUTE;
+        for word in &[prot, prot, 1, 0] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(b"__text\0\0\0\0\0\0\0\0\0\0__TEXT\0\0\0\0\0\0\0\0\0\0");
+        for value in &[0x1_0000_0080u64, 0x100] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        for word in &[0x80, 0, 0, 0, macho::S_ATTR_PURE_INSTRUCTIONS, 0, 0, 0] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        for word in &[macho::LC_MAIN, 24] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        // The entry point is a file offset.
+        bytes.extend_from_slice(&entryoff.to_le_bytes());
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        for word in &[macho::LC_SYMTAB, 24, 232, 2, 264, 16] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        for &(n_strx, n_value) in &[(1u32, 0x1_0000_0080u64), (9, 0x1_0000_0100)] {
+            bytes.extend_from_slice(&n_strx.to_le_bytes());
+            bytes.extend_from_slice(&[macho::N_SECT | macho::N_EXT, 1, 0, 0]);
+            bytes.extend_from_slice(&n_value.to_le_bytes());
+        }
+        bytes.extend_from_slice(b"\0_helper\0_main\0\0");
+        bytes.resize(0x1000, 0);
+        bytes
+    };
+
+    let bytes = build(0x100);
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert_eq!(file.entry(), 0x100);
+    let symbol = file.entry_symbol().unwrap();
+    assert_eq!(symbol.name(), Ok("_main"));
+    assert_eq!(symbol.address(), 0x1_0000_0100);
+    assert!(file.validate().is_empty());
+
+    // No symbol at the entry point.
+    let bytes = build(0x200);
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert!(file.entry_symbol().is_none());
+
+    // The entry point is not within a segment.
+    let bytes = build(0x2000);
+    let file = MachOFile64::<Endianness>::parse(&*bytes).unwrap();
+    assert!(file.entry_symbol().is_none());
+    assert_eq!(file.validate(), vec![ValidationIssue::EntryPoint(0x2000)]);
+}
@@ -0,0 +1,130 @@
+use object::read::elf::FileHeader;
+use object::read::{Object, ObjectSection, ObjectSymbol, ObjectSymbolTable};
+use object::{read, write};
+use object::{
+    Architecture, BinaryFormat, Endianness, LittleEndian, RelocationEncoding, RelocationKind,
+    SymbolFlags, SymbolKind, SymbolScope,
+};
+
+fn build(format: BinaryFormat) -> Vec<u8> {
+    let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+    object.add_file_symbol(b"file.c".to_vec());
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 32], 16);
+    let data = object.section_id(write::StandardSection::Data);
+    object.append_section_data(data, &[0; 16], 8);
+    let func = object.add_symbol(write::Symbol {
+        name: b"func".to_vec(),
+        value: 0,
+        size: 32,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    object.add_symbol(write::Symbol {
+        name: b"undefined".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Data,
+        scope: SymbolScope::Dynamic,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+    object
+        .add_relocation(
+            data,
+            write::Relocation {
+                offset: 8,
+                size: 64,
+                kind: RelocationKind::Absolute,
+                encoding: RelocationEncoding::Generic,
+                symbol: func,
+                addend: 0,
+            },
+        )
+        .unwrap();
+    object.write().unwrap()
+}
+
+/// Iterate over everything that the section, symbol and relocation iterators yield.
+fn visit(data: &[u8]) {
+    let object = match read::File::parse(data) {
+        Ok(object) => object,
+        Err(_) => return,
+    };
+    for section in object.sections() {
+        let _ = section.name();
+        let _ = section.data();
+        let _ = section.file_range();
+        for (offset, relocation) in section.relocations() {
+            let _ = (offset, relocation.target(), relocation.addend());
+        }
+    }
+    for symbol in object.symbols() {
+        let _ = (symbol.name(), symbol.address(), symbol.section_index());
+    }
+    if let Some(table) = object.symbol_table() {
+        for symbol in table.symbols() {
+            let _ = table.symbol_by_index(symbol.index());
+        }
+    }
+    for symbol in object.dynamic_symbols() {
+        let _ = symbol.name();
+    }
+    if let Some(relocations) = object.dynamic_relocations() {
+        for (offset, relocation) in relocations {
+            let _ = (offset, relocation.target());
+        }
+    }
+}
+
+#[test]
+fn truncated() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let bytes = build(format);
+        for len in 0..bytes.len() {
+            visit(&bytes[..len]);
+        }
+    }
+}
+
+#[test]
+fn corrupted() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let bytes = build(format);
+        for offset in 0..bytes.len() {
+            for &value in &[0, 0x7f, 0xff] {
+                let mut bytes = bytes.clone();
+                bytes[offset] = value;
+                visit(&bytes);
+            }
+        }
+    }
+}
+
+#[test]
+fn relocation_data_out_of_range() {
+    let mut bytes = build(BinaryFormat::Elf);
+    let (offset, count) = {
+        let object = read::elf::ElfFile64::<LittleEndian>::parse(&*bytes).unwrap();
+        let data = object.section_by_name(".data").unwrap();
+        let count = data.relocations().count();
+        let index = object.section_by_name(".rela.data").unwrap().index().0;
+        let shoff = object.raw_header().e_shoff(LittleEndian) as usize;
+        let offset =
+            shoff + index * std::mem::size_of::<object::elf::SectionHeader64<LittleEndian>>();
+        (offset, count)
+    };
+    assert_eq!(count, 1);
+    let (header, _) =
+        object::from_bytes_mut::<object::elf::SectionHeader64<LittleEndian>>(&mut bytes[offset..])
+            .unwrap();
+    header.sh_offset.set(LittleEndian, 0xffff_ffff);
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let data = object.section_by_name(".data").unwrap();
+    assert_eq!(data.relocations().count(), 0);
+}
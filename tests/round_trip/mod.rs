@@ -12,6 +12,8 @@ mod comdat;
 mod common;
 mod elf;
 mod macho;
+mod malformed;
+mod pe;
 mod tls;
 
 #[test]
@@ -111,6 +113,947 @@ fn coff_x86_64() {
     assert_eq!(map.get(func1_offset - 1), None);
 }
 
+#[test]
+fn coff_grouped_sections() {
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+    for name in &[".text$mn", ".data", ".text$di", ".text", ".text$di"] {
+        object.add_section(Vec::new(), name.as_bytes().to_vec(), SectionKind::Text);
+    }
+    let bytes = object.write().unwrap();
+
+    let object = read::coff::CoffFile::<&[u8]>::parse(&*bytes).unwrap();
+    let groups = object.grouped_sections().unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name(), b".text");
+    let sections = groups[0]
+        .sections()
+        .iter()
+        .map(|section| (section.name().unwrap(), section.index().0))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sections,
+        [
+            (".text", 4),
+            (".text$di", 3),
+            (".text$di", 5),
+            (".text$mn", 1)
+        ]
+    );
+    assert_eq!(groups[1].name(), b".data");
+    assert_eq!(groups[1].sections().len(), 1);
+}
+
+#[test]
+fn coff_timestamp() {
+    let object = write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+    let mut bytes = object.write().unwrap();
+    assert_eq!(read::File::parse(&*bytes).unwrap().timestamp(), None);
+
+    // The writer always uses a zero timestamp, so set it directly.
+    bytes[4..8].copy_from_slice(&0x5f00_0000u32.to_le_bytes());
+    assert_eq!(
+        read::File::parse(&*bytes).unwrap().timestamp(),
+        Some(0x5f00_0000)
+    );
+}
+
+#[test]
+fn is_relocatable() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        assert!(object.is_relocatable(), "{:?}", format);
+    }
+
+    // Change the file type of the objects to linked images.
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let mut bytes = object.write().unwrap();
+    bytes[16..18].copy_from_slice(&object::elf::ET_EXEC.to_le_bytes());
+    assert!(!read::File::parse(&*bytes).unwrap().is_relocatable());
+    bytes[16..18].copy_from_slice(&object::elf::ET_DYN.to_le_bytes());
+    assert!(!read::File::parse(&*bytes).unwrap().is_relocatable());
+
+    let object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let mut bytes = object.write().unwrap();
+    bytes[12..16].copy_from_slice(&object::macho::MH_EXECUTE.to_le_bytes());
+    assert!(!read::File::parse(&*bytes).unwrap().is_relocatable());
+
+    let bytes = pe::pe64_with_certificates(&[]);
+    assert!(!read::File::parse(&*bytes).unwrap().is_relocatable());
+}
+
+#[test]
+fn parse_at_offset() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(Vec::new(), b".text".to_vec(), SectionKind::Text);
+    object.append_section_data(section, &[0xc3], 1);
+    let bytes = object.write().unwrap();
+
+    let mut container = vec![0; 100];
+    container.extend_from_slice(&bytes);
+    let object = read::File::parse_at_offset(&container, 100).unwrap();
+    assert_eq!(object.base_offset(), 100);
+    let text = object.section_by_name(".text").unwrap();
+    assert_eq!(text.data().unwrap(), &[0xc3]);
+    let (offset, size) = text.file_range().unwrap();
+    let start = (object.base_offset() + offset) as usize;
+    assert_eq!(&container[start..][..size as usize], &[0xc3]);
+
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.base_offset(), 0);
+    assert!(read::File::parse_at_offset(&container, 101).is_err());
+    assert!(read::File::parse_at_offset(&container, container.len() as u64 + 1).is_err());
+}
+
+#[test]
+fn section_file_range() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0xc3; 4], 4);
+        let bss = object.section_id(write::StandardSection::UninitializedData);
+        object.append_section_bss(bss, 16, 4);
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let text = object.section_by_name(".text").unwrap();
+        let (offset, size) = text.file_range().unwrap();
+        assert_eq!(size, 4, "{:?}", format);
+        assert_eq!(
+            &bytes[offset as usize..][..size as usize],
+            &[0xc3; 4],
+            "{:?}",
+            format
+        );
+        let bss = object.section_by_name(".bss").unwrap();
+        assert_eq!(bss.file_range(), None, "{:?}", format);
+    }
+}
+
+#[test]
+fn file_kind_short() {
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let bytes = object.write().unwrap();
+
+    // The file kind only needs the first 16 bytes.
+    assert!(matches!(
+        read::FileKind::parse(&bytes[..16]),
+        Ok(read::FileKind::Elf64)
+    ));
+    assert!(read::FileKind::parse(&bytes[..15]).is_err());
+    assert!(read::File::parse(&bytes[..16]).is_err());
+}
+
+#[test]
+fn initializers() {
+    for &(format, init, fini) in &[
+        (BinaryFormat::Coff, &b".CRT$XCU"[..], &b".CRT$XTU"[..]),
+        (BinaryFormat::Elf, b".init_array", b".fini_array"),
+        (BinaryFormat::MachO, b"__mod_init_func", b"__mod_term_func"),
+    ] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let segment = object.segment_name(write::StandardSegment::Data).to_vec();
+        let section = object.add_section(segment.clone(), init.to_vec(), SectionKind::Data);
+        let mut data = Vec::new();
+        // Use the high bits to check that the pointer size is correct.
+        for &pointer in &[0x1_0000_1000u64, 0, 0x1_0000_2000] {
+            data.extend_from_slice(&pointer.to_le_bytes());
+        }
+        object.append_section_data(section, &data, 8);
+        let section = object.add_section(segment, fini.to_vec(), SectionKind::Data);
+        object.append_section_data(section, &0x1_0000_3000u64.to_le_bytes(), 8);
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        assert_eq!(
+            object.initializers().unwrap(),
+            [0x1_0000_1000, 0x1_0000_2000],
+            "{:?}",
+            format
+        );
+        assert_eq!(
+            object.finalizers().unwrap(),
+            [0x1_0000_3000],
+            "{:?}",
+            format
+        );
+    }
+
+    // Pointers that are filled in by relocations are 0 in the section data.
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let section = object.add_section(Vec::new(), b".init_array".to_vec(), SectionKind::Data);
+    object.append_section_data(section, &[0; 16], 8);
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert!(object.initializers().unwrap().is_empty());
+}
+
+#[test]
+fn section_hashes() {
+    let build = |data: &[u8]| {
+        let mut object =
+            write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0xc3; 4], 4);
+        let section = object.section_id(write::StandardSection::Data);
+        object.append_section_data(section, data, 4);
+        let section = object.section_id(write::StandardSection::UninitializedData);
+        object.append_section_bss(section, 16, 4);
+        object.write().unwrap()
+    };
+    let bytes1 = build(&[1, 2, 3]);
+    let bytes2 = build(&[1, 2, 4]);
+    let hashes1 = read::File::parse(&*bytes1)
+        .unwrap()
+        .section_hashes()
+        .unwrap();
+    let hashes2 = read::File::parse(&*bytes2)
+        .unwrap()
+        .section_hashes()
+        .unwrap();
+
+    let names = hashes1
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(&names[..4], ["", ".text", ".data", ".bss"]);
+    assert_eq!(hashes1.len(), hashes2.len());
+    let differences = hashes1
+        .iter()
+        .zip(hashes2.iter())
+        .filter(|(hash1, hash2)| hash1 != hash2)
+        .map(|((name, _), _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(differences, [".data"]);
+    assert_eq!(hashes1[3].1, 0xcbf2_9ce4_8422_2325);
+}
+
+#[test]
+fn toolchain() {
+    let toolchain = |sections: &[(&str, &[u8])]| {
+        let mut object =
+            write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        for &(name, data) in sections {
+            let section = object.add_section(Vec::new(), name.into(), SectionKind::Other);
+            object.append_section_data(section, data, 1);
+        }
+        let bytes = object.write().unwrap();
+        read::File::parse(&*bytes).unwrap().toolchain()
+    };
+
+    let t = toolchain(&[]);
+    assert_eq!(t.compiler(), None);
+    assert_eq!(t.linker(), None);
+    assert_eq!(t.language_hint(), None);
+
+    let t = toolchain(&[(
+        ".comment",
+        b"GCC: (Debian 12.2.0-14) 12.2.0\0Ubuntu clang version 14.0.0-1ubuntu1\0\
+          GCC: (GNU) 11.2.0\0",
+    )]);
+    assert_eq!(t.compiler(), Some("clang 14.0.0-1ubuntu1"));
+    assert_eq!(t.language_hint(), None);
+
+    let t = toolchain(&[(
+        ".comment",
+        b"GCC: (GNU) 11.2.0\0rustc version 1.60.0 (7737e0b5c 2022-04-04)\0\
+          Linker: LLD 14.0.0 (https://github.com/llvm/llvm-project)\0",
+    )]);
+    assert_eq!(t.compiler(), Some("rustc 1.60.0"));
+    assert_eq!(t.linker(), Some("lld 14.0.0"));
+    assert_eq!(t.language_hint(), Some("Rust"));
+
+    let t = toolchain(&[
+        (".comment", b"GCC: (GNU) 11.2.0\0"),
+        (
+            ".note.gnu.gold-version",
+            b"\x04\0\0\0\x09\0\0\0\x04\0\0\0GNU\0gold 1.16\0\0\0",
+        ),
+    ]);
+    assert_eq!(t.compiler(), Some("gcc 11.2.0"));
+    assert_eq!(t.linker(), Some("gold 1.16"));
+
+    // A note with a different type.
+    let t = toolchain(&[(
+        ".note.gnu.gold-version",
+        b"\x04\0\0\0\x09\0\0\0\x05\0\0\0GNU\0gold 1.16\0\0\0",
+    )]);
+    assert_eq!(t.linker(), None);
+
+    let mut buildinfo = b"\xff Go buildinf:\x08\x02".to_vec();
+    buildinfo.resize(32, 0);
+    buildinfo.push(8);
+    buildinfo.extend_from_slice(b"go1.18.1");
+    let t = toolchain(&[
+        (".comment", b"GCC: (GNU) 11.2.0\0"),
+        (".go.buildinfo", &buildinfo),
+    ]);
+    assert_eq!(t.compiler(), Some("go 1.18.1"));
+    assert_eq!(t.language_hint(), Some("Go"));
+
+    // Before Go 1.18, the version is not stored inline.
+    buildinfo[15] = 0;
+    let t = toolchain(&[
+        (".comment", b"GCC: (GNU) 11.2.0\0"),
+        (".go.buildinfo", &buildinfo),
+    ]);
+    assert_eq!(t.compiler(), Some("gcc 11.2.0"));
+    assert_eq!(t.language_hint(), None);
+}
+
+#[test]
+fn section_permissions() {
+    use object::read::Permissions;
+
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let mut sections = Vec::new();
+        for &(section, permissions) in &[
+            (
+                write::StandardSection::Text,
+                Permissions::new(true, false, true),
+            ),
+            (
+                write::StandardSection::Data,
+                Permissions::new(true, true, false),
+            ),
+            (
+                write::StandardSection::ReadOnlyData,
+                Permissions::new(true, false, false),
+            ),
+            (
+                write::StandardSection::UninitializedData,
+                Permissions::new(true, true, false),
+            ),
+        ] {
+            let id = object.section_id(section);
+            if section == write::StandardSection::UninitializedData {
+                object.append_section_bss(id, 8, 4);
+            } else {
+                object.append_section_data(id, &[1; 8], 4);
+            }
+            let name = object.section(id).name().unwrap().to_string();
+            sections.push((name, permissions));
+        }
+        if format != BinaryFormat::Coff {
+            let segment = object.segment_name(write::StandardSegment::Debug).to_vec();
+            let name = if format == BinaryFormat::MachO {
+                "__debug_info"
+            } else {
+                ".debug_info"
+            };
+            let id = object.add_section(segment, name.into(), SectionKind::Debug);
+            object.append_section_data(id, &[1; 8], 1);
+            sections.push((name.to_string(), Permissions::default()));
+        }
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        for (name, permissions) in sections {
+            let section = object.section_by_name(&name).unwrap();
+            assert_eq!(section.permissions(), permissions, "{:?} {}", format, name);
+        }
+    }
+}
+
+#[test]
+fn contains_symbol() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0xc3; 8], 4);
+        for &(name, section) in &[
+            (&b"defined"[..], write::SymbolSection::Section(text)),
+            (&b"undefined"[..], write::SymbolSection::Undefined),
+        ] {
+            object.add_symbol(write::Symbol {
+                name: name.to_vec(),
+                value: 0,
+                size: 0,
+                kind: SymbolKind::Text,
+                scope: SymbolScope::Linkage,
+                weak: false,
+                section,
+                flags: SymbolFlags::None,
+            });
+        }
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let prefix = if format == BinaryFormat::MachO {
+            "_"
+        } else {
+            ""
+        };
+        for &(name, expected) in &[("defined", true), ("undefined", true), ("missing", false)] {
+            let name = format!("{}{}", prefix, name);
+            assert_eq!(
+                object.contains_symbol(&name),
+                expected,
+                "{:?} {}",
+                format,
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn symbol_language() {
+    use object::read::SymbolLanguage;
+
+    let names = [
+        (&b"main"[..], SymbolLanguage::Unknown),
+        (
+            b"_ZN4core3fmt5write17h0123456789abcdefE",
+            SymbolLanguage::Rust,
+        ),
+        (b"_RNvCs1234_7mycrate3foo", SymbolLanguage::Rust),
+        (b"_ZN3foo3barEv", SymbolLanguage::Cpp),
+        (b"_Z3fooi", SymbolLanguage::Cpp),
+        (b"?foo@@YAXXZ", SymbolLanguage::Cpp),
+        (b"$s4main3fooyyF", SymbolLanguage::Swift),
+        (b"_Rabbit", SymbolLanguage::Unknown),
+    ];
+    for &format in &[BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        for &(name, _) in &names {
+            object.add_symbol(write::Symbol {
+                name: name.to_vec(),
+                value: 0,
+                size: 0,
+                kind: SymbolKind::Text,
+                scope: SymbolScope::Linkage,
+                weak: false,
+                section: write::SymbolSection::Undefined,
+                flags: SymbolFlags::None,
+            });
+        }
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        for &(name, language) in &names {
+            let symbol = object
+                .symbols()
+                .find(|symbol| {
+                    symbol
+                        .name()
+                        .unwrap()
+                        .ends_with(std::str::from_utf8(name).unwrap())
+                })
+                .unwrap();
+            assert_eq!(
+                symbol.language(),
+                language,
+                "{:?} {:?}",
+                format,
+                symbol.name()
+            );
+        }
+    }
+}
+
+#[cfg(feature = "demangle")]
+#[test]
+fn symbol_demangle() {
+    let names = [
+        (&b"main"[..], None),
+        (
+            b"_ZN4core3fmt5write17h0123456789abcdefE",
+            Some("core::fmt::write"),
+        ),
+        (b"_RNvCs1234_7mycrate3foo", Some("mycrate::foo")),
+        (b"_ZN3foo3barEv", Some("foo::bar()")),
+        (b"_Z3fooi", Some("foo(int)")),
+        (b"?foo@@YAXXZ", None),
+        (b"$s4main3fooyyF", None),
+        (b"_Z", None),
+    ];
+    for &format in &[BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        for &(name, _) in &names {
+            object.add_symbol(write::Symbol {
+                name: name.to_vec(),
+                value: 0,
+                size: 0,
+                kind: SymbolKind::Text,
+                scope: SymbolScope::Linkage,
+                weak: false,
+                section: write::SymbolSection::Undefined,
+                flags: SymbolFlags::None,
+            });
+        }
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        for &(name, demangled) in &names {
+            let symbol = object
+                .symbols()
+                .find(|symbol| {
+                    symbol
+                        .name()
+                        .unwrap()
+                        .ends_with(std::str::from_utf8(name).unwrap())
+                })
+                .unwrap();
+            assert_eq!(
+                symbol.demangle().as_ref().map(|name| &**name),
+                demangled,
+                "{:?} {:?}",
+                format,
+                symbol.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn section_by_address() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::X86_64,
+        Endianness::Little,
+    );
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 4], 4);
+    let data = object.section_id(write::StandardSection::Data);
+    object.append_section_data(data, &[1; 8], 16);
+    let bss = object.section_id(write::StandardSection::UninitializedData);
+    object.append_section_bss(bss, 32, 16);
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let text = object.section_by_name("__text").unwrap();
+    let data = object.section_by_name("__data").unwrap();
+    let bss = object.section_by_name("__bss").unwrap();
+    let index = |address| object.section_by_address(address).map(|s| s.index());
+    assert_eq!(index(text.address()), Some(text.index()));
+    assert_eq!(index(text.address() + 3), Some(text.index()));
+    // The gap between `__text` and `__data` due to alignment.
+    assert!(text.address() + 4 < data.address());
+    assert_eq!(index(text.address() + 4), None);
+    assert_eq!(index(data.address()), Some(data.index()));
+    assert_eq!(index(data.address() + 7), Some(data.index()));
+    assert_eq!(index(bss.address()), Some(bss.index()));
+    assert_eq!(index(bss.address() + 31), Some(bss.index()));
+    assert_eq!(index(bss.address() + 32), None);
+}
+
+#[test]
+fn section_by_address_debug() {
+    for &(format, debug_name, text_name) in &[
+        (BinaryFormat::Coff, &b".debug$S"[..], ".text"),
+        (BinaryFormat::MachO, b"__debug_info", "__text"),
+    ] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let segment = object.segment_name(write::StandardSegment::Debug).to_vec();
+        let debug = object.add_section(segment, debug_name.to_vec(), SectionKind::Debug);
+        object.append_section_data(debug, &[1; 8], 1);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0xc3; 4], 4);
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let debug = object
+            .section_by_name(std::str::from_utf8(debug_name).unwrap())
+            .unwrap();
+        let text = object.section_by_name(text_name).unwrap();
+        let index = |address| object.section_by_address(address).map(|s| s.index());
+        assert_eq!(index(text.address()), Some(text.index()), "{:?}", format);
+        assert_eq!(
+            index(text.address() + 3),
+            Some(text.index()),
+            "{:?}",
+            format
+        );
+        assert_eq!(index(text.address() + 4), None, "{:?}", format);
+        // Debugging sections are never found, even if they overlap `.text`.
+        if debug.address() != text.address() {
+            assert_eq!(index(debug.address()), None, "{:?}", format);
+        }
+    }
+}
+
+#[test]
+fn symbol_by_name() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0xc3; 16], 4);
+        for &(name, value, scope, section) in &[
+            (
+                &b"dup"[..],
+                0,
+                SymbolScope::Compilation,
+                write::SymbolSection::Section(text),
+            ),
+            (
+                b"dup",
+                4,
+                SymbolScope::Linkage,
+                write::SymbolSection::Section(text),
+            ),
+            (
+                b"local",
+                8,
+                SymbolScope::Compilation,
+                write::SymbolSection::Section(text),
+            ),
+            (
+                b"undefined",
+                0,
+                SymbolScope::Linkage,
+                write::SymbolSection::Undefined,
+            ),
+        ] {
+            object.add_symbol(write::Symbol {
+                name: name.to_vec(),
+                value,
+                size: 0,
+                kind: SymbolKind::Text,
+                scope,
+                weak: false,
+                section,
+                flags: SymbolFlags::None,
+            });
+        }
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let symbol = object.symbol_by_name("dup").unwrap();
+        assert!(symbol.is_global(), "{:?}", format);
+        assert_eq!(symbol.address(), 4, "{:?}", format);
+        let symbol = object.symbol_by_name("local").unwrap();
+        assert!(symbol.is_local(), "{:?}", format);
+        assert_eq!(symbol.address(), 8, "{:?}", format);
+        assert!(object.symbol_by_name("undefined").is_none(), "{:?}", format);
+        assert!(object.symbol_by_name("missing").is_none(), "{:?}", format);
+    }
+}
+
+#[test]
+fn raw_relocations() {
+    use object::read::{RawRelocation, RelocationTarget};
+    use object::{elf, macho, pe};
+
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0; 16], 4);
+        let symbol = object.add_symbol(write::Symbol {
+            name: b"func".to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Undefined,
+            flags: SymbolFlags::None,
+        });
+        object
+            .add_relocation(
+                text,
+                write::Relocation {
+                    offset: 8,
+                    size: 64,
+                    kind: RelocationKind::Absolute,
+                    encoding: RelocationEncoding::Generic,
+                    symbol,
+                    addend: 0,
+                },
+            )
+            .unwrap();
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let text = object
+            .section_by_name(".text")
+            .or_else(|| object.section_by_name("__text"));
+        let text = text.unwrap();
+        let symbol = match text.relocations().next().unwrap().1.target() {
+            RelocationTarget::Symbol(symbol) => symbol.0,
+            target => panic!("{:?} {:?}", format, target),
+        };
+        let raw = text.raw_relocations().unwrap();
+        assert_eq!(raw.len(), 1, "{:?}", format);
+        match raw[0] {
+            RawRelocation::Coff {
+                virtual_address,
+                symbol_table_index,
+                typ,
+            } => {
+                assert_eq!(format, BinaryFormat::Coff);
+                assert_eq!(virtual_address, 8);
+                assert_eq!(symbol_table_index as usize, symbol);
+                assert_eq!(typ, pe::IMAGE_REL_AMD64_ADDR64);
+            }
+            RawRelocation::Elf {
+                r_offset,
+                r_info,
+                r_addend,
+            } => {
+                assert_eq!(format, BinaryFormat::Elf);
+                assert_eq!(r_offset, 8);
+                assert_eq!(
+                    r_info,
+                    ((symbol as u64) << 32) | u64::from(elf::R_X86_64_64)
+                );
+                assert_eq!(r_addend, Some(0));
+            }
+            RawRelocation::MachO { r_word0, r_word1 } => {
+                assert_eq!(format, BinaryFormat::MachO);
+                assert_eq!(r_word0, 8);
+                // r_symbolnum, r_length = 3, r_extern, r_type
+                assert_eq!(
+                    r_word1,
+                    symbol as u32
+                        | (3 << 25)
+                        | (1 << 27)
+                        | (u32::from(macho::X86_64_RELOC_UNSIGNED) << 28)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn read_pointer() {
+    for &(format, architecture, endian, pointer_size) in &[
+        (
+            BinaryFormat::Coff,
+            Architecture::X86_64,
+            Endianness::Little,
+            8,
+        ),
+        (
+            BinaryFormat::Coff,
+            Architecture::I386,
+            Endianness::Little,
+            4,
+        ),
+        (
+            BinaryFormat::Elf,
+            Architecture::X86_64,
+            Endianness::Little,
+            8,
+        ),
+        (BinaryFormat::Elf, Architecture::I386, Endianness::Little, 4),
+        (BinaryFormat::Elf, Architecture::S390x, Endianness::Big, 8),
+        (
+            BinaryFormat::MachO,
+            Architecture::X86_64,
+            Endianness::Little,
+            8,
+        ),
+    ] {
+        let mut object = write::Object::new(format, architecture, endian);
+        let data = object.section_id(write::StandardSection::Data);
+        let mut bytes = vec![0; 4];
+        if pointer_size == 8 {
+            bytes.extend_from_slice(&0x0102_0304_0506_0708u64.to_le_bytes());
+        } else {
+            bytes.extend_from_slice(&0x0102_0304u32.to_le_bytes());
+        }
+        if endian == Endianness::Big {
+            bytes[4..].reverse();
+        }
+        object.append_section_data(data, &bytes, 4);
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        assert_eq!(object.pointer_size(), pointer_size, "{:?}", architecture);
+        let address = object
+            .sections()
+            .find(|section| section.kind() == SectionKind::Data)
+            .unwrap()
+            .address();
+        let expected = if pointer_size == 8 {
+            0x0102_0304_0506_0708
+        } else {
+            0x0102_0304
+        };
+        assert_eq!(object.read_pointer(address + 4), Ok(Some(expected)));
+        assert_eq!(object.read_pointer(address + 6), Ok(None));
+    }
+
+    // Sections in relocatable ELF files all have an address of 0.
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let data = object.section_id(write::StandardSection::Data);
+    object.append_section_data(data, &[1; 8], 8);
+    let rodata = object.section_id(write::StandardSection::ReadOnlyData);
+    object.append_section_data(rodata, &[2; 8], 8);
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert!(object.read_pointer(0).is_err());
+}
+
+#[test]
+fn section_kind_unwind() {
+    for &(format, segment, name) in &[
+        (BinaryFormat::Coff, "", ".pdata"),
+        (BinaryFormat::Coff, "", ".xdata$x"),
+        (BinaryFormat::Elf, "", ".eh_frame"),
+        (BinaryFormat::MachO, "__TEXT", "__eh_frame"),
+        (BinaryFormat::MachO, "__TEXT", "__unwind_info"),
+    ] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let section = object.add_section(
+            segment.as_bytes().to_vec(),
+            name.as_bytes().to_vec(),
+            SectionKind::Unwind,
+        );
+        object.append_section_data(section, &[0; 8], 4);
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        let section = object.section_by_name(name).unwrap();
+        assert_eq!(section.kind(), SectionKind::Unwind, "{:?} {}", format, name);
+    }
+}
+
+#[test]
+fn default_page_size() {
+    for &(format, architecture, page_size) in &[
+        (BinaryFormat::Coff, Architecture::X86_64, 0x1000),
+        (BinaryFormat::Elf, Architecture::X86_64, 0x1000),
+        (BinaryFormat::Elf, Architecture::Aarch64, 0x1000),
+        (BinaryFormat::MachO, Architecture::X86_64, 0x1000),
+        (BinaryFormat::MachO, Architecture::Aarch64, 0x4000),
+    ] {
+        let object = write::Object::new(format, architecture, Endianness::Little);
+        let bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        assert_eq!(
+            object.default_page_size(),
+            page_size,
+            "{:?} {:?}",
+            format,
+            architecture
+        );
+    }
+}
+
+#[test]
+fn coff_aarch64() {
+    let mut object = write::Object::new(
+        BinaryFormat::Coff,
+        Architecture::Aarch64,
+        Endianness::Little,
+    );
+
+    let text = object.section_id(write::StandardSection::Text);
+    // bl long_function_name; ret
+    let func1_offset =
+        object.append_section_data(text, &[0x00, 0x00, 0x00, 0x94, 0xc0, 0x03, 0x5f, 0xd6], 4);
+    let func1_symbol = object.add_symbol(write::Symbol {
+        name: b"func1".to_vec(),
+        value: func1_offset,
+        size: 8,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let callee_symbol = object.add_symbol(write::Symbol {
+        name: b"long_function_name".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+    object
+        .add_relocation(
+            text,
+            write::Relocation {
+                offset: func1_offset,
+                size: 32,
+                kind: RelocationKind::Coff(object::pe::IMAGE_REL_ARM64_BRANCH26),
+                encoding: RelocationEncoding::Generic,
+                symbol: callee_symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+
+    let data = object.section_id(write::StandardSection::Data);
+    let data_offset = object.append_section_data(data, &[0; 8], 8);
+    object
+        .add_relocation(
+            data,
+            write::Relocation {
+                offset: data_offset,
+                size: 64,
+                kind: RelocationKind::Absolute,
+                encoding: RelocationEncoding::Generic,
+                symbol: func1_symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.format(), BinaryFormat::Coff);
+    assert_eq!(object.architecture(), Architecture::Aarch64);
+
+    let text = object.section_by_name(".text").unwrap();
+    assert_eq!(text.kind(), SectionKind::Text);
+    assert_eq!(
+        text.data(),
+        Ok(&[0x00, 0x00, 0x00, 0x94, 0xc0, 0x03, 0x5f, 0xd6][..])
+    );
+
+    let func1 = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("func1"))
+        .unwrap();
+    assert_eq!(func1.address(), func1_offset);
+    assert_eq!(func1.section_index(), Some(text.index()));
+    let callee = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("long_function_name"))
+        .unwrap();
+    assert!(callee.is_undefined());
+
+    let mut relocations = text.relocations();
+    let (offset, relocation) = relocations.next().unwrap();
+    assert_eq!(offset, func1_offset);
+    assert_eq!(
+        relocation.kind(),
+        RelocationKind::Coff(object::pe::IMAGE_REL_ARM64_BRANCH26)
+    );
+    assert_eq!(
+        relocation.target(),
+        read::RelocationTarget::Symbol(callee.index())
+    );
+    assert!(relocations.next().is_none());
+
+    let data = object.section_by_name(".data").unwrap();
+    let mut relocations = data.relocations();
+    let (offset, relocation) = relocations.next().unwrap();
+    assert_eq!(offset, data_offset);
+    assert_eq!(relocation.kind(), RelocationKind::Absolute);
+    assert_eq!(relocation.size(), 64);
+    assert_eq!(
+        relocation.target(),
+        read::RelocationTarget::Symbol(func1.index())
+    );
+    assert!(relocations.next().is_none());
+}
+
 #[test]
 fn elf_x86_64() {
     let mut object =
@@ -225,6 +1168,199 @@ fn elf_x86_64() {
     assert_eq!(map.get(func1_offset - 1), None);
 }
 
+#[test]
+fn elf_riscv64() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::Riscv64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    let func1_offset = object.append_section_data(text, &[0x13; 16], 4);
+    let func1_symbol = object.add_symbol(write::Symbol {
+        name: b"func1".to_vec(),
+        value: func1_offset,
+        size: 16,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let data = object.section_id(write::StandardSection::Data);
+    object.append_section_data(data, &[0; 24], 8);
+    for &(offset, kind, size) in &[
+        (0, RelocationKind::Absolute, 64),
+        (8, RelocationKind::Relative, 32),
+        (12, RelocationKind::Elf(object::elf::R_RISCV_CALL_PLT), 0),
+        (16, RelocationKind::Absolute, 16),
+        (18, RelocationKind::Absolute, 8),
+        (20, RelocationKind::Elf(object::elf::R_RISCV_SET32), 0),
+    ] {
+        object
+            .add_relocation(
+                data,
+                write::Relocation {
+                    offset,
+                    size,
+                    kind,
+                    encoding: RelocationEncoding::Generic,
+                    symbol: func1_symbol,
+                    addend: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.format(), BinaryFormat::Elf);
+    assert_eq!(object.architecture(), Architecture::Riscv64);
+    assert!(object.is_64());
+
+    let data = object.section_by_name(".data").unwrap();
+    let relocations = data
+        .relocations()
+        .map(|(offset, relocation)| (offset, relocation.kind(), relocation.size()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        relocations,
+        [
+            (0, RelocationKind::Absolute, 64),
+            (8, RelocationKind::Relative, 32),
+            (12, RelocationKind::Elf(object::elf::R_RISCV_CALL_PLT), 0),
+            (16, RelocationKind::Absolute, 16),
+            (18, RelocationKind::Absolute, 8),
+            (20, RelocationKind::Absolute, 32),
+        ]
+    );
+
+    let object = write::Object::new(BinaryFormat::Elf, Architecture::Riscv32, Endianness::Little);
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.architecture(), Architecture::Riscv32);
+    assert!(!object.is_64());
+}
+
+#[test]
+fn elf_powerpc() {
+    for &(architecture, endian, is_64) in &[
+        (Architecture::PowerPc, Endianness::Big, false),
+        (Architecture::PowerPc64, Endianness::Big, true),
+        (Architecture::PowerPc64, Endianness::Little, true),
+    ] {
+        let mut object = write::Object::new(BinaryFormat::Elf, architecture, endian);
+        let text = object.section_id(write::StandardSection::Text);
+        let func1_offset = object.append_section_data(text, &[0x60; 16], 4);
+        let func1_symbol = object.add_symbol(write::Symbol {
+            name: b"func1".to_vec(),
+            value: func1_offset,
+            size: 16,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+        let data = object.section_id(write::StandardSection::Data);
+        object.append_section_data(data, &[0; 8], 8);
+        object
+            .add_relocation(
+                data,
+                write::Relocation {
+                    offset: 0,
+                    size: 32,
+                    kind: RelocationKind::Absolute,
+                    encoding: RelocationEncoding::Generic,
+                    symbol: func1_symbol,
+                    addend: 0,
+                },
+            )
+            .unwrap();
+
+        let bytes = object.write().unwrap();
+        let object = read::File::parse(&*bytes).unwrap();
+        assert_eq!(object.architecture(), architecture);
+        assert_eq!(object.endianness(), endian, "{:?}", architecture);
+        assert_eq!(object.is_64(), is_64, "{:?}", architecture);
+
+        let data = object.section_by_name(".data").unwrap();
+        let (offset, relocation) = data.relocations().next().unwrap();
+        assert_eq!(offset, 0);
+        assert_eq!(relocation.kind(), RelocationKind::Absolute);
+        assert_eq!(relocation.size(), 32);
+    }
+}
+
+#[test]
+fn arm_thumb() {
+    let mut object = write::Object::new(BinaryFormat::Elf, Architecture::Arm, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 16], 4);
+    for &(name, value, kind) in &[
+        (&b"$t"[..], 0, SymbolKind::Label),
+        (&b"thumb_func"[..], 1, SymbolKind::Text),
+        (&b"thumb_label"[..], 4, SymbolKind::Label),
+        (&b"$a.0"[..], 8, SymbolKind::Label),
+        (&b"arm_func"[..], 8, SymbolKind::Text),
+        (&b"arm_label"[..], 12, SymbolKind::Label),
+    ] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value,
+            size: 0,
+            kind,
+            scope: SymbolScope::Compilation,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let symbols = object
+        .symbols()
+        .filter(|symbol| !matches!(symbol.kind(), SymbolKind::Null | SymbolKind::Section))
+        .filter_map(|symbol| Some((symbol.name().ok()?, symbol.address(), symbol.is_thumb())))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        symbols,
+        [
+            ("$t", 0, true),
+            ("thumb_func", 0, true),
+            ("thumb_label", 4, true),
+            ("$a.0", 8, false),
+            ("arm_func", 8, false),
+            ("arm_label", 12, false),
+        ]
+    );
+
+    let mut object = write::Object::new(BinaryFormat::MachO, Architecture::Arm, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0; 16], 4);
+    for &(name, value, n_desc) in &[
+        (&b"thumb_func"[..], 0, object::macho::N_ARM_THUMB_DEF),
+        (&b"arm_func"[..], 8, 0),
+    ] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::MachO { n_desc },
+        });
+    }
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    let symbols = object
+        .symbols()
+        .filter_map(|symbol| Some((symbol.name().ok()?, symbol.address(), symbol.is_thumb())))
+        .collect::<Vec<_>>();
+    assert_eq!(symbols, [("_thumb_func", 0, true), ("_arm_func", 8, false)]);
+}
+
 #[test]
 fn macho_x86_64() {
     let mut object = write::Object::new(
@@ -341,3 +1477,411 @@ fn macho_x86_64() {
     assert_eq!(symbol.name(), "_func1");
     assert_eq!(map.get(func1_offset - 1), None);
 }
+
+#[test]
+fn macho_aarch64() {
+    let mut object = write::Object::new(
+        BinaryFormat::MachO,
+        Architecture::Aarch64,
+        Endianness::Little,
+    );
+
+    let text = object.section_id(write::StandardSection::Text);
+    // bl callee; ret
+    let func1_offset =
+        object.append_section_data(text, &[0x00, 0x00, 0x00, 0x94, 0xc0, 0x03, 0x5f, 0xd6], 4);
+    let func1_symbol = object.add_symbol(write::Symbol {
+        name: b"func1".to_vec(),
+        value: func1_offset,
+        size: 8,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(text),
+        flags: SymbolFlags::None,
+    });
+    let callee_symbol = object.add_symbol(write::Symbol {
+        name: b"callee".to_vec(),
+        value: 0,
+        size: 0,
+        kind: SymbolKind::Text,
+        scope: SymbolScope::Dynamic,
+        weak: false,
+        section: write::SymbolSection::Undefined,
+        flags: SymbolFlags::None,
+    });
+    object
+        .add_relocation(
+            text,
+            write::Relocation {
+                offset: func1_offset,
+                size: 32,
+                kind: RelocationKind::MachO {
+                    value: object::macho::ARM64_RELOC_BRANCH26,
+                    relative: true,
+                },
+                encoding: RelocationEncoding::Generic,
+                symbol: callee_symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+
+    let data = object.section_id(write::StandardSection::Data);
+    let data_offset = object.append_section_data(data, &[0; 8], 8);
+    object
+        .add_relocation(
+            data,
+            write::Relocation {
+                offset: data_offset,
+                size: 64,
+                kind: RelocationKind::Absolute,
+                encoding: RelocationEncoding::Generic,
+                symbol: func1_symbol,
+                addend: 0,
+            },
+        )
+        .unwrap();
+
+    let bytes = object.write().unwrap();
+    let object = read::File::parse(&*bytes).unwrap();
+    assert_eq!(object.format(), BinaryFormat::MachO);
+    assert_eq!(object.architecture(), Architecture::Aarch64);
+    assert_eq!(object.endianness(), Endianness::Little);
+
+    let text = object.section_by_name("__text").unwrap();
+    assert_eq!(text.segment_name(), Ok(Some("__TEXT")));
+    assert_eq!(text.kind(), SectionKind::Text);
+    assert_eq!(
+        text.data(),
+        Ok(&[0x00, 0x00, 0x00, 0x94, 0xc0, 0x03, 0x5f, 0xd6][..])
+    );
+
+    let func1 = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("_func1"))
+        .unwrap();
+    assert_eq!(func1.address(), func1_offset);
+    assert_eq!(func1.kind(), SymbolKind::Text);
+    assert_eq!(func1.section_index(), Some(text.index()));
+    assert_eq!(func1.scope(), SymbolScope::Linkage);
+    let callee = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("_callee"))
+        .unwrap();
+    assert!(callee.is_undefined());
+
+    let mut relocations = text.relocations();
+    let (offset, relocation) = relocations.next().unwrap();
+    assert_eq!(offset, func1_offset);
+    assert_eq!(
+        relocation.kind(),
+        RelocationKind::MachO {
+            value: object::macho::ARM64_RELOC_BRANCH26,
+            relative: true,
+        }
+    );
+    assert_eq!(relocation.size(), 32);
+    assert_eq!(
+        relocation.target(),
+        read::RelocationTarget::Symbol(callee.index())
+    );
+    assert!(relocations.next().is_none());
+
+    let data = object.section_by_name("__data").unwrap();
+    let mut relocations = data.relocations();
+    let (offset, relocation) = relocations.next().unwrap();
+    assert_eq!(offset, data_offset);
+    assert_eq!(relocation.kind(), RelocationKind::Absolute);
+    assert_eq!(relocation.size(), 64);
+    assert_eq!(
+        relocation.target(),
+        read::RelocationTarget::Symbol(func1.index())
+    );
+    assert!(relocations.next().is_none());
+}
+
+#[test]
+fn symbol_data() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let data = object.section_id(write::StandardSection::Data);
+    object.append_section_data(data, &[0; 8], 8);
+    let offset = object.append_section_data(data, &[1, 2, 3, 4], 4);
+    object.add_symbol(write::Symbol {
+        name: b"data_symbol".to_vec(),
+        value: offset,
+        size: 4,
+        kind: SymbolKind::Data,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(data),
+        flags: SymbolFlags::None,
+    });
+
+    let bss = object.section_id(write::StandardSection::UninitializedData);
+    let offset = object.append_section_bss(bss, 16, 8);
+    object.add_symbol(write::Symbol {
+        name: b"bss_symbol".to_vec(),
+        value: offset,
+        size: 16,
+        kind: SymbolKind::Data,
+        scope: SymbolScope::Linkage,
+        weak: false,
+        section: write::SymbolSection::Section(bss),
+        flags: SymbolFlags::None,
+    });
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let symbol = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("data_symbol"))
+        .unwrap();
+    assert_eq!(object.symbol_data(&symbol), Ok(Some(&[1, 2, 3, 4][..])));
+    let symbol = object
+        .symbols()
+        .find(|symbol| symbol.name() == Ok("bss_symbol"))
+        .unwrap();
+    assert_eq!(object.symbol_data(&symbol), Ok(None));
+}
+
+#[test]
+fn unsymbolized_ranges() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xcc; 0x40], 16);
+    for &(name, value, size) in &[("a", 0x10, 0x10), ("b", 0x18, 0x10), ("c", 0x30, 0)] {
+        object.add_symbol(write::Symbol {
+            name: name.as_bytes().to_vec(),
+            value,
+            size,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let section = object.section_by_name(".text").unwrap();
+    assert_eq!(
+        object.unsymbolized_ranges(section.index()),
+        Ok(vec![(0, 0x10), (0x28, 0x8)])
+    );
+}
+
+#[test]
+fn symbol_name_map() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xcc; 0x30], 16);
+    for &(name, value) in &[("dup", 0x20), ("func", 0x10), ("dup", 0x0)] {
+        object.add_symbol(write::Symbol {
+            name: name.as_bytes().to_vec(),
+            value,
+            size: 0x10,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Compilation,
+            weak: false,
+            section: write::SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let map = read::SymbolNameMap::from_object(&object);
+    assert_eq!(map.get("func").map(|s| s.address()), Some(0x10));
+    assert_eq!(map.get("dup").map(|s| s.address()), Some(0x20));
+    let all = map
+        .get_all("dup")
+        .iter()
+        .map(|s| s.address())
+        .collect::<Vec<_>>();
+    assert_eq!(all, [0x20, 0x0]);
+    assert_eq!(map.get("missing"), None);
+    assert!(map.get_all("missing").is_empty());
+}
+
+#[test]
+fn symbol_map_overlapping() {
+    use object::read::{SymbolMap, SymbolMapName};
+
+    let map = SymbolMap::new(vec![
+        SymbolMapName::new(0x1000, "outer").with_size(0x100),
+        SymbolMapName::new(0x1040, "inner").with_size(0x10),
+        SymbolMapName::new(0x1000, "alias").with_size(0x20),
+        SymbolMapName::new(0x2000, "unsized"),
+        SymbolMapName::new(0x3000, "small").with_size(0x10),
+        SymbolMapName::new(0x4000, "first").with_size(0x100),
+        SymbolMapName::new(0x4080, "second").with_size(0x100),
+    ]);
+    let get = |address| map.get(address).map(|symbol| symbol.name());
+    // Two symbols at the same address with different sizes.
+    assert_eq!(get(0x1000), Some("alias"));
+    assert_eq!(get(0x101f), Some("alias"));
+    assert_eq!(get(0x1020), Some("outer"));
+    // A symbol nested inside another.
+    assert_eq!(get(0x1040), Some("inner"));
+    assert_eq!(get(0x104f), Some("inner"));
+    assert_eq!(get(0x1050), Some("outer"));
+    assert_eq!(get(0x10ff), Some("outer"));
+    // Addresses that are not contained in any symbol.
+    assert_eq!(get(0x1100), Some("inner"));
+    assert_eq!(get(0x2010), Some("unsized"));
+    assert_eq!(get(0x3010), Some("small"));
+    assert_eq!(get(0xfff), None);
+    // Partially overlapping symbols, where the one that starts last is preferred.
+    assert_eq!(get(0x407f), Some("first"));
+    assert_eq!(get(0x4080), Some("second"));
+    assert_eq!(get(0x40ff), Some("second"));
+    assert_eq!(get(0x4100), Some("second"));
+}
+
+#[test]
+fn multi_symbol_map() {
+    use object::read::{MultiSymbolMap, SymbolMap, SymbolMapName};
+
+    let lib = SymbolMap::new(vec![
+        SymbolMapName::new(0x100, "lib_a"),
+        SymbolMapName::new(0x200, "lib_b"),
+    ]);
+    let exe = SymbolMap::new(vec![
+        SymbolMapName::new(0x40_1000, "main"),
+        SymbolMapName::new(0x40_2000, "helper"),
+    ]);
+    let map = MultiSymbolMap::new(vec![(0x7f00_0000, lib), (0, exe)]);
+    let get = |address| {
+        map.get(address)
+            .map(|(index, symbol)| (index, symbol.name()))
+    };
+    assert_eq!(get(0x40_1010), Some((1, "main")));
+    assert_eq!(get(0x40_2000), Some((1, "helper")));
+    assert_eq!(get(0x40_0fff), None);
+    assert_eq!(get(0x7f00_0100), Some((0, "lib_a")));
+    assert_eq!(get(0x7f00_0250), Some((0, "lib_b")));
+    assert_eq!(get(0x7f00_0050), None);
+    assert_eq!(map.modules()[0].0, 0x7f00_0000);
+}
+
+#[test]
+fn symbols_by_address() {
+    let mut object =
+        write::Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    for &(name, value, section) in &[
+        (&b"c"[..], 8, write::SymbolSection::Section(text)),
+        (&b"a"[..], 4, write::SymbolSection::Section(text)),
+        (&b"b"[..], 4, write::SymbolSection::Section(text)),
+        (&b"undefined"[..], 0, write::SymbolSection::Undefined),
+    ] {
+        object.add_symbol(write::Symbol {
+            name: name.to_vec(),
+            value,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section,
+            flags: SymbolFlags::None,
+        });
+    }
+    let bytes = object.write().unwrap();
+
+    let object = read::File::parse(&*bytes).unwrap();
+    let symbols = object.symbols_by_address();
+    assert_eq!(symbols.len(), object.symbols().count());
+    let names: Vec<_> = symbols
+        .iter()
+        .map(|symbol| symbol.name().unwrap())
+        .filter(|name| ["a", "b", "c", "undefined"].contains(name))
+        .collect();
+    assert_eq!(names, ["undefined", "a", "b", "c"]);
+    assert!(symbols
+        .windows(2)
+        .all(|w| (w[0].address(), w[0].index().0) < (w[1].address(), w[1].index().0)));
+}
+
+#[test]
+fn is_zero_filled() {
+    for &format in &[BinaryFormat::Coff, BinaryFormat::Elf, BinaryFormat::MachO] {
+        let mut object = write::Object::new(format, Architecture::X86_64, Endianness::Little);
+        let bss = object.section_id(write::StandardSection::UninitializedData);
+        object.append_section_bss(bss, 16, 4);
+        let data = object.section_id(write::StandardSection::Data);
+        object.append_section_data(data, &[0; 16], 4);
+        let text = object.section_id(write::StandardSection::Text);
+        object.append_section_data(text, &[0, 0, 0xc3, 0], 4);
+        let bytes = object.write().unwrap();
+
+        let object = read::File::parse(&*bytes).unwrap();
+        for &(kind, expected) in &[
+            (SectionKind::UninitializedData, true),
+            (SectionKind::Data, true),
+            (SectionKind::Text, false),
+        ] {
+            let section = object.sections().find(|s| s.kind() == kind).unwrap();
+            assert_eq!(
+                section.is_zero_filled(),
+                expected,
+                "{:?} {:?}",
+                format,
+                kind
+            );
+        }
+    }
+}
+
+#[test]
+fn coff_line_numbers() {
+    use object::read::coff::{CoffFile, LineNumber};
+
+    let mut object =
+        write::Object::new(BinaryFormat::Coff, Architecture::X86_64, Endianness::Little);
+    let text = object.section_id(write::StandardSection::Text);
+    object.append_section_data(text, &[0xc3; 16], 4);
+    let mut bytes = object.write().unwrap();
+
+    let index = {
+        let file = CoffFile::parse(&*bytes).unwrap();
+        let section = file.section_by_name(".text").unwrap();
+        assert!(section.line_numbers().unwrap().is_empty());
+        section.index()
+    };
+
+    // Append a line number table and point the section header at it.
+    let offset = bytes.len() as u32;
+    for &(value, line) in &[(3u32, 0u16), (0x4, 1), (0x8, 2)] {
+        bytes.extend_from_slice(&value.to_le_bytes());
+        bytes.extend_from_slice(&line.to_le_bytes());
+    }
+    let header = 20 + 40 * (index.0 - 1);
+    bytes[header + 28..header + 32].copy_from_slice(&offset.to_le_bytes());
+    bytes[header + 34..header + 36].copy_from_slice(&3u16.to_le_bytes());
+
+    let file = CoffFile::parse(&*bytes).unwrap();
+    let section = file.section_by_name(".text").unwrap();
+    assert_eq!(
+        section.line_numbers().unwrap(),
+        [
+            LineNumber::Function(object::SymbolIndex(3)),
+            LineNumber::Line {
+                address: 0x4,
+                line: 1
+            },
+            LineNumber::Line {
+                address: 0x8,
+                line: 2
+            },
+        ]
+    );
+}
@@ -0,0 +1,536 @@
+use object::pe;
+use object::read::pe::PeFile64;
+
+/// Build a PE32+ image with no sections, followed by the given certificate table.
+pub(super) fn pe64_with_certificates(certificates: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0; 328];
+    bytes[0..2].copy_from_slice(&pe::IMAGE_DOS_SIGNATURE.to_le_bytes());
+    bytes[0x3c..0x40].copy_from_slice(&64u32.to_le_bytes());
+    bytes[64..68].copy_from_slice(&pe::IMAGE_NT_SIGNATURE.to_le_bytes());
+    bytes[68..70].copy_from_slice(&pe::IMAGE_FILE_MACHINE_AMD64.to_le_bytes());
+    bytes[84..86].copy_from_slice(&240u16.to_le_bytes());
+    bytes[88..90].copy_from_slice(&pe::IMAGE_NT_OPTIONAL_HDR64_MAGIC.to_le_bytes());
+    bytes[196..200].copy_from_slice(&16u32.to_le_bytes());
+    let security = 200 + 8 * pe::IMAGE_DIRECTORY_ENTRY_SECURITY;
+    bytes[security..security + 4].copy_from_slice(&328u32.to_le_bytes());
+    bytes[security + 4..security + 8].copy_from_slice(&(certificates.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(certificates);
+    bytes
+}
+
+/// Build a PE32+ image with a single section at RVA 0x1000.
+fn pe64_with_section(name: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut bytes = pe64_with_certificates(&[]);
+    let security = 200 + 8 * pe::IMAGE_DIRECTORY_ENTRY_SECURITY;
+    bytes[security..security + 8].copy_from_slice(&[0; 8]);
+    bytes[70..72].copy_from_slice(&1u16.to_le_bytes());
+    let mut section = [0; 40];
+    section[..name.len()].copy_from_slice(name);
+    section[8..12].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    section[12..16].copy_from_slice(&0x1000u32.to_le_bytes());
+    section[16..20].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    section[20..24].copy_from_slice(&512u32.to_le_bytes());
+    bytes.extend_from_slice(&section);
+    bytes.resize(512, 0);
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Build a PE32+ image with a single section at RVA 0x1000, which contains the
+/// data for the given data directory.
+pub(super) fn pe64_with_directory(name: &[u8], directory: usize, data: &[u8]) -> Vec<u8> {
+    let mut bytes = pe64_with_section(name, data);
+    let entry = 200 + 8 * directory;
+    bytes[entry..entry + 4].copy_from_slice(&0x1000u32.to_le_bytes());
+    bytes[entry + 4..entry + 8].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes
+}
+
+/// Build a PE32+ image with a single `.rsrc` section at RVA 0x1000.
+fn pe64_with_resources(resources: &[u8]) -> Vec<u8> {
+    pe64_with_directory(b".rsrc", pe::IMAGE_DIRECTORY_ENTRY_RESOURCE, resources)
+}
+
+#[test]
+fn certificates() {
+    let mut table = Vec::new();
+    for &(certificate_type, data) in &[
+        (pe::WIN_CERT_TYPE_PKCS_SIGNED_DATA, &b"signature"[..]),
+        (pe::WIN_CERT_TYPE_X509, b"x509"),
+    ] {
+        table.extend_from_slice(&(8 + data.len() as u32).to_le_bytes());
+        table.extend_from_slice(&pe::WIN_CERT_REVISION_2_0.to_le_bytes());
+        table.extend_from_slice(&certificate_type.to_le_bytes());
+        table.extend_from_slice(data);
+        while table.len() % 8 != 0 {
+            table.push(0);
+        }
+    }
+    let bytes = pe64_with_certificates(&table);
+
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let certificates = file.certificates().unwrap();
+    assert_eq!(certificates.len(), 2);
+    assert_eq!(certificates[0].revision(), pe::WIN_CERT_REVISION_2_0);
+    assert_eq!(
+        certificates[0].certificate_type(),
+        pe::WIN_CERT_TYPE_PKCS_SIGNED_DATA
+    );
+    assert_eq!(certificates[0].data(), b"signature");
+    assert_eq!(certificates[1].certificate_type(), pe::WIN_CERT_TYPE_X509);
+    assert_eq!(certificates[1].data(), b"x509");
+
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.certificates().unwrap().is_empty());
+}
+
+#[test]
+fn manifest() {
+    let xml = b"<assembly><requestedExecutionLevel level=\"requireAdministrator\"/></assembly>";
+    let mut resources = Vec::new();
+    let mut directory = |entries: &[(u32, u32)]| {
+        resources.extend_from_slice(&[0; 14]);
+        resources.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        for &(id, offset) in entries {
+            resources.extend_from_slice(&id.to_le_bytes());
+            resources.extend_from_slice(&offset.to_le_bytes());
+        }
+    };
+    let is_directory = pe::IMAGE_RESOURCE_DATA_IS_DIRECTORY;
+    // Type directory at 0, with an RT_VERSION entry before the RT_MANIFEST entry.
+    directory(&[
+        (pe::RT_VERSION.into(), is_directory | 32),
+        (pe::RT_MANIFEST.into(), is_directory | 32),
+    ]);
+    // Name directory at 32.
+    directory(&[(1, is_directory | 56)]);
+    // Language directory at 56.
+    directory(&[(0x409, 80)]);
+    // Data entry at 80.
+    resources.extend_from_slice(&(0x1000u32 + 96).to_le_bytes());
+    resources.extend_from_slice(&(xml.len() as u32).to_le_bytes());
+    resources.extend_from_slice(&[0; 8]);
+    resources.extend_from_slice(xml);
+
+    let bytes = pe64_with_resources(&resources);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.manifest().unwrap(), Some(&xml[..]));
+
+    // Remove the RT_MANIFEST entry.
+    resources[14..16].copy_from_slice(&1u16.to_le_bytes());
+    let bytes = pe64_with_resources(&resources);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.manifest().unwrap(), None);
+
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.manifest().unwrap(), None);
+}
+
+#[test]
+fn preferred_base() {
+    use object::read::Object;
+
+    let mut bytes = pe64_with_certificates(&[]);
+    bytes[112..120].copy_from_slice(&0x1_4000_0000u64.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.preferred_base(), 0x1_4000_0000);
+    assert_eq!(
+        file.file_address(0x7ff6_0000_1234, 0x7ff6_0000_0000),
+        0x1_4000_1234
+    );
+}
+
+#[test]
+fn forwarded_exports() {
+    use object::read::{ForwardName, Object};
+
+    // The export directory, followed by the address, name and ordinal tables,
+    // and then the strings.
+    let mut exports = vec![0; 40];
+    exports[16..20].copy_from_slice(&1u32.to_le_bytes());
+    exports[20..24].copy_from_slice(&3u32.to_le_bytes());
+    exports[24..28].copy_from_slice(&3u32.to_le_bytes());
+    exports[28..32].copy_from_slice(&(0x1000u32 + 40).to_le_bytes());
+    exports[32..36].copy_from_slice(&(0x1000u32 + 52).to_le_bytes());
+    exports[36..40].copy_from_slice(&(0x1000u32 + 64).to_le_bytes());
+    let strings: &[&[u8]] = &[
+        b"foo",
+        b"bar",
+        b"baz",
+        b"NTDLL.RtlAllocateHeap",
+        b"KERNEL32.#12",
+    ];
+    let mut offsets = Vec::new();
+    let mut data = Vec::new();
+    for string in strings {
+        offsets.push(0x1000u32 + 70 + data.len() as u32);
+        data.extend_from_slice(string);
+        data.push(0);
+    }
+    // Addresses: a normal export, and two forwarders.
+    for &address in &[0x2000, offsets[3], offsets[4]] {
+        exports.extend_from_slice(&address.to_le_bytes());
+    }
+    for &offset in &offsets[..3] {
+        exports.extend_from_slice(&offset.to_le_bytes());
+    }
+    for ordinal in 0..3u16 {
+        exports.extend_from_slice(&ordinal.to_le_bytes());
+    }
+    assert_eq!(exports.len(), 70);
+    exports.extend_from_slice(&data);
+
+    let mut bytes = pe64_with_directory(b".edata", pe::IMAGE_DIRECTORY_ENTRY_EXPORT, &exports);
+    bytes[112..120].copy_from_slice(&0x1_4000_0000u64.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let exports = file.exports().unwrap();
+    assert_eq!(exports.len(), 3);
+
+    assert_eq!(exports[0].name(), b"foo");
+    assert_eq!(exports[0].address(), 0x1_4000_2000);
+    assert_eq!(exports[0].forward(), None);
+    assert_eq!(exports[0].forward_target(), None);
+
+    assert_eq!(exports[1].name(), b"bar");
+    assert_eq!(exports[1].address(), 0);
+    assert_eq!(exports[1].forward(), Some(&b"NTDLL.RtlAllocateHeap"[..]));
+    assert_eq!(
+        exports[1].forward_target(),
+        Some((&b"NTDLL"[..], ForwardName::Name(b"RtlAllocateHeap")))
+    );
+
+    assert_eq!(exports[2].name(), b"baz");
+    assert_eq!(exports[2].address(), 0);
+    assert_eq!(
+        exports[2].forward_target(),
+        Some((&b"KERNEL32"[..], ForwardName::Ordinal(12)))
+    );
+}
+
+#[test]
+fn bound_imports() {
+    // Build the descriptors, with a forwarder reference after the first, followed by
+    // the null descriptor and then the names.
+    let names: &[&[u8]] = &[b"KERNEL32.dll", b"NTDLL.dll"];
+    let mut name_offsets = Vec::new();
+    let mut offset = 32;
+    for name in names {
+        name_offsets.push(offset as u16);
+        offset += name.len() + 1;
+    }
+    let mut bound = Vec::new();
+    for &(time_date_stamp, name, refs) in &[
+        (0x1111_1111u32, name_offsets[0], 1u16),
+        (0x2222_2222, name_offsets[1], 0),
+        (0x3333_3333, name_offsets[1], 0),
+        (0, 0, 0),
+    ] {
+        bound.extend_from_slice(&time_date_stamp.to_le_bytes());
+        bound.extend_from_slice(&name.to_le_bytes());
+        bound.extend_from_slice(&refs.to_le_bytes());
+    }
+    for name in names {
+        bound.extend_from_slice(name);
+        bound.push(0);
+    }
+
+    let build = |bound: &[u8], size: usize| {
+        let mut bytes = pe64_with_certificates(bound);
+        let security = 200 + 8 * pe::IMAGE_DIRECTORY_ENTRY_SECURITY;
+        bytes[security..security + 8].copy_from_slice(&[0; 8]);
+        let entry = 200 + 8 * pe::IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT;
+        bytes[entry..entry + 4].copy_from_slice(&328u32.to_le_bytes());
+        bytes[entry + 4..entry + 8].copy_from_slice(&(size as u32).to_le_bytes());
+        bytes
+    };
+
+    let bytes = build(&bound, bound.len());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let imports = file.bound_imports().unwrap();
+    // The forwarder reference is not returned as a descriptor.
+    assert_eq!(imports.len(), 2);
+    assert_eq!(imports[0].name(), b"KERNEL32.dll");
+    assert_eq!(imports[0].time_date_stamp(), 0x1111_1111);
+    assert_eq!(imports[1].name(), b"NTDLL.dll");
+    assert_eq!(imports[1].time_date_stamp(), 0x3333_3333);
+
+    // Missing null descriptor.
+    let bytes = build(&bound, 24);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.bound_imports().is_err());
+
+    // Name offset outside of the directory.
+    let mut invalid = bound.clone();
+    invalid[4..6].copy_from_slice(&0x1000u16.to_le_bytes());
+    let bytes = build(&invalid, invalid.len());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.bound_imports().is_err());
+
+    // Directory outside of the file.
+    let bytes = build(&bound, 0x1000);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.bound_imports().is_err());
+
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.bound_imports().unwrap().is_empty());
+}
+
+#[test]
+fn load_config() {
+    let mut config = vec![0; core::mem::size_of::<pe::ImageLoadConfigDirectory64>()];
+    let size = config.len() as u32;
+    config[0..4].copy_from_slice(&size.to_le_bytes());
+    for &(offset, value) in &[
+        (88, 0x1_4000_3000u64),
+        (96, 0x1_4000_4000),
+        (104, 2),
+        (112, 0x1_4000_5000),
+        (120, 0x1_4000_5008),
+        (128, 0x1_4000_6000),
+        (136, 3),
+    ] {
+        config[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    }
+    config[144..148].copy_from_slice(&pe::IMAGE_GUARD_CF_INSTRUMENTED.to_le_bytes());
+
+    let bytes = pe64_with_directory(b".rdata", pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG, &config);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let load_config = file.load_config().unwrap().unwrap();
+    assert_eq!(load_config.size(), size);
+    assert_eq!(load_config.security_cookie(), 0x1_4000_3000);
+    assert_eq!(load_config.se_handler_table(), 0x1_4000_4000);
+    assert_eq!(load_config.se_handler_count(), 2);
+    assert_eq!(load_config.guard_cf_check_function_pointer(), 0x1_4000_5000);
+    assert_eq!(
+        load_config.guard_cf_dispatch_function_pointer(),
+        0x1_4000_5008
+    );
+    assert_eq!(load_config.guard_cf_function_table(), 0x1_4000_6000);
+    assert_eq!(load_config.guard_cf_function_count(), 3);
+    assert_eq!(load_config.guard_flags(), pe::IMAGE_GUARD_CF_INSTRUMENTED);
+
+    // An older directory that ends after the security cookie. The fields after it
+    // must be zero, even though the section contains more data.
+    let mut short = config.clone();
+    short[0..4].copy_from_slice(&96u32.to_le_bytes());
+    let bytes = pe64_with_directory(b".rdata", pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG, &short);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let load_config = file.load_config().unwrap().unwrap();
+    assert_eq!(load_config.size(), 96);
+    assert_eq!(load_config.security_cookie(), 0x1_4000_3000);
+    assert_eq!(load_config.se_handler_table(), 0);
+    assert_eq!(load_config.guard_cf_function_count(), 0);
+    assert_eq!(load_config.guard_flags(), 0);
+
+    // A directory that is too short to contain its own size.
+    let bytes = pe64_with_directory(b".rdata", pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG, &[4, 0]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.load_config().is_err());
+
+    // A size that extends past the end of the section.
+    let mut long = config.clone();
+    long[0..4].copy_from_slice(&(size + 8).to_le_bytes());
+    let bytes = pe64_with_directory(b".rdata", pe::IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG, &long);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.load_config().is_err());
+
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.load_config().unwrap().is_none());
+}
+
+#[test]
+fn is_reproducible() {
+    let debug_directory = |types: &[u32]| {
+        let mut data = Vec::new();
+        for typ in types {
+            let mut entry = [0; 28];
+            entry[12..16].copy_from_slice(&typ.to_le_bytes());
+            data.extend_from_slice(&entry);
+        }
+        data
+    };
+
+    let data = debug_directory(&[pe::IMAGE_DEBUG_TYPE_CODEVIEW, pe::IMAGE_DEBUG_TYPE_REPRO]);
+    let bytes = pe64_with_directory(b".rdata", pe::IMAGE_DIRECTORY_ENTRY_DEBUG, &data);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.is_reproducible().unwrap());
+
+    let data = debug_directory(&[pe::IMAGE_DEBUG_TYPE_CODEVIEW, pe::IMAGE_DEBUG_TYPE_POGO]);
+    let bytes = pe64_with_directory(b".rdata", pe::IMAGE_DIRECTORY_ENTRY_DEBUG, &data);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(!file.is_reproducible().unwrap());
+
+    // A directory size that extends past the end of the section.
+    let mut bytes = bytes;
+    let entry = 200 + 8 * pe::IMAGE_DIRECTORY_ENTRY_DEBUG;
+    bytes[entry + 4..entry + 8].copy_from_slice(&0x1000u32.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.is_reproducible().is_err());
+
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(!file.is_reproducible().unwrap());
+}
+
+#[test]
+fn dos_stub() {
+    let stub = b"\x0e\x1f\xba\x0e\x00\xb4\x09\xcd\x21This program cannot be run in DOS mode.\r\r\n$\0\0\0\0";
+    let mut bytes = pe64_with_certificates(&[]);
+    bytes.splice(64..64, stub.iter().cloned());
+    bytes[0x3c..0x40].copy_from_slice(&(64 + stub.len() as u32).to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.dos_stub().unwrap(), &stub[..]);
+
+    // The NT headers immediately follow the DOS header.
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.dos_stub().unwrap(), &[]);
+}
+
+#[test]
+fn exception_functions_x64() {
+    let mut pdata = Vec::new();
+    for &(begin, end, unwind) in &[(0x1000u32, 0x1040u32, 0x3000u32), (0x1040, 0x1100, 0x3008)] {
+        pdata.extend_from_slice(&begin.to_le_bytes());
+        pdata.extend_from_slice(&end.to_le_bytes());
+        pdata.extend_from_slice(&unwind.to_le_bytes());
+    }
+    let bytes = pe64_with_directory(b".pdata", pe::IMAGE_DIRECTORY_ENTRY_EXCEPTION, &pdata);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let functions = file.exception_functions().unwrap();
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0].begin_address(), 0x1000);
+    assert_eq!(functions[0].end_address(), 0x1040);
+    assert_eq!(functions[0].unwind_info_address(), Some(0x3000));
+    assert_eq!(functions[1].begin_address(), 0x1040);
+    assert_eq!(functions[1].end_address(), 0x1100);
+    assert_eq!(functions[1].unwind_info_address(), Some(0x3008));
+
+    // Other machines use a different layout.
+    let mut bytes = bytes;
+    bytes[68..70].copy_from_slice(&pe::IMAGE_FILE_MACHINE_I386.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.exception_functions().is_err());
+
+    let bytes = pe64_with_certificates(&[]);
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.exception_functions().unwrap().is_empty());
+}
+
+#[test]
+fn exception_functions_arm64() {
+    // The first entry refers to an `.xdata` record that follows the entries in the
+    // same section, and the second entry has packed unwind data.
+    let xdata = 0x1010u32;
+    let mut pdata = Vec::new();
+    pdata.extend_from_slice(&0x2000u32.to_le_bytes());
+    pdata.extend_from_slice(&xdata.to_le_bytes());
+    pdata.extend_from_slice(&0x2200u32.to_le_bytes());
+    pdata.extend_from_slice(&(1u32 | (0x10 << 2)).to_le_bytes());
+    pdata.extend_from_slice(&0x80u32.to_le_bytes());
+    let mut bytes = pe64_with_directory(b".pdata", pe::IMAGE_DIRECTORY_ENTRY_EXCEPTION, &pdata);
+    bytes[68..70].copy_from_slice(&pe::IMAGE_FILE_MACHINE_ARM64.to_le_bytes());
+    let entry = 200 + 8 * pe::IMAGE_DIRECTORY_ENTRY_EXCEPTION;
+    bytes[entry + 4..entry + 8].copy_from_slice(&16u32.to_le_bytes());
+
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let functions = file.exception_functions().unwrap();
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0].begin_address(), 0x2000);
+    assert_eq!(functions[0].end_address(), 0x2200);
+    assert_eq!(functions[0].unwind_info_address(), Some(xdata));
+    assert_eq!(functions[1].begin_address(), 0x2200);
+    assert_eq!(functions[1].end_address(), 0x2240);
+    assert_eq!(functions[1].unwind_info_address(), None);
+
+    // An `.xdata` address outside of the image.
+    bytes[512 + 4..512 + 8].copy_from_slice(&0x8000u32.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.exception_functions().is_err());
+}
+
+#[test]
+fn entry_symbol() {
+    use object::read::{Object, ObjectSymbol};
+
+    let mut bytes = pe64_with_section(b".text", &[0xc3; 0x40]);
+    bytes[112..120].copy_from_slice(&0x1_4000_0000u64.to_le_bytes());
+    // Add a COFF symbol table after the section data.
+    let symbol_table = bytes.len() as u32;
+    bytes[76..80].copy_from_slice(&symbol_table.to_le_bytes());
+    bytes[80..84].copy_from_slice(&2u32.to_le_bytes());
+    for &(name, value) in &[(b"helper\0\0", 0x10u32), (b"start\0\0\0", 0x20)] {
+        bytes.extend_from_slice(name);
+        bytes.extend_from_slice(&value.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&[pe::IMAGE_SYM_CLASS_EXTERNAL, 0]);
+    }
+    bytes.extend_from_slice(&4u32.to_le_bytes());
+
+    // The entry point is an RVA.
+    bytes[104..108].copy_from_slice(&0x1020u32.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let symbol = file.entry_symbol().unwrap();
+    assert_eq!(symbol.name(), Ok("start"));
+    assert_eq!(symbol.address(), 0x1_4000_1020);
+
+    bytes[104..108].copy_from_slice(&0x1030u32.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.entry_symbol().is_none());
+
+    bytes[104..108].copy_from_slice(&0u32.to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert!(file.entry_symbol().is_none());
+}
+
+#[test]
+fn contains_symbol_imports() {
+    use object::read::Object;
+
+    // The import descriptor, the null descriptor, the import lookup table, a hint/name
+    // entry, and the library name.
+    let mut idata = Vec::new();
+    for word in &[0x1028u32, 0, 0, 0x104e, 0x1028] {
+        idata.extend_from_slice(&word.to_le_bytes());
+    }
+    idata.extend_from_slice(&[0; 20]);
+    for thunk in &[0x1040u64, pe::IMAGE_ORDINAL_FLAG64 | 5, 0] {
+        idata.extend_from_slice(&thunk.to_le_bytes());
+    }
+    idata.extend_from_slice(b"\0\0ExitProcess\0");
+    idata.extend_from_slice(b"KERNEL32.dll\0");
+    let bytes = pe64_with_directory(b".idata", pe::IMAGE_DIRECTORY_ENTRY_IMPORT, &idata);
+
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let imports = file.imports().unwrap();
+    assert_eq!(imports.len(), 1);
+    assert_eq!(imports[0].name(), b"ExitProcess");
+    assert_eq!(imports[0].library(), b"KERNEL32.dll");
+    assert!(file.contains_symbol("ExitProcess"));
+    assert!(!file.contains_symbol("ExitThread"));
+}
+
+#[test]
+fn toolchain() {
+    use object::read::Object;
+
+    // The Rich header is at the end of the DOS stub, followed by a checksum.
+    let stub = b"DanS\0\0\0\0\0\0\0\0\0\0\0\0Rich\x12\x34\x56\x78";
+    let mut bytes = pe64_with_certificates(&[]);
+    bytes[90] = 14;
+    bytes[91] = 29;
+    let file = PeFile64::parse(&*bytes).unwrap();
+    assert_eq!(file.toolchain().linker(), None);
+
+    bytes.splice(64..64, stub.iter().cloned());
+    bytes[0x3c..0x40].copy_from_slice(&(64 + stub.len() as u32).to_le_bytes());
+    let file = PeFile64::parse(&*bytes).unwrap();
+    let toolchain = file.toolchain();
+    assert_eq!(toolchain.linker(), Some("link 14.29"));
+    assert_eq!(toolchain.compiler(), None);
+}
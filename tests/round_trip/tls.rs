@@ -141,6 +141,13 @@ fn elf_x86_64_tls() {
     assert_eq!(symbol.is_weak(), false);
     assert_eq!(symbol.is_undefined(), false);
     assert_eq!(symbol.size(), 31);
+
+    let tls_symbols = object
+        .tls_symbols()
+        .into_iter()
+        .map(|(symbol, offset)| (symbol.name().unwrap(), offset))
+        .collect::<Vec<_>>();
+    assert_eq!(tls_symbols, [("tls1", 0), ("tls2", 0)]);
 }
 
 #[test]
@@ -313,4 +320,20 @@ fn macho_x86_64_tls() {
         read::RelocationTarget::Symbol(tls2_init_symbol)
     );
     assert_eq!(relocation.addend(), 0);
+
+    let tls_symbols = object
+        .tls_symbols()
+        .into_iter()
+        .map(|(symbol, offset)| (symbol.name().unwrap(), offset))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tls_symbols,
+        [
+            ("_tls1$tlv$init", 0),
+            (
+                "_tls2$tlv$init",
+                thread_bss.address() - thread_data.address()
+            ),
+        ]
+    );
 }
@@ -0,0 +1,103 @@
+#![cfg(feature = "wasm")]
+
+use object::read::wasm::{TableElementType, WasmFile};
+
+#[rustfmt::skip]
+const MODULE: &[u8] = &[
+    // Header.
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+    // Table section: funcref, initial 1, maximum 2.
+    0x04, 0x05, 0x01, 0x70, 0x01, 0x01, 0x02,
+    // Memory section: initial 1, maximum 16.
+    0x05, 0x04, 0x01, 0x01, 0x01, 0x10,
+    // Element section: table 0, offset (i32.const 0), functions [0].
+    0x09, 0x07, 0x01, 0x00, 0x41, 0x00, 0x0b, 0x01, 0x00,
+    // Data section: memory 0, offset (i32.const 8), "abc".
+    0x0b, 0x09, 0x01, 0x00, 0x41, 0x08, 0x0b, 0x03, 0x61, 0x62, 0x63,
+];
+
+#[test]
+fn wasm_memories_tables() {
+    let file = WasmFile::<&[u8]>::parse(MODULE).unwrap();
+
+    let memories = file.memories().unwrap();
+    assert_eq!(memories.len(), 1);
+    assert_eq!(memories[0].limits().initial(), 1);
+    assert_eq!(memories[0].limits().maximum(), Some(16));
+    assert!(!memories[0].is_shared());
+
+    let tables = file.tables().unwrap();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].element_type(), TableElementType::FuncRef);
+    assert_eq!(tables[0].limits().initial(), 1);
+    assert_eq!(tables[0].limits().maximum(), Some(2));
+}
+
+#[test]
+fn wasm_segments() {
+    let file = WasmFile::<&[u8]>::parse(MODULE).unwrap();
+
+    let data = file.data_segments().unwrap();
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0].memory_index(), Some(0));
+    assert_eq!(data[0].offset(), Some(8));
+    assert_eq!(data[0].data(), b"abc");
+
+    let elements = file.element_segments().unwrap();
+    assert_eq!(elements.len(), 1);
+    assert_eq!(elements[0].table_index(), Some(0));
+    assert_eq!(elements[0].offset(), Some(0));
+    assert_eq!(elements[0].functions(), &[Some(0)]);
+}
+
+#[test]
+fn wasm_truncated_element_items() {
+    #[rustfmt::skip]
+    let module: &[u8] = &[
+        // Header.
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+        // Element section: table 0, offset (i32.const 0), 0xffffffff functions but only [0].
+        0x09, 0x0b, 0x01, 0x00, 0x41, 0x00, 0x0b, 0xff, 0xff, 0xff, 0xff, 0x0f, 0x00,
+    ];
+    let file = WasmFile::<&[u8]>::parse(module).unwrap();
+    assert!(file.element_segments().is_err());
+}
+
+#[test]
+fn wasm_invalid_export_index() {
+    #[rustfmt::skip]
+    let module: &[u8] = &[
+        // Header.
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+        // Export section: function "f" with index 5, but there are no functions.
+        0x07, 0x05, 0x01, 0x01, 0x66, 0x00, 0x05,
+    ];
+    assert!(WasmFile::<&[u8]>::parse(module).is_err());
+}
+
+#[test]
+fn wasm_toolchain() {
+    use object::read::Object;
+
+    #[rustfmt::skip]
+    let module: &[u8] = &[
+        // Header.
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+        // Custom section "producers":
+        // language: [Rust ""], processed-by: [rustc "1.50.0 (abc)", walrus "0.18"].
+        0x00, 0x48, 0x09, b'p', b'r', b'o', b'd', b'u', b'c', b'e', b'r', b's',
+        0x02,
+        0x08, b'l', b'a', b'n', b'g', b'u', b'a', b'g', b'e', 0x01,
+        0x04, b'R', b'u', b's', b't', 0x00,
+        0x0c, b'p', b'r', b'o', b'c', b'e', b's', b's', b'e', b'd', b'-', b'b', b'y', 0x02,
+        0x05, b'r', b'u', b's', b't', b'c',
+        0x0c, b'1', b'.', b'5', b'0', b'.', b'0', b' ', b'(', b'a', b'b', b'c', b')',
+        0x06, b'w', b'a', b'l', b'r', b'u', b's',
+        0x04, b'0', b'.', b'1', b'8',
+    ];
+    let file = WasmFile::<&[u8]>::parse(module).unwrap();
+    let toolchain = file.toolchain();
+    assert_eq!(toolchain.compiler(), Some("rustc 1.50.0"));
+    assert_eq!(toolchain.language_hint(), Some("Rust"));
+    assert_eq!(toolchain.linker(), None);
+}